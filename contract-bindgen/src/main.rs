@@ -0,0 +1,108 @@
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use clap::Parser;
+use openrpc_testgen::utils::v7::contract::{
+    bindgen::Bindgen,
+    registry::{ContractRegistry, ARTIFACTS_DIR_ENV, DEFAULT_ARTIFACTS_DIR},
+    SierraClass,
+};
+use tracing::{error, info, warn};
+
+#[derive(Parser, Debug)]
+#[command(version, about = "Generates typed Rust bindings from Sierra ABIs", long_about = None)]
+struct Cli {
+    #[arg(
+        long,
+        env = ARTIFACTS_DIR_ENV,
+        default_value = DEFAULT_ARTIFACTS_DIR,
+        help = "Directory with the artifacts written by scarb build"
+    )]
+    artifacts_dir: PathBuf,
+
+    #[arg(
+        long,
+        default_value = "openrpc-testgen/src/utils/v7/contract/bindings",
+        help = "Directory the bindings are written to"
+    )]
+    out_dir: PathBuf,
+
+    #[arg(
+        required = true,
+        help = "Contracts to generate bindings for, as <artifact>[:<StructName>], e.g. \
+                contracts_sample_contract_3_HelloStarknet:HelloStarknet"
+    )]
+    contracts: Vec<String>,
+}
+
+fn main() {
+    tracing_subscriber::fmt()
+        .with_max_level(tracing::Level::INFO)
+        .init();
+
+    let cli = Cli::parse();
+    let registry = ContractRegistry::new(&cli.artifacts_dir);
+
+    for contract in &cli.contracts {
+        if let Err(e) = generate(&registry, contract, &cli.out_dir) {
+            error!("Failed to generate bindings of {}: {}", contract, e);
+            std::process::exit(1);
+        }
+    }
+}
+
+fn generate(
+    registry: &ContractRegistry,
+    contract: &str,
+    out_dir: &Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let (artifact, name) = match contract.split_once(':') {
+        Some((artifact, name)) => (artifact, name),
+        None => (contract, contract.rsplit('_').next().unwrap_or(contract)),
+    };
+
+    let sierra_path = registry.sierra_path(artifact);
+    let sierra: SierraClass = serde_json::from_str(&std::fs::read_to_string(&sierra_path)?)?;
+    let bindings = Bindgen::new(name, artifact, &sierra.abi).generate()?;
+
+    let out_path = out_dir.join(format!("{}.rs", snake_case(name)));
+    std::fs::write(&out_path, bindings)?;
+    info!("Bindings of {} written to {}", artifact, out_path.display());
+
+    // Snapshot of the ABI the bindings come from, checked by the bindgen tests.
+    let abi_dir = out_dir.join("abi");
+    std::fs::create_dir_all(&abi_dir)?;
+    let abi_path = abi_dir.join(format!("{}.json", snake_case(name)));
+    std::fs::write(
+        &abi_path,
+        format!("{}\n", serde_json::to_string_pretty(&sierra.abi)?),
+    )?;
+
+    match Command::new("rustfmt")
+        .args(["--edition", "2021"])
+        .arg(&out_path)
+        .status()
+    {
+        Ok(status) if status.success() => {}
+        _ => warn!("Failed to format {}", out_path.display()),
+    }
+
+    Ok(())
+}
+
+fn snake_case(name: &str) -> String {
+    let mut out = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_ascii_uppercase() {
+            if i > 0 {
+                out.push('_');
+            }
+            out.push(c.to_ascii_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
@@ -0,0 +1,92 @@
+#[starknet::interface]
+pub trait IBuiltinUser<TContractState> {
+    fn pedersen(self: @TContractState, n: felt252) -> felt252;
+    fn poseidon(self: @TContractState, n: felt252) -> felt252;
+    fn ec_op(self: @TContractState, n: felt252) -> felt252;
+    fn keccak(self: @TContractState, n: felt252) -> u256;
+    fn range_check(self: @TContractState, n: felt252) -> u128;
+    fn store(ref self: TContractState, base: felt252, n: felt252);
+}
+
+/// Contract applying a builtin once in each of the `n` iterations of its functions, so that the
+/// builtin counters of a transaction calling it grow by exactly `n`.
+#[starknet::contract]
+mod BuiltinUser {
+    use core::ec::{EcPointTrait, EcStateTrait, stark_curve};
+    use starknet::storage::Map;
+
+    #[storage]
+    struct Storage {
+        values: Map<felt252, felt252>,
+    }
+
+    #[abi(embed_v0)]
+    impl BuiltinUserImpl of super::IBuiltinUser<ContractState> {
+        fn pedersen(self: @ContractState, n: felt252) -> felt252 {
+            let mut hash = 0;
+            let mut i = n;
+            while i != 0 {
+                hash = core::pedersen::pedersen(hash, i);
+                i -= 1;
+            };
+            hash
+        }
+
+        fn poseidon(self: @ContractState, n: felt252) -> felt252 {
+            let mut hash = 0;
+            let mut i = n;
+            while i != 0 {
+                let (state, _, _) = core::poseidon::hades_permutation(hash, i, 0);
+                hash = state;
+                i -= 1;
+            };
+            hash
+        }
+
+        fn ec_op(self: @ContractState, n: felt252) -> felt252 {
+            let generator = EcPointTrait::new_nz(stark_curve::GEN_X, stark_curve::GEN_Y).unwrap();
+            let mut state = EcStateTrait::init();
+            let mut i = n;
+            while i != 0 {
+                state.add_mul(i, generator);
+                i -= 1;
+            };
+            match state.finalize_nz() {
+                Option::Some(point) => point.x(),
+                Option::None => 0,
+            }
+        }
+
+        /// Each input fits in a single block, hashed with one keccak round.
+        fn keccak(self: @ContractState, n: felt252) -> u256 {
+            let mut hash = 0;
+            let mut i = n;
+            while i != 0 {
+                hash = core::keccak::keccak_u256s_le_inputs(array![i.into()].span());
+                i -= 1;
+            };
+            hash
+        }
+
+        /// Range checks are also applied by the gas accounting of the loop, so only the growth
+        /// of their counter with `n` is known.
+        fn range_check(self: @ContractState, n: felt252) -> u128 {
+            let mut total: u128 = 0;
+            let mut i = n;
+            while i != 0 {
+                total += 1;
+                i -= 1;
+            };
+            total
+        }
+
+        /// Writes `n` storage slots from `base + 1`, growing the state diff by `n` entries.
+        fn store(ref self: ContractState, base: felt252, n: felt252) {
+            let mut i = n;
+            while i != 0 {
+                self.values.write(base + i, i);
+                i -= 1;
+            };
+        }
+    }
+}
@@ -0,0 +1,38 @@
+#[starknet::interface]
+pub trait ICounter<TContractState> {
+    fn increase(ref self: TContractState, amount: felt252);
+    fn get_counter(self: @TContractState) -> felt252;
+}
+
+/// Counter emitting an event with the new value on every increase.
+#[starknet::contract]
+mod Counter {
+    #[storage]
+    struct Storage {
+        counter: felt252,
+    }
+
+    #[event]
+    #[derive(Drop, starknet::Event)]
+    enum Event {
+        Increased: Increased,
+    }
+
+    #[derive(Drop, starknet::Event)]
+    struct Increased {
+        value: felt252,
+    }
+
+    #[abi(embed_v0)]
+    impl CounterImpl of super::ICounter<ContractState> {
+        fn increase(ref self: ContractState, amount: felt252) {
+            let value = self.counter.read() + amount;
+            self.counter.write(value);
+            self.emit(Increased { value });
+        }
+
+        fn get_counter(self: @ContractState) -> felt252 {
+            self.counter.read()
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use starknet::{ClassHash, ContractAddress};
+
+#[starknet::interface]
+pub trait IUniversalDeployer<TContractState> {
+    fn deploy_contract(
+        ref self: TContractState,
+        class_hash: ClassHash,
+        salt: felt252,
+        not_from_zero: bool,
+        calldata: Span<felt252>
+    ) -> ContractAddress;
+}
+
+#[starknet::interface]
+pub trait ISyscallDeployer<TContractState> {
+    fn deploy(
+        ref self: TContractState,
+        class_hash: ClassHash,
+        salt: felt252,
+        deploy_from_zero: bool,
+        calldata: Span<felt252>
+    ) -> ContractAddress;
+}
+
+#[starknet::interface]
+pub trait IConstructorValue<TContractState> {
+    fn get_value(self: @TContractState) -> felt252;
+}
+
+/// Cairo 1 Universal Deployer Contract, behaving like the OpenZeppelin preset: if
+/// `not_from_zero`, the caller address is mixed into the salt and the UDC is the deployer.
+#[starknet::contract]
+mod UniversalDeployer {
+    use core::poseidon::poseidon_hash_span;
+    use starknet::{ClassHash, ContractAddress, SyscallResultTrait, get_caller_address};
+
+    #[storage]
+    struct Storage {}
+
+    #[event]
+    #[derive(Drop, starknet::Event)]
+    enum Event {
+        ContractDeployed: ContractDeployed,
+    }
+
+    #[derive(Drop, starknet::Event)]
+    struct ContractDeployed {
+        address: ContractAddress,
+        deployer: ContractAddress,
+        not_from_zero: bool,
+        class_hash: ClassHash,
+        calldata: Span<felt252>,
+        salt: felt252,
+    }
+
+    #[abi(embed_v0)]
+    impl UniversalDeployerImpl of super::IUniversalDeployer<ContractState> {
+        fn deploy_contract(
+            ref self: ContractState,
+            class_hash: ClassHash,
+            salt: felt252,
+            not_from_zero: bool,
+            calldata: Span<felt252>
+        ) -> ContractAddress {
+            let deployer = get_caller_address();
+            let deployment_salt = if not_from_zero {
+                poseidon_hash_span(array![deployer.into(), salt].span())
+            } else {
+                salt
+            };
+
+            let (address, _) = starknet::syscalls::deploy_syscall(
+                class_hash, deployment_salt, calldata, !not_from_zero
+            )
+                .unwrap_syscall();
+
+            self
+                .emit(
+                    ContractDeployed {
+                        address, deployer, not_from_zero, class_hash, calldata, salt
+                    }
+                );
+            address
+        }
+    }
+}
+
+/// Deploys contracts with `deploy_syscall`, without mixing anything into the salt.
+#[starknet::contract]
+mod SyscallDeployer {
+    use starknet::{ClassHash, ContractAddress, SyscallResultTrait};
+
+    #[storage]
+    struct Storage {}
+
+    #[abi(embed_v0)]
+    impl SyscallDeployerImpl of super::ISyscallDeployer<ContractState> {
+        fn deploy(
+            ref self: ContractState,
+            class_hash: ClassHash,
+            salt: felt252,
+            deploy_from_zero: bool,
+            calldata: Span<felt252>
+        ) -> ContractAddress {
+            let (address, _) = starknet::syscalls::deploy_syscall(
+                class_hash, salt, calldata, deploy_from_zero
+            )
+                .unwrap_syscall();
+            address
+        }
+    }
+}
+
+/// Contract storing the value passed to its constructor.
+#[starknet::contract]
+mod ConstructorValue {
+    #[storage]
+    struct Storage {
+        value: felt252,
+    }
+
+    #[constructor]
+    fn constructor(ref self: ContractState, value: felt252) {
+        self.value.write(value);
+    }
+
+    #[abi(embed_v0)]
+    impl ConstructorValueImpl of super::IConstructorValue<ContractState> {
+        fn get_value(self: @ContractState) -> felt252 {
+            self.value.read()
+        }
+    }
+}
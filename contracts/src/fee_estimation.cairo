@@ -0,0 +1,32 @@
+#[starknet::interface]
+pub trait IEstimatedDeclare<TContractState> {
+    fn declare_version(self: @TContractState) -> felt252;
+}
+
+/// Class declared by the fee estimation tests with a V2 declare transaction.
+#[starknet::contract]
+mod EstimatedDeclareV2 {
+    #[storage]
+    struct Storage {}
+
+    #[abi(embed_v0)]
+    impl EstimatedDeclareImpl of super::IEstimatedDeclare<ContractState> {
+        fn declare_version(self: @ContractState) -> felt252 {
+            2
+        }
+    }
+}
+
+/// Class declared by the fee estimation tests with a V3 declare transaction.
+#[starknet::contract]
+mod EstimatedDeclareV3 {
+    #[storage]
+    struct Storage {}
+
+    #[abi(embed_v0)]
+    impl EstimatedDeclareImpl of super::IEstimatedDeclare<ContractState> {
+        fn declare_version(self: @ContractState) -> felt252 {
+            3
+        }
+    }
+}
@@ -7,6 +7,33 @@ mod sample_contract_6;
 mod smpl1;
 mod smpl2;
 
+/// Contract replacing its own class with `replace_class_syscall`.
+mod upgradeable;
+
+/// Contract running other classes with `library_call_syscall`.
+mod library_caller;
+
+/// Contract receiving messages from L1 and sending messages to L1.
+mod messaging;
+
+/// Cairo 1 UDC and contracts deploying other contracts with `deploy_syscall`.
+mod deployer;
+
+/// Contracts invoked and declared by the `simulateTransactions` tests.
+mod simulation;
+
+/// Classes declared by the fee estimation tests.
+mod fee_estimation;
+
+/// Contract applying builtins a known number of times, to check the reported resources.
+mod builtins;
+
+/// Contract panicking on purpose to revert transactions.
+mod reverter;
+
+/// Counter emitting events, used to follow state changes across blocks.
+mod counter;
+
 /// Paymaster implementation.
 mod paymaster {
     /// Implementation of an account that an execute txns from outside the contract
@@ -0,0 +1,32 @@
+use starknet::ClassHash;
+
+#[starknet::interface]
+pub trait ILibraryCaller<TContractState> {
+    fn set_value_with_library(ref self: TContractState, class_hash: ClassHash, value: felt252);
+    fn get_value(self: @TContractState) -> felt252;
+}
+
+/// Runs the code of other classes in its own context with `library_call_syscall`.
+#[starknet::contract]
+mod LibraryCaller {
+    use starknet::ClassHash;
+    use contracts::upgradeable::{
+        IUpgradeableValueLibraryDispatcher, IUpgradeableValueDispatcherTrait
+    };
+
+    #[storage]
+    struct Storage {
+        value: felt252,
+    }
+
+    #[abi(embed_v0)]
+    impl LibraryCallerImpl of super::ILibraryCaller<ContractState> {
+        fn set_value_with_library(ref self: ContractState, class_hash: ClassHash, value: felt252) {
+            IUpgradeableValueLibraryDispatcher { class_hash }.set_value(value);
+        }
+
+        fn get_value(self: @ContractState) -> felt252 {
+            self.value.read()
+        }
+    }
+}
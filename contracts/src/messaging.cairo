@@ -0,0 +1,35 @@
+#[starknet::interface]
+pub trait IL1L2Messaging<TContractState> {
+    fn withdraw(ref self: TContractState, to_address: felt252, user: felt252, amount: felt252);
+    fn get_balance(self: @TContractState, user: felt252) -> felt252;
+}
+
+/// Credits users with messages sent from L1 and debits them with messages sent to L1.
+#[starknet::contract]
+mod L1L2Messaging {
+    use starknet::SyscallResultTrait;
+    use starknet::storage::Map;
+
+    #[storage]
+    struct Storage {
+        balances: Map<felt252, felt252>,
+    }
+
+    #[l1_handler]
+    fn deposit(ref self: ContractState, from_address: felt252, user: felt252, amount: felt252) {
+        self.balances.write(user, self.balances.read(user) + amount);
+    }
+
+    #[abi(embed_v0)]
+    impl L1L2MessagingImpl of super::IL1L2Messaging<ContractState> {
+        fn withdraw(ref self: ContractState, to_address: felt252, user: felt252, amount: felt252) {
+            self.balances.write(user, self.balances.read(user) - amount);
+            starknet::syscalls::send_message_to_l1_syscall(to_address, array![user, amount].span())
+                .unwrap_syscall();
+        }
+
+        fn get_balance(self: @ContractState, user: felt252) -> felt252 {
+            self.balances.read(user)
+        }
+    }
+}
@@ -0,0 +1,37 @@
+#[starknet::interface]
+pub trait IReverter<TContractState> {
+    fn panic_with(self: @TContractState, message: felt252);
+    fn panic_nested(self: @TContractState, message: felt252);
+    fn exhaust_resources(self: @TContractState);
+}
+
+/// Contract failing on purpose, reverting the transactions invoking it.
+#[starknet::contract]
+mod Reverter {
+    use starknet::get_contract_address;
+    use super::{IReverterDispatcher, IReverterDispatcherTrait};
+
+    #[storage]
+    struct Storage {}
+
+    #[abi(embed_v0)]
+    impl ReverterImpl of super::IReverter<ContractState> {
+        /// Panics with the given message.
+        fn panic_with(self: @ContractState, message: felt252) {
+            core::panic_with_felt252(message);
+        }
+
+        /// Calls `panic_with` on itself, so that the panic comes from a nested call.
+        fn panic_nested(self: @ContractState, message: felt252) {
+            IReverterDispatcher { contract_address: get_contract_address() }.panic_with(message);
+        }
+
+        /// Loops until the transaction runs out of steps or gas.
+        fn exhaust_resources(self: @ContractState) {
+            let mut counter: felt252 = 0;
+            loop {
+                counter += 1;
+            }
+        }
+    }
+}
@@ -0,0 +1,58 @@
+#[starknet::interface]
+pub trait ISimulatedStorage<TContractState> {
+    fn set_value(ref self: TContractState, value: felt252);
+    fn get_value(self: @TContractState) -> felt252;
+}
+
+/// Contract invoked by the simulation tests, storing the last written value.
+#[starknet::contract]
+mod SimulatedStorage {
+    #[storage]
+    struct Storage {
+        value: felt252,
+    }
+
+    #[abi(embed_v0)]
+    impl SimulatedStorageImpl of super::ISimulatedStorage<ContractState> {
+        fn set_value(ref self: ContractState, value: felt252) {
+            self.value.write(value);
+        }
+
+        fn get_value(self: @ContractState) -> felt252 {
+            self.value.read()
+        }
+    }
+}
+
+#[starknet::interface]
+pub trait ISimulatedDeclare<TContractState> {
+    fn declare_version(self: @TContractState) -> felt252;
+}
+
+/// Class declared by the simulation tests with a V2 declare transaction.
+#[starknet::contract]
+mod SimulatedDeclareV2 {
+    #[storage]
+    struct Storage {}
+
+    #[abi(embed_v0)]
+    impl SimulatedDeclareImpl of super::ISimulatedDeclare<ContractState> {
+        fn declare_version(self: @ContractState) -> felt252 {
+            2
+        }
+    }
+}
+
+/// Class declared by the simulation tests with a V3 declare transaction.
+#[starknet::contract]
+mod SimulatedDeclareV3 {
+    #[storage]
+    struct Storage {}
+
+    #[abi(embed_v0)]
+    impl SimulatedDeclareImpl of super::ISimulatedDeclare<ContractState> {
+        fn declare_version(self: @ContractState) -> felt252 {
+            3
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use starknet::ClassHash;
+
+#[starknet::interface]
+pub trait IUpgradeableValue<TContractState> {
+    fn upgrade(ref self: TContractState, new_class_hash: ClassHash);
+    fn set_value(ref self: TContractState, value: felt252);
+    fn get_value(self: @TContractState) -> felt252;
+    fn version(self: @TContractState) -> felt252;
+}
+
+/// First version of a contract replacing its own class, keeping the storage of `value`.
+#[starknet::contract]
+mod UpgradeableV1 {
+    use starknet::{ClassHash, SyscallResultTrait};
+
+    #[storage]
+    struct Storage {
+        value: felt252,
+    }
+
+    #[abi(embed_v0)]
+    impl UpgradeableValueImpl of super::IUpgradeableValue<ContractState> {
+        fn upgrade(ref self: ContractState, new_class_hash: ClassHash) {
+            starknet::syscalls::replace_class_syscall(new_class_hash).unwrap_syscall();
+        }
+
+        fn set_value(ref self: ContractState, value: felt252) {
+            self.value.write(value);
+        }
+
+        fn get_value(self: @ContractState) -> felt252 {
+            self.value.read()
+        }
+
+        fn version(self: @ContractState) -> felt252 {
+            1
+        }
+    }
+}
+
+/// Second version of [UpgradeableV1], doubling the values it stores.
+#[starknet::contract]
+mod UpgradeableV2 {
+    use starknet::{ClassHash, SyscallResultTrait};
+
+    #[storage]
+    struct Storage {
+        value: felt252,
+    }
+
+    #[abi(embed_v0)]
+    impl UpgradeableValueImpl of super::IUpgradeableValue<ContractState> {
+        fn upgrade(ref self: ContractState, new_class_hash: ClassHash) {
+            starknet::syscalls::replace_class_syscall(new_class_hash).unwrap_syscall();
+        }
+
+        fn set_value(ref self: ContractState, value: felt252) {
+            self.value.write(value * 2);
+        }
+
+        fn get_value(self: @ContractState) -> felt252 {
+            self.value.read()
+        }
+
+        fn version(self: @ContractState) -> felt252 {
+            2
+        }
+    }
+}
@@ -0,0 +1,482 @@
+//! Class hash of Cairo 0 (legacy) contract classes.
+//!
+//! The hash follows `compute_deprecated_class_hash` of cairo-lang: a Pedersen hash over the API
+//! version, the entry points, the builtins, the hinted class hash and the bytecode. The hinted
+//! class hash is the Starknet Keccak of the ABI and program serialized the way Python's
+//! `json.dumps(sort_keys=True)` does, so both are taken as raw JSON to preserve the large
+//! integers the program contains.
+
+use core::fmt::{Display, Formatter, Result as CoreResult, Write as _};
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::value::RawValue;
+use sha3::{Digest, Keccak256};
+use starknet_types_core::{
+    felt::Felt,
+    hash::{Pedersen, StarkHash},
+};
+
+/// Version of the legacy class hash, hashed as its first element.
+const API_VERSION: Felt = Felt::ZERO;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LegacyEntryPoints {
+    #[serde(rename = "CONSTRUCTOR")]
+    pub constructor: Vec<LegacyEntryPoint>,
+    #[serde(rename = "EXTERNAL")]
+    pub external: Vec<LegacyEntryPoint>,
+    #[serde(rename = "L1_HANDLER")]
+    pub l1_handler: Vec<LegacyEntryPoint>,
+}
+
+/// Entry point of a legacy class. Compilers write the offset as a hex string, while the RPC
+/// specification uses a number, so both are accepted.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LegacyEntryPoint {
+    #[serde(deserialize_with = "deserialize_offset")]
+    pub offset: u64,
+    pub selector: Felt,
+}
+
+/// Parts of the program needed for the class hash besides the hinted class hash.
+#[derive(Deserialize)]
+struct LegacyProgramHashData {
+    builtins: Vec<String>,
+    data: Vec<Felt>,
+}
+
+#[derive(Debug)]
+pub enum LegacyClassHashError {
+    InvalidBuiltinName(String),
+    Json(serde_json::Error),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LegacyClassHashError {}
+
+impl Display for LegacyClassHashError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> CoreResult {
+        match self {
+            Self::InvalidBuiltinName(name) => write!(f, "invalid builtin name {name}"),
+            Self::Json(inner) => write!(f, "json serialization error: {inner}"),
+        }
+    }
+}
+
+impl From<serde_json::Error> for LegacyClassHashError {
+    fn from(value: serde_json::Error) -> Self {
+        Self::Json(value)
+    }
+}
+
+/// Computes the class hash of a legacy class from its entry points and its ABI and program as
+/// JSON, the program being decompressed when taken from a `DECLARE` v1 transaction.
+pub fn compute_legacy_class_hash(
+    entry_points_by_type: &LegacyEntryPoints,
+    abi: &str,
+    program: &str,
+) -> Result<Felt, LegacyClassHashError> {
+    let program_data: LegacyProgramHashData = serde_json::from_str(program)?;
+
+    let builtins = program_data
+        .builtins
+        .iter()
+        .map(|builtin| {
+            if builtin.len() > 31 || !builtin.is_ascii() {
+                return Err(LegacyClassHashError::InvalidBuiltinName(builtin.clone()));
+            }
+            Ok(Felt::from_bytes_be_slice(builtin.as_bytes()))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Pedersen::hash_array(&[
+        API_VERSION,
+        hash_legacy_entrypoints(&entry_points_by_type.external),
+        hash_legacy_entrypoints(&entry_points_by_type.l1_handler),
+        hash_legacy_entrypoints(&entry_points_by_type.constructor),
+        Pedersen::hash_array(&builtins),
+        legacy_hinted_class_hash(abi, program)?,
+        Pedersen::hash_array(&program_data.data),
+    ]))
+}
+
+/// Starknet Keccak of the ABI and the program without debug info, with the attribute fields
+/// added after the first classes were declared left out when empty, as cairo-lang does for
+/// backward compatibility.
+pub fn legacy_hinted_class_hash(abi: &str, program: &str) -> Result<Felt, LegacyClassHashError> {
+    let mut program = program_without_debug_info(program)?;
+
+    if let PythonicJson::Object(program) = &mut program {
+        // Compilers before 0.10.0 did not write the compiler version and formatted types as
+        // `(a : felt)`, which is the formatting their classes were hashed with.
+        if !program.contains_key("compiler_version") {
+            if let Some(PythonicJson::Object(identifiers)) = program.get_mut("identifiers") {
+                for identifier in identifiers.values_mut() {
+                    patch_legacy_cairo_types(identifier);
+                }
+            }
+        }
+
+        match program.get_mut("attributes") {
+            Some(PythonicJson::Array(attributes)) if attributes.is_empty() => {
+                program.remove("attributes");
+            }
+            Some(PythonicJson::Array(attributes)) => {
+                for attribute in attributes {
+                    if let PythonicJson::Object(attribute) = attribute {
+                        if matches!(
+                            attribute.get("accessible_scopes"),
+                            Some(PythonicJson::Array(scopes)) if scopes.is_empty()
+                        ) {
+                            attribute.remove("accessible_scopes");
+                        }
+                        if matches!(
+                            attribute.get("flow_tracking_data"),
+                            Some(PythonicJson::Null)
+                        ) {
+                            attribute.remove("flow_tracking_data");
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let input = PythonicJson::Object(BTreeMap::from([
+        ("abi".to_string(), PythonicJson::parse(abi)?),
+        ("program".to_string(), program),
+    ]));
+
+    Ok(starknet_keccak(input.to_string_pythonic().as_bytes()))
+}
+
+/// The program with its debug info removed, serialized as cairo-lang does before compressing it
+/// into a `DECLARE` v1 class.
+pub fn legacy_program_json(program: &str) -> Result<String, LegacyClassHashError> {
+    Ok(program_without_debug_info(program)?.to_string_pythonic())
+}
+
+fn program_without_debug_info(program: &str) -> Result<PythonicJson, serde_json::Error> {
+    let mut program = PythonicJson::parse(program)?;
+    if let PythonicJson::Object(program) = &mut program {
+        program.insert("debug_info".to_string(), PythonicJson::Null);
+    }
+    Ok(program)
+}
+
+fn patch_legacy_cairo_types(identifier: &mut PythonicJson) {
+    let PythonicJson::Object(identifier) = identifier else {
+        return;
+    };
+
+    if let Some(PythonicJson::String(cairo_type)) = identifier.get_mut("cairo_type") {
+        *cairo_type = legacy_cairo_type(cairo_type);
+    }
+    if let Some(PythonicJson::Object(members)) = identifier.get_mut("members") {
+        for member in members.values_mut() {
+            if let PythonicJson::Object(member) = member {
+                if let Some(PythonicJson::String(cairo_type)) = member.get_mut("cairo_type") {
+                    *cairo_type = legacy_cairo_type(cairo_type);
+                }
+            }
+        }
+    }
+}
+
+/// Formats `(a: felt)` as `(a : felt)`, leaving types already written that way as they are.
+fn legacy_cairo_type(cairo_type: &str) -> String {
+    cairo_type.replace(" : ", ": ").replace(": ", " : ")
+}
+
+fn hash_legacy_entrypoints(entrypoints: &[LegacyEntryPoint]) -> Felt {
+    let data: Vec<Felt> = entrypoints
+        .iter()
+        .flat_map(|entry| [entry.selector, Felt::from(entry.offset)])
+        .collect();
+
+    Pedersen::hash_array(&data)
+}
+
+fn starknet_keccak(data: &[u8]) -> Felt {
+    let mut hash = Keccak256::digest(data);
+
+    // Keep the 250 low bits
+    hash[0] &= 0b00000011;
+
+    Felt::from_bytes_be_slice(&hash)
+}
+
+fn deserialize_offset<'de, D>(deserializer: D) -> Result<u64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Offset {
+        Number(u64),
+        Hex(String),
+    }
+
+    match Offset::deserialize(deserializer)? {
+        Offset::Number(offset) => Ok(offset),
+        Offset::Hex(offset) => u64::from_str_radix(offset.trim_start_matches("0x"), 16)
+            .map_err(serde::de::Error::custom),
+    }
+}
+
+/// JSON tree with numbers kept as written, serialized like Python's
+/// `json.dumps(sort_keys=True)` with the default separators and `ensure_ascii`.
+#[derive(Debug, Clone)]
+enum PythonicJson {
+    Null,
+    Bool(bool),
+    Number(String),
+    String(String),
+    Array(Vec<PythonicJson>),
+    Object(BTreeMap<String, PythonicJson>),
+}
+
+impl PythonicJson {
+    fn parse(json: &str) -> Result<Self, serde_json::Error> {
+        let raw: &RawValue = serde_json::from_str(json)?;
+        let raw = raw.get();
+
+        Ok(match raw.as_bytes().first() {
+            Some(b'{') => {
+                let members: BTreeMap<String, &RawValue> = serde_json::from_str(raw)?;
+                Self::Object(
+                    members
+                        .into_iter()
+                        .map(|(key, value)| Ok((key, Self::parse(value.get())?)))
+                        .collect::<Result<_, serde_json::Error>>()?,
+                )
+            }
+            Some(b'[') => {
+                let items: Vec<&RawValue> = serde_json::from_str(raw)?;
+                Self::Array(
+                    items
+                        .into_iter()
+                        .map(|item| Self::parse(item.get()))
+                        .collect::<Result<_, _>>()?,
+                )
+            }
+            Some(b'"') => Self::String(serde_json::from_str(raw)?),
+            Some(b't' | b'f') => Self::Bool(serde_json::from_str(raw)?),
+            Some(b'n') => Self::Null,
+            _ => Self::Number(raw.to_string()),
+        })
+    }
+
+    fn to_string_pythonic(&self) -> String {
+        let mut out = String::new();
+        self.write_pythonic(&mut out);
+        out
+    }
+
+    fn write_pythonic(&self, out: &mut String) {
+        match self {
+            Self::Null => out.push_str("null"),
+            Self::Bool(value) => out.push_str(if *value { "true" } else { "false" }),
+            Self::Number(value) => out.push_str(value),
+            Self::String(value) => write_pythonic_string(value, out),
+            Self::Array(items) => {
+                out.push('[');
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        out.push_str(", ");
+                    }
+                    item.write_pythonic(out);
+                }
+                out.push(']');
+            }
+            Self::Object(members) => {
+                // The keys of `hints` are program counters, which Python sorts as integers
+                // before turning them into strings.
+                let mut members: Vec<_> = members.iter().collect();
+                if members.iter().all(|(key, _)| key.parse::<u64>().is_ok()) {
+                    members.sort_by_key(|(key, _)| key.parse::<u64>().unwrap_or_default());
+                }
+
+                out.push('{');
+                for (i, (key, value)) in members.into_iter().enumerate() {
+                    if i > 0 {
+                        out.push_str(", ");
+                    }
+                    write_pythonic_string(key, out);
+                    out.push_str(": ");
+                    value.write_pythonic(out);
+                }
+                out.push('}');
+            }
+        }
+    }
+}
+
+/// Escapes everything outside printable ASCII, as `ensure_ascii` does.
+fn write_pythonic_string(value: &str, out: &mut String) {
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\u{08}' => out.push_str("\\b"),
+            '\u{0c}' => out.push_str("\\f"),
+            ' '..='~' => out.push(c),
+            c => {
+                let mut units = [0u16; 2];
+                for unit in c.encode_utf16(&mut units) {
+                    // Writing to a String cannot fail.
+                    let _ = write!(out, "\\u{:04x}", unit);
+                }
+            }
+        }
+    }
+    out.push('"');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Artifact of a legacy class as written by the cairo-lang compiler.
+    #[derive(Deserialize)]
+    struct LegacyArtifact<'a> {
+        #[serde(borrow)]
+        abi: &'a RawValue,
+        entry_points_by_type: LegacyEntryPoints,
+        #[serde(borrow)]
+        program: &'a RawValue,
+    }
+
+    /// Class hash and hinted class hash of a compiler artifact.
+    fn artifact_hashes(json: &str) -> (Felt, Felt) {
+        let artifact: LegacyArtifact = serde_json::from_str(json).unwrap();
+        let class_hash = compute_legacy_class_hash(
+            &artifact.entry_points_by_type,
+            artifact.abi.get(),
+            artifact.program.get(),
+        )
+        .unwrap();
+        let hinted_class_hash =
+            legacy_hinted_class_hash(artifact.abi.get(), artifact.program.get()).unwrap();
+
+        (class_hash, hinted_class_hash)
+    }
+
+    #[test]
+    fn test_pythonic_serialization() {
+        let value = PythonicJson::parse(
+            r#"{"b": [1, -106710729501573572985208420194530329073740042555888586719489, {}],
+               "a": "é\"\n", "c": null, "d": true}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            value.to_string_pythonic(),
+            r#"{"a": "\u00e9\"\n", "b": [1, -106710729501573572985208420194530329073740042555888586719489, {}], "c": null, "d": true}"#
+        );
+    }
+
+    #[test]
+    fn test_hint_keys_sorted_as_integers() {
+        let value = PythonicJson::parse(r#"{"10": [], "9": [], "100": []}"#).unwrap();
+
+        assert_eq!(
+            value.to_string_pythonic(),
+            r#"{"9": [], "10": [], "100": []}"#
+        );
+    }
+
+    #[test]
+    fn test_entry_point_offsets() {
+        let entry_points: Vec<LegacyEntryPoint> = serde_json::from_str(
+            r#"[{"offset": "0x3a", "selector": "0x1"}, {"offset": 58, "selector": "0x2"}]"#,
+        )
+        .unwrap();
+
+        assert_eq!(entry_points[0].offset, 58);
+        assert_eq!(entry_points[1].offset, 58);
+    }
+
+    #[test]
+    fn test_legacy_cairo_type() {
+        assert_eq!(
+            legacy_cairo_type("(a: felt, b : felt*)"),
+            "(a : felt, b : felt*)"
+        );
+    }
+
+    #[test]
+    fn test_program_without_debug_info() {
+        let program =
+            legacy_program_json(r#"{"debug_info": {"file": "a.cairo"}, "data": []}"#).unwrap();
+
+        assert_eq!(program, r#"{"data": [], "debug_info": null}"#);
+    }
+
+    #[test]
+    fn test_invalid_builtin_name() {
+        let entry_points = LegacyEntryPoints {
+            constructor: vec![],
+            external: vec![],
+            l1_handler: vec![],
+        };
+        let result = compute_legacy_class_hash(
+            &entry_points,
+            "[]",
+            r#"{"builtins": ["a_builtin_name_longer_than_a_short_string"], "data": []}"#,
+        );
+
+        assert!(matches!(
+            result,
+            Err(LegacyClassHashError::InvalidBuiltinName(_))
+        ));
+    }
+
+    #[test]
+    fn test_udc_class_hash() {
+        // Compiled with cairo-lang 0.10.0, declared on mainnet with this class hash.
+        let (class_hash, hinted_class_hash) = artifact_hashes(include_str!(
+            "../../t8n/src/accounts_artifacts/UDC_OZ_0.5.0.json"
+        ));
+
+        assert_eq!(
+            class_hash,
+            Felt::from_hex_unchecked(
+                "0x7b3e05f48f0c69e4a65ce5e076a66271a527aff2c34ce1083ec6e1526997a69"
+            )
+        );
+        assert_eq!(
+            hinted_class_hash,
+            Felt::from_hex_unchecked(
+                "0x3d5c6caadcca46b4e8e1b0adf2661feb7974d665b6e10d035e66030e52e856b"
+            )
+        );
+    }
+
+    #[test]
+    fn test_erc20_class_hash() {
+        // Compiled before cairo-lang 0.10.0, without a `compiler_version`, with attributes.
+        let (class_hash, hinted_class_hash) = artifact_hashes(include_str!(
+            "../../openrpc-testgen/src/suite_openrpc/test_data/ERC20_Mintable_OZ_0.2.0.json"
+        ));
+
+        assert_eq!(
+            class_hash,
+            Felt::from_hex_unchecked(
+                "0x5bcea3c561a12679ff904d3d2a34e8c7cfd72ce58594304d56e4316b08cae94"
+            )
+        );
+        assert_eq!(
+            hinted_class_hash,
+            Felt::from_hex_unchecked(
+                "0x2cf1071bd43bdb85c65dabf88330bb866a2c939dc52871898372365d3fcb791"
+            )
+        );
+    }
+}
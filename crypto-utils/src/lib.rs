@@ -1 +1,2 @@
 pub mod curve;
+pub mod legacy_class_hash;
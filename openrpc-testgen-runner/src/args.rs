@@ -1,9 +1,17 @@
-use clap::Parser;
+use std::path::PathBuf;
+
+use clap::{ArgGroup, Parser};
+use openrpc_testgen::utils::v7::signers::key_pair::{KeystoreError, SigningKey};
 use starknet_types_core::felt::Felt;
 use url::Url;
 
 #[derive(Parser, Debug, Clone)]
 #[command(version, about, long_about = None, disable_version_flag = true)]
+#[command(group(
+    ArgGroup::new("paymaster_key")
+        .required(true)
+        .args(["paymaster_private_key", "paymaster_keystore", "paymaster_key_file"])
+))]
 pub struct Args {
     #[arg(
         long,
@@ -17,7 +25,30 @@ pub struct Args {
     pub paymaster_account_address: Felt,
 
     #[arg(long, env, help = "Private Key of an account that would pay for fees")]
-    pub paymaster_private_key: Felt,
+    pub paymaster_private_key: Option<Felt>,
+
+    #[arg(
+        long,
+        env,
+        requires = "paymaster_keystore_password",
+        help = "Path to an encrypted keystore JSON file holding the paymaster private key"
+    )]
+    pub paymaster_keystore: Option<PathBuf>,
+
+    #[arg(
+        long,
+        env,
+        hide_env_values = true,
+        help = "Password of the paymaster keystore, preferably passed through the environment"
+    )]
+    pub paymaster_keystore_password: Option<String>,
+
+    #[arg(
+        long,
+        env,
+        help = "Path to a plain text file holding the paymaster private key as a hex string"
+    )]
+    pub paymaster_key_file: Option<PathBuf>,
 
     #[arg(long, env, help = "Universal Deployer Contract address")]
     pub udc_address: Felt,
@@ -25,10 +56,46 @@ pub struct Args {
     #[arg(long, env, help = "Class hash of account contract")]
     pub account_class_hash: Felt,
 
+    #[arg(
+        long,
+        env,
+        help = "URL of the L1 node relaying messages, required by the messaging suite (e.g. 'http://127.0.0.1:8545')"
+    )]
+    pub l1_url: Option<Url>,
+
+    #[arg(
+        long,
+        env,
+        help = "Seconds a syncing node is polled for progress by the syncing suite, which skips the polling when unset"
+    )]
+    pub sync_progress_timeout: Option<u64>,
+
     #[arg(short, long, value_enum)]
     pub suite: Vec<Suite>,
 }
 
+impl Args {
+    /// Resolves the paymaster private key from whichever source was provided.
+    pub fn paymaster_private_key(&self) -> Result<Felt, KeystoreError> {
+        if let Some(private_key) = self.paymaster_private_key {
+            return Ok(private_key);
+        }
+
+        let signing_key = match (&self.paymaster_keystore, &self.paymaster_key_file) {
+            (Some(keystore), _) => SigningKey::from_keystore(
+                keystore,
+                self.paymaster_keystore_password
+                    .as_deref()
+                    .unwrap_or_default(),
+            )?,
+            (None, Some(key_file)) => SigningKey::from_key_file(key_file)?,
+            (None, None) => return Err(KeystoreError::InvalidPath),
+        };
+
+        Ok(signing_key.secret_scalar())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, clap::ValueEnum)]
 pub enum Suite {
     OpenRpc,
@@ -36,4 +103,10 @@ pub enum Suite {
     KatanaNoMining,
     KatanaNoFee,
     KatanaNoAccountValidation,
+    Fuzz,
+    Messaging,
+    BlockHash,
+    Reorg,
+    Syncing,
+    ExecutionResources,
 }
@@ -2,13 +2,21 @@ use args::{Args, Suite};
 use clap::Parser;
 #[allow(unused_imports)]
 use openrpc_testgen::{
+    suite_block_hash::{SetupInput as SetupInputBlockHash, TestSuiteBlockHash},
+    suite_execution_resources::{
+        SetupInput as SetupInputExecutionResources, TestSuiteExecutionResources,
+    },
+    suite_fuzz::{SetupInput as SetupInputFuzz, TestSuiteFuzz},
     suite_katana::{SetupInput as SetupInputKatana, TestSuiteKatana},
     suite_katana_no_account_validation::{
         SetupInput as SetupInputKatanaNoAccountValidation, TestSuiteKatanaNoAccountValidation,
     },
     suite_katana_no_fee::{SetupInput as SetupInputKatanaNoFee, TestSuiteKatanaNoFee},
     suite_katana_no_mining::{SetupInput as SetupInputKatanaNoMining, TestSuiteKatanaNoMining},
+    suite_messaging::{SetupInput as SetupInputMessaging, TestSuiteMessaging},
     suite_openrpc::{SetupInput, TestSuiteOpenRpc},
+    suite_reorg::{SetupInput as SetupInputReorg, TestSuiteReorg},
+    suite_syncing::{SetupInput as SetupInputSyncing, TestSuiteSyncing},
     RunnableTrait,
 };
 use std::collections::HashMap;
@@ -23,6 +31,13 @@ async fn main() {
         .init();
 
     let args = Args::parse();
+    let paymaster_private_key = match args.paymaster_private_key() {
+        Ok(private_key) => private_key,
+        Err(e) => {
+            error!("Failed to load the paymaster private key: {}", e);
+            std::process::exit(1);
+        }
+    };
     let mut failed_tests: HashMap<String, HashMap<String, String>> = HashMap::new(); // Suite -> {TestName -> ErrorMessage}
 
     for suite in args.suite {
@@ -33,7 +48,7 @@ async fn main() {
                     let suite_openrpc_input = SetupInput {
                         urls: args.urls.clone(),
                         paymaster_account_address: args.paymaster_account_address.clone(),
-                        paymaster_private_key: args.paymaster_private_key.clone(),
+                        paymaster_private_key,
                         udc_address: args.udc_address.clone(),
                         account_class_hash: args.account_class_hash.clone(),
                     };
@@ -56,7 +71,7 @@ async fn main() {
                     let suite_katana_input = SetupInputKatana {
                         urls: args.urls.clone(),
                         paymaster_account_address: args.paymaster_account_address.clone(),
-                        paymaster_private_key: args.paymaster_private_key.clone(),
+                        paymaster_private_key,
                         udc_address: args.udc_address.clone(),
                         account_class_hash: args.account_class_hash.clone(),
                     };
@@ -79,7 +94,7 @@ async fn main() {
                     let suite_katana_no_mining_input = SetupInputKatanaNoMining {
                         urls: args.urls.clone(),
                         paymaster_account_address: args.paymaster_account_address.clone(),
-                        paymaster_private_key: args.paymaster_private_key.clone(),
+                        paymaster_private_key,
                         udc_address: args.udc_address.clone(),
                         account_class_hash: args.account_class_hash.clone(),
                     };
@@ -104,7 +119,7 @@ async fn main() {
                     let suite_katana_no_fee_input = SetupInputKatanaNoFee {
                         urls: args.urls.clone(),
                         paymaster_account_address: args.paymaster_account_address.clone(),
-                        paymaster_private_key: args.paymaster_private_key.clone(),
+                        paymaster_private_key,
                         udc_address: args.udc_address.clone(),
                         account_class_hash: args.account_class_hash.clone(),
                     };
@@ -128,7 +143,7 @@ async fn main() {
                         SetupInputKatanaNoAccountValidation {
                             urls: args.urls.clone(),
                             paymaster_account_address: args.paymaster_account_address.clone(),
-                            paymaster_private_key: args.paymaster_private_key.clone(),
+                            paymaster_private_key,
                             udc_address: args.udc_address.clone(),
                             account_class_hash: args.account_class_hash.clone(),
                         };
@@ -149,6 +164,145 @@ async fn main() {
                     error!("Feature 'katana_no_account_validation' not enabled during compilation phase.");
                 }
             }
+            Suite::Fuzz => {
+                #[cfg(feature = "fuzz")]
+                {
+                    let suite_fuzz_input = SetupInputFuzz {
+                        urls: args.urls.clone(),
+                        paymaster_account_address: args.paymaster_account_address.clone(),
+                        paymaster_private_key,
+                        udc_address: args.udc_address.clone(),
+                        account_class_hash: args.account_class_hash.clone(),
+                    };
+                    if let Err(e) = TestSuiteFuzz::run(&suite_fuzz_input).await {
+                        if let openrpc_testgen::utils::v7::endpoints::errors::OpenRpcTestGenError::TestSuiteFailure { failed_tests: suite_failed_tests } = e {
+                            failed_tests.insert("Fuzz".to_string(), suite_failed_tests);
+                        } else {
+                            error!("Error while running TestSuiteFuzz: {}", e);
+                        }
+                    }
+                }
+                #[cfg(not(feature = "fuzz"))]
+                {
+                    error!("Feature 'fuzz' not enabled during compilation phase.");
+                }
+            }
+            Suite::Messaging => {
+                #[cfg(feature = "messaging")]
+                {
+                    let Some(l1_url) = args.l1_url.clone() else {
+                        error!("The messaging suite requires --l1-url.");
+                        std::process::exit(1);
+                    };
+                    let suite_messaging_input = SetupInputMessaging {
+                        urls: args.urls.clone(),
+                        paymaster_account_address: args.paymaster_account_address.clone(),
+                        paymaster_private_key,
+                        udc_address: args.udc_address.clone(),
+                        account_class_hash: args.account_class_hash.clone(),
+                        l1_url,
+                    };
+                    if let Err(e) = TestSuiteMessaging::run(&suite_messaging_input).await {
+                        if let openrpc_testgen::utils::v7::endpoints::errors::OpenRpcTestGenError::TestSuiteFailure { failed_tests: suite_failed_tests } = e {
+                            failed_tests.insert("Messaging".to_string(), suite_failed_tests);
+                        } else {
+                            error!("Error while running TestSuiteMessaging: {}", e);
+                        }
+                    }
+                }
+                #[cfg(not(feature = "messaging"))]
+                {
+                    error!("Feature 'messaging' not enabled during compilation phase.");
+                }
+            }
+            Suite::BlockHash => {
+                #[cfg(feature = "block_hash")]
+                {
+                    let suite_block_hash_input = SetupInputBlockHash {
+                        urls: args.urls.clone(),
+                    };
+                    if let Err(e) = TestSuiteBlockHash::run(&suite_block_hash_input).await {
+                        if let openrpc_testgen::utils::v7::endpoints::errors::OpenRpcTestGenError::TestSuiteFailure { failed_tests: suite_failed_tests } = e {
+                            failed_tests.insert("BlockHash".to_string(), suite_failed_tests);
+                        } else {
+                            error!("Error while running TestSuiteBlockHash: {}", e);
+                        }
+                    }
+                }
+                #[cfg(not(feature = "block_hash"))]
+                {
+                    error!("Feature 'block_hash' not enabled during compilation phase.");
+                }
+            }
+            Suite::Reorg => {
+                #[cfg(feature = "reorg")]
+                {
+                    let suite_reorg_input = SetupInputReorg {
+                        urls: args.urls.clone(),
+                        paymaster_account_address: args.paymaster_account_address.clone(),
+                        paymaster_private_key,
+                        udc_address: args.udc_address.clone(),
+                        account_class_hash: args.account_class_hash.clone(),
+                    };
+                    if let Err(e) = TestSuiteReorg::run(&suite_reorg_input).await {
+                        if let openrpc_testgen::utils::v7::endpoints::errors::OpenRpcTestGenError::TestSuiteFailure { failed_tests: suite_failed_tests } = e {
+                            failed_tests.insert("Reorg".to_string(), suite_failed_tests);
+                        } else {
+                            error!("Error while running TestSuiteReorg: {}", e);
+                        }
+                    }
+                }
+                #[cfg(not(feature = "reorg"))]
+                {
+                    error!("Feature 'reorg' not enabled during compilation phase.");
+                }
+            }
+            Suite::Syncing => {
+                #[cfg(feature = "syncing")]
+                {
+                    let suite_syncing_input = SetupInputSyncing {
+                        urls: args.urls.clone(),
+                        sync_progress_timeout: args
+                            .sync_progress_timeout
+                            .map(std::time::Duration::from_secs),
+                    };
+                    if let Err(e) = TestSuiteSyncing::run(&suite_syncing_input).await {
+                        if let openrpc_testgen::utils::v7::endpoints::errors::OpenRpcTestGenError::TestSuiteFailure { failed_tests: suite_failed_tests } = e {
+                            failed_tests.insert("Syncing".to_string(), suite_failed_tests);
+                        } else {
+                            error!("Error while running TestSuiteSyncing: {}", e);
+                        }
+                    }
+                }
+                #[cfg(not(feature = "syncing"))]
+                {
+                    error!("Feature 'syncing' not enabled during compilation phase.");
+                }
+            }
+            Suite::ExecutionResources => {
+                #[cfg(feature = "execution_resources")]
+                {
+                    let suite_execution_resources_input = SetupInputExecutionResources {
+                        urls: args.urls.clone(),
+                        paymaster_account_address: args.paymaster_account_address.clone(),
+                        paymaster_private_key,
+                        udc_address: args.udc_address.clone(),
+                    };
+                    if let Err(e) =
+                        TestSuiteExecutionResources::run(&suite_execution_resources_input).await
+                    {
+                        if let openrpc_testgen::utils::v7::endpoints::errors::OpenRpcTestGenError::TestSuiteFailure { failed_tests: suite_failed_tests } = e {
+                            failed_tests.insert("ExecutionResources".to_string(), suite_failed_tests);
+                        } else {
+                            error!("Error while running TestSuiteExecutionResources: {}", e);
+                        }
+                    }
+                }
+                #[cfg(not(feature = "execution_resources"))]
+                {
+                    error!("Feature 'execution_resources' not enabled during compilation phase.");
+                }
+            }
         }
     }
 
@@ -7,6 +7,12 @@ use utils::v7::{
 };
 
 pub mod macros;
+#[cfg(feature = "block_hash")]
+pub mod suite_block_hash;
+#[cfg(feature = "execution_resources")]
+pub mod suite_execution_resources;
+#[cfg(feature = "fuzz")]
+pub mod suite_fuzz;
 #[cfg(feature = "katana")]
 pub mod suite_katana;
 #[cfg(feature = "katana_no_account_validation")]
@@ -15,8 +21,14 @@ pub mod suite_katana_no_account_validation;
 pub mod suite_katana_no_fee;
 #[cfg(feature = "katana_no_mining")]
 pub mod suite_katana_no_mining;
+#[cfg(feature = "messaging")]
+pub mod suite_messaging;
 #[cfg(feature = "openrpc")]
 pub mod suite_openrpc;
+#[cfg(feature = "reorg")]
+pub mod suite_reorg;
+#[cfg(feature = "syncing")]
+pub mod suite_syncing;
 
 pub mod utils;
 
@@ -0,0 +1,46 @@
+/// Concatenated Cairo serialization of the arguments, each a `cainome_cairo_serde` type, e.g.
+/// `cairo_calldata![recipient, U256 { low: amount, high: 0 }]` for `transfer`. Results are decoded
+/// back with [decode](crate::utils::cairo_serde::decode).
+#[macro_export]
+macro_rules! cairo_calldata {
+    ($($value:expr),* $(,)?) => {
+        {
+            #[allow(unused_mut)]
+            let mut calldata: Vec<starknet_types_core::felt::Felt> = vec![];
+            $(calldata.extend($crate::macros::cairo_calldata::serialize(&$value));)*
+            calldata
+        }
+    };
+}
+
+/// Cairo serialization of a value whose Rust type is its own `CairoSerde` type.
+pub fn serialize<T>(value: &T) -> Vec<starknet_types_core::felt::Felt>
+where
+    T: cainome_cairo_serde::CairoSerde<RustType = T>,
+{
+    T::cairo_serialize(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use cainome_cairo_serde::{ContractAddress, U256};
+    use starknet_types_core::felt::Felt;
+
+    #[test]
+    fn test_cairo_calldata() {
+        let recipient = ContractAddress(Felt::from(0x123));
+        let amount = U256 { low: 10, high: 1 };
+
+        assert_eq!(
+            crate::cairo_calldata![recipient, amount, vec![Felt::from(7)]],
+            vec![
+                Felt::from(0x123),
+                Felt::from(10),
+                Felt::ONE,
+                Felt::ONE,
+                Felt::from(7),
+            ]
+        );
+        assert!(crate::cairo_calldata![].is_empty());
+    }
+}
@@ -2,4 +2,5 @@ pub mod assert_eq_result;
 pub mod assert_matches_result;
 pub mod assert_provider_starknet_err;
 pub mod assert_result;
+pub mod cairo_calldata;
 pub mod macros_errors;
@@ -0,0 +1,103 @@
+use starknet_types_core::felt::Felt;
+use starknet_types_rpc::{
+    BlockId, BlockWithReceipts, MaybePendingBlockWithReceipts, MaybePendingStateUpdate, StateUpdate,
+};
+use url::Url;
+
+use crate::{
+    utils::v7::{
+        endpoints::errors::OpenRpcTestGenError,
+        providers::{
+            jsonrpc::{HttpTransport, JsonRpcClient},
+            provider::Provider,
+        },
+    },
+    SetupableTrait,
+};
+
+pub mod test_block_hash;
+pub mod test_parent_hash;
+
+/// Number of the latest blocks of every node verified by the suite.
+pub const BLOCKS_TO_VERIFY: u64 = 10;
+
+/// Verification of block hashes and header commitments of the blocks served by the nodes,
+/// against their local recomputation.
+#[derive(Clone, Debug)]
+pub struct TestSuiteBlockHash {
+    pub providers: Vec<JsonRpcClient<HttpTransport>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct SetupInput {
+    pub urls: Vec<Url>,
+}
+
+impl SetupableTrait for TestSuiteBlockHash {
+    type Input = SetupInput;
+
+    async fn setup(setup_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        if setup_input.urls.is_empty() {
+            return Err(OpenRpcTestGenError::EmptyUrlList(
+                "No L2 node urls.".to_string(),
+            ));
+        }
+
+        let providers = setup_input
+            .urls
+            .iter()
+            .map(|url| JsonRpcClient::new(HttpTransport::new(url.clone())))
+            .collect();
+
+        Ok(Self { providers })
+    }
+}
+
+/// Numbers of the latest [BLOCKS_TO_VERIFY] blocks of the node.
+pub async fn blocks_to_verify<P: Provider>(
+    provider: &P,
+) -> Result<std::ops::RangeInclusive<u64>, OpenRpcTestGenError> {
+    let latest_block = provider.block_number().await?;
+
+    Ok(latest_block.saturating_sub(BLOCKS_TO_VERIFY - 1)..=latest_block)
+}
+
+/// Block with receipts and state update of an accepted block.
+pub async fn get_block<P: Provider>(
+    provider: &P,
+    block_number: u64,
+) -> Result<(BlockWithReceipts<Felt>, StateUpdate<Felt>), OpenRpcTestGenError> {
+    let block = match provider
+        .get_block_with_receipts(BlockId::Number(block_number))
+        .await?
+    {
+        MaybePendingBlockWithReceipts::Block(block) => block,
+        MaybePendingBlockWithReceipts::Pending(_) => {
+            return Err(OpenRpcTestGenError::UnexpectedBlockResponseType(format!(
+                "Pending block returned for block {}",
+                block_number
+            )));
+        }
+    };
+
+    let state_update = match provider
+        .get_state_update(BlockId::Number(block_number))
+        .await?
+    {
+        MaybePendingStateUpdate::Block(state_update) => state_update,
+        MaybePendingStateUpdate::Pending(_) => {
+            return Err(OpenRpcTestGenError::UnexpectedBlockResponseType(format!(
+                "Pending state update returned for block {}",
+                block_number
+            )));
+        }
+    };
+
+    Ok((block, state_update))
+}
+
+#[cfg(not(feature = "rust-analyzer"))]
+include!(concat!(
+    env!("OUT_DIR"),
+    "/generated_tests_suite_block_hash.rs"
+));
@@ -0,0 +1,63 @@
+use tracing::info;
+
+use crate::{
+    assert_result,
+    utils::v7::{
+        block_hash::{verify_block_header, BlockHashError},
+        endpoints::errors::OpenRpcTestGenError,
+    },
+    RunnableTrait,
+};
+
+#[derive(Clone, Debug)]
+pub struct TestCase {}
+
+impl RunnableTrait for TestCase {
+    type Input = super::TestSuiteBlockHash;
+
+    async fn run(test_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let mut verified_blocks = 0;
+        for provider in &test_input.providers {
+            for block_number in super::blocks_to_verify(provider).await? {
+                let (block, state_update) = super::get_block(provider, block_number).await?;
+
+                let header = match verify_block_header(&block, &state_update) {
+                    Ok(header) => header,
+                    Err(BlockHashError::UnsupportedVersion(version)) => {
+                        info!(
+                            "Skipping block {} of Starknet version {}",
+                            block_number, version
+                        );
+                        continue;
+                    }
+                    Err(BlockHashError::GasConsumed(transaction_hash)) => {
+                        info!(
+                            "Skipping block {}, gas consumed by transaction {:#x} cannot be derived exactly from its fee",
+                            block_number, transaction_hash
+                        );
+                        continue;
+                    }
+                    Err(error) => return Err(error.into()),
+                };
+
+                info!(
+                    "Block {} hash {:#x} verified with transaction commitment {:#x}, event commitment {:#x}, receipt commitment {:#x} and state diff commitment {:#x}",
+                    header.number,
+                    header.hash,
+                    header.transaction_commitment,
+                    header.event_commitment,
+                    header.receipt_commitment,
+                    header.state_diff_commitment
+                );
+                verified_blocks += 1;
+            }
+        }
+
+        assert_result!(
+            verified_blocks > 0,
+            "None of the checked blocks could be verified"
+        );
+
+        Ok(Self {})
+    }
+}
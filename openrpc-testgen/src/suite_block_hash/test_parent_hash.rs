@@ -0,0 +1,46 @@
+use crate::{
+    assert_eq_result,
+    utils::v7::{endpoints::errors::OpenRpcTestGenError, providers::provider::Provider},
+    RunnableTrait,
+};
+use starknet_types_rpc::{BlockId, MaybePendingBlockWithTxHashes};
+
+#[derive(Clone, Debug)]
+pub struct TestCase {}
+
+impl RunnableTrait for TestCase {
+    type Input = super::TestSuiteBlockHash;
+
+    async fn run(test_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        for provider in &test_input.providers {
+            let mut previous_block_hash = None;
+
+            for block_number in super::blocks_to_verify(provider).await? {
+                let block = match provider
+                    .get_block_with_tx_hashes(BlockId::Number(block_number))
+                    .await?
+                {
+                    MaybePendingBlockWithTxHashes::Block(block) => block,
+                    MaybePendingBlockWithTxHashes::Pending(_) => {
+                        return Err(OpenRpcTestGenError::UnexpectedBlockResponseType(format!(
+                            "Pending block returned for block {}",
+                            block_number
+                        )));
+                    }
+                };
+
+                if let Some(previous_block_hash) = previous_block_hash {
+                    assert_eq_result!(
+                        block.block_header.parent_hash,
+                        previous_block_hash,
+                        "parent_hash of block {}",
+                        block_number
+                    );
+                }
+                previous_block_hash = Some(block.block_header.block_hash);
+            }
+        }
+
+        Ok(Self {})
+    }
+}
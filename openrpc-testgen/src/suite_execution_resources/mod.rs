@@ -0,0 +1,240 @@
+use std::time::{Duration, Instant};
+
+use production_nodes_types::pathfinder_types::types::receipt::{
+    BuiltinCounters, ExecutionResources, L1Gas,
+};
+use serde::Deserialize;
+use starknet_types_core::felt::Felt;
+use starknet_types_rpc::{BlockId, BlockTag};
+use url::Url;
+
+use crate::{
+    assert_eq_result,
+    utils::v7::{
+        accounts::{
+            account::Account,
+            call::Call,
+            creation::helpers::get_chain_id,
+            single_owner::{ExecutionEncoding, SingleOwnerAccount},
+        },
+        contract::declare_and_deploy::{ensure_declared, ensure_deployed},
+        endpoints::{
+            errors::OpenRpcTestGenError,
+            utils::{get_selector_from_name, wait_for_sent_transaction},
+        },
+        providers::{
+            jsonrpc::{HttpTransport, JsonRpcClient, StarknetError},
+            provider::{Provider, ProviderError},
+        },
+        signers::{key_pair::SigningKey, local_wallet::LocalWallet},
+    },
+    SetupableTrait,
+};
+
+pub mod test_builtin_counters;
+pub mod test_data_availability_gas;
+pub mod test_range_check_counter;
+
+/// Number of iterations of the loops of `BuiltinUser` run by the tests.
+pub const ITERATIONS: u64 = 8;
+
+/// Time the other nodes are given to serve a transaction accepted by the first one.
+pub const SYNC_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Resources of the transactions invoking `BuiltinUser`, sent to the first node and compared
+/// across the receipts and traces of all the nodes.
+#[derive(Clone, Debug)]
+pub struct TestSuiteExecutionResources {
+    pub paymaster_account: SingleOwnerAccount<JsonRpcClient<HttpTransport>, LocalWallet>,
+    pub providers: Vec<JsonRpcClient<HttpTransport>>,
+    pub builtin_user_address: Felt,
+}
+
+#[derive(Clone, Debug)]
+pub struct SetupInput {
+    pub urls: Vec<Url>,
+    pub paymaster_account_address: Felt,
+    pub paymaster_private_key: Felt,
+    pub udc_address: Felt,
+}
+
+impl SetupableTrait for TestSuiteExecutionResources {
+    type Input = SetupInput;
+
+    async fn setup(setup_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let providers: Vec<_> = setup_input
+            .urls
+            .iter()
+            .map(|url| JsonRpcClient::new(HttpTransport::new(url.clone())))
+            .collect();
+        let provider = providers
+            .first()
+            .ok_or_else(|| OpenRpcTestGenError::EmptyUrlList("No L2 node urls.".to_string()))?
+            .clone();
+        let chain_id = get_chain_id(&provider).await?;
+
+        let mut paymaster_account = SingleOwnerAccount::new(
+            provider,
+            LocalWallet::from(SigningKey::from_secret_scalar(
+                setup_input.paymaster_private_key,
+            )),
+            setup_input.paymaster_account_address,
+            chain_id,
+            ExecutionEncoding::New,
+        );
+        paymaster_account.set_block_id(BlockId::Tag(BlockTag::Pending));
+
+        let class_hash = ensure_declared(&paymaster_account, "BuiltinUser")
+            .await?
+            .class_hash();
+        let builtin_user_address = ensure_deployed(
+            &paymaster_account,
+            setup_input.udc_address,
+            class_hash,
+            Felt::ZERO,
+            vec![],
+        )
+        .await?
+        .contract_address();
+
+        Ok(Self {
+            paymaster_account,
+            providers,
+            builtin_user_address,
+        })
+    }
+}
+
+/// Execution resources of a receipt or a trace, read by the field names of the specification,
+/// which leaves out the builtins that were not applied.
+#[derive(Debug, Deserialize)]
+struct SpecExecutionResources {
+    steps: u64,
+    #[serde(default)]
+    memory_holes: u64,
+    #[serde(default)]
+    range_check_builtin_applications: u64,
+    #[serde(default)]
+    pedersen_builtin_applications: u64,
+    #[serde(default)]
+    poseidon_builtin_applications: u64,
+    #[serde(default)]
+    ec_op_builtin_applications: u64,
+    #[serde(default)]
+    ecdsa_builtin_applications: u64,
+    #[serde(default)]
+    bitwise_builtin_applications: u64,
+    #[serde(default)]
+    keccak_builtin_applications: u64,
+    #[serde(default)]
+    segment_arena_builtin: u64,
+    data_availability: L1Gas,
+}
+
+impl From<SpecExecutionResources> for ExecutionResources {
+    fn from(resources: SpecExecutionResources) -> Self {
+        Self {
+            builtins: BuiltinCounters {
+                pedersen: resources.pedersen_builtin_applications,
+                range_check: resources.range_check_builtin_applications,
+                ecdsa: resources.ecdsa_builtin_applications,
+                bitwise: resources.bitwise_builtin_applications,
+                ec_op: resources.ec_op_builtin_applications,
+                keccak: resources.keccak_builtin_applications,
+                poseidon: resources.poseidon_builtin_applications,
+                segment_arena: resources.segment_arena_builtin,
+                ..Default::default()
+            },
+            n_steps: resources.steps,
+            n_memory_holes: resources.memory_holes,
+            data_availability: resources.data_availability,
+            ..Default::default()
+        }
+    }
+}
+
+/// Invokes `function` of `BuiltinUser` and returns the resources of the transaction, once checked
+/// to be the same in the receipt and the trace of every node.
+pub async fn invoke_resources(
+    test_input: &TestSuiteExecutionResources,
+    function: &str,
+    calldata: Vec<Felt>,
+) -> Result<ExecutionResources, OpenRpcTestGenError> {
+    let account = &test_input.paymaster_account;
+    let result = account
+        .execute_v3(vec![Call {
+            to: test_input.builtin_user_address,
+            selector: get_selector_from_name(function)?,
+            calldata,
+        }])
+        .send()
+        .await?;
+    wait_for_sent_transaction(result.transaction_hash, account).await?;
+
+    let mut resources = vec![];
+    for (node, provider) in test_input.providers.iter().enumerate() {
+        let receipt_resources = receipt_resources(provider, result.transaction_hash).await?;
+        let trace =
+            serde_json::to_value(provider.trace_transaction(result.transaction_hash).await?)?;
+        let trace_resources: ExecutionResources =
+            serde_json::from_value::<SpecExecutionResources>(trace["execution_resources"].clone())?
+                .into();
+
+        assert_eq_result!(
+            trace_resources,
+            receipt_resources,
+            "Resources of transaction {:#x} in the trace and the receipt of node {}",
+            result.transaction_hash,
+            node
+        );
+        resources.push(receipt_resources);
+    }
+
+    let first = resources.remove(0);
+    for (index, node_resources) in resources.iter().enumerate() {
+        assert_eq_result!(
+            node_resources,
+            &first,
+            "Resources of transaction {:#x} reported by node {} and node 0",
+            result.transaction_hash,
+            index + 1
+        );
+    }
+
+    Ok(first)
+}
+
+/// Resources in the receipt of the transaction, waiting for a node that has not received it yet.
+async fn receipt_resources(
+    provider: &JsonRpcClient<HttpTransport>,
+    transaction_hash: Felt,
+) -> Result<ExecutionResources, OpenRpcTestGenError> {
+    let start = Instant::now();
+    loop {
+        match provider.get_transaction_receipt(transaction_hash).await {
+            Ok(receipt) => {
+                let receipt = serde_json::to_value(receipt)?;
+                let resources: SpecExecutionResources =
+                    serde_json::from_value(receipt["execution_resources"].clone())?;
+                return Ok(resources.into());
+            }
+            Err(ProviderError::StarknetError(StarknetError::TransactionHashNotFound)) => {}
+            Err(e) => return Err(e.into()),
+        }
+
+        if start.elapsed() > SYNC_TIMEOUT {
+            return Err(OpenRpcTestGenError::Timeout(format!(
+                "No receipt for transaction {:#x} in {} seconds.",
+                transaction_hash,
+                SYNC_TIMEOUT.as_secs()
+            )));
+        }
+        tokio::time::sleep(Duration::from_secs(1)).await;
+    }
+}
+
+#[cfg(not(feature = "rust-analyzer"))]
+include!(concat!(
+    env!("OUT_DIR"),
+    "/generated_tests_suite_execution_resources.rs"
+));
@@ -0,0 +1,46 @@
+use production_nodes_types::pathfinder_types::types::receipt::BuiltinCounters;
+use starknet_types_core::felt::Felt;
+
+use super::{invoke_resources, ITERATIONS};
+use crate::{assert_eq_result, utils::v7::endpoints::errors::OpenRpcTestGenError, RunnableTrait};
+
+/// Builtins applied once per iteration, by the function of `BuiltinUser` named after them.
+const EXERCISED_BUILTINS: [(&str, fn(&BuiltinCounters) -> u64); 4] = [
+    ("pedersen", |builtins| builtins.pedersen),
+    ("poseidon", |builtins| builtins.poseidon),
+    ("ec_op", |builtins| builtins.ec_op),
+    ("keccak", |builtins| builtins.keccak),
+];
+
+#[derive(Clone, Debug)]
+pub struct TestCase {}
+
+impl RunnableTrait for TestCase {
+    type Input = super::TestSuiteExecutionResources;
+
+    async fn run(test_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        for (function, _) in EXERCISED_BUILTINS {
+            let baseline = invoke_resources(test_input, function, vec![Felt::ZERO])
+                .await?
+                .builtins;
+            let exercised = invoke_resources(test_input, function, vec![Felt::from(ITERATIONS)])
+                .await?
+                .builtins;
+
+            for (builtin, counter) in EXERCISED_BUILTINS {
+                let expected = if builtin == function { ITERATIONS } else { 0 };
+                assert_eq_result!(
+                    counter(&exercised).checked_sub(counter(&baseline)),
+                    Some(expected),
+                    "Growth of the {} counter from {}(0) to {}({})",
+                    builtin,
+                    function,
+                    function,
+                    ITERATIONS
+                );
+            }
+        }
+
+        Ok(Self {})
+    }
+}
@@ -0,0 +1,53 @@
+use production_nodes_types::pathfinder_types::types::receipt::ExecutionResources;
+use starknet_types_core::felt::Felt;
+
+use super::{invoke_resources, ITERATIONS};
+use crate::{
+    assert_eq_result, assert_result, utils::v7::endpoints::errors::OpenRpcTestGenError,
+    RunnableTrait,
+};
+
+#[derive(Clone, Debug)]
+pub struct TestCase {}
+
+impl RunnableTrait for TestCase {
+    type Input = super::TestSuiteExecutionResources;
+
+    /// Only the storage values that change are published, so writing new slots costs data
+    /// availability gas while writing the same values again costs as much as writing nothing.
+    async fn run(test_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let base = Felt::from(rand::random::<u64>());
+
+        let baseline = invoke_resources(test_input, "store", vec![base, Felt::ZERO]).await?;
+        let written =
+            invoke_resources(test_input, "store", vec![base, Felt::from(ITERATIONS)]).await?;
+        let rewritten =
+            invoke_resources(test_input, "store", vec![base, Felt::from(ITERATIONS)]).await?;
+
+        let (baseline, written, rewritten) = (
+            data_availability_gas(&baseline),
+            data_availability_gas(&written),
+            data_availability_gas(&rewritten),
+        );
+        assert_result!(
+            written > baseline,
+            format!(
+                "Data availability gas {} of {} new storage slots doesn't exceed {} of none",
+                written, ITERATIONS, baseline
+            )
+        );
+        assert_eq_result!(
+            rewritten,
+            baseline,
+            "Data availability gas of {} unchanged storage slots",
+            ITERATIONS
+        );
+
+        Ok(Self {})
+    }
+}
+
+/// Gas of data availability, spent as L1 gas or L1 data gas depending on the DA mode of the block.
+fn data_availability_gas(resources: &ExecutionResources) -> u128 {
+    resources.data_availability.l1_gas + resources.data_availability.l1_data_gas
+}
@@ -0,0 +1,45 @@
+use starknet_types_core::felt::Felt;
+
+use super::{invoke_resources, ITERATIONS};
+use crate::{
+    assert_eq_result, assert_result, utils::v7::endpoints::errors::OpenRpcTestGenError,
+    RunnableTrait,
+};
+
+#[derive(Clone, Debug)]
+pub struct TestCase {}
+
+impl RunnableTrait for TestCase {
+    type Input = super::TestSuiteExecutionResources;
+
+    /// The range checks of an iteration also depend on the compiled gas accounting, so the
+    /// counter is checked to grow by the same amount with every iteration.
+    async fn run(test_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let mut range_checks = vec![];
+        for iterations in [0, ITERATIONS, 2 * ITERATIONS] {
+            let resources =
+                invoke_resources(test_input, "range_check", vec![Felt::from(iterations)]).await?;
+            range_checks.push(resources.builtins.range_check);
+        }
+
+        let growth = range_checks[1].checked_sub(range_checks[0]);
+        let per_iteration_growth = growth.is_some_and(|growth| growth >= ITERATIONS);
+        assert_result!(
+            per_iteration_growth,
+            format!(
+                "Range check counter for 0, {} and {} iterations: {:?}",
+                ITERATIONS,
+                2 * ITERATIONS,
+                range_checks
+            )
+        );
+        assert_eq_result!(
+            range_checks[2].checked_sub(range_checks[1]),
+            growth,
+            "Growth of the range check counter over the second {} iterations",
+            ITERATIONS
+        );
+
+        Ok(Self {})
+    }
+}
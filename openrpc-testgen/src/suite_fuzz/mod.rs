@@ -0,0 +1,131 @@
+use rand::Rng;
+use starknet_types_core::felt::Felt;
+use starknet_types_rpc::{BlockId, BlockTag};
+use tracing::info;
+use url::Url;
+
+use crate::{
+    utils::{
+        random_single_owner_account::RandomSingleOwnerAccount,
+        v7::{
+            accounts::{
+                creation::helpers::get_chain_id,
+                single_owner::{ExecutionEncoding, SingleOwnerAccount},
+            },
+            endpoints::errors::OpenRpcTestGenError,
+            fuzzing::{Finding, TxnFuzzer},
+            providers::jsonrpc::{HttpTransport, JsonRpcClient},
+            signers::{key_pair::SigningKey, local_wallet::LocalWallet},
+        },
+    },
+    SetupableTrait,
+};
+pub mod test_fuzz_declare_txn_v2;
+pub mod test_fuzz_declare_txn_v3;
+pub mod test_fuzz_deploy_account_txn_v1;
+pub mod test_fuzz_deploy_account_txn_v3;
+pub mod test_fuzz_invoke_txn_v1;
+pub mod test_fuzz_invoke_txn_v3;
+
+/// Number of mutated transactions submitted by every test in the suite.
+pub const FUZZ_CASES: usize = 64;
+
+/// L1 gas bound of the seed transactions. Setting it skips fee estimation, which would fail for
+/// seeds that are not executable, e.g. declarations of already declared classes.
+pub const SEED_L1_GAS: u64 = 100_000;
+
+/// Max fee in WEI of the V1 and V2 seeds, set to skip fee estimation like [SEED_L1_GAS].
+pub const SEED_MAX_FEE: u64 = 1_000_000_000_000_000;
+
+#[derive(Clone, Debug)]
+pub struct TestSuiteFuzz {
+    pub random_paymaster_account: RandomSingleOwnerAccount,
+    pub account_class_hash: Felt,
+    pub urls: Vec<Url>,
+}
+
+#[derive(Clone, Debug)]
+pub struct SetupInput {
+    pub urls: Vec<Url>,
+    pub paymaster_account_address: Felt,
+    pub paymaster_private_key: Felt,
+    pub account_class_hash: Felt,
+    pub udc_address: Felt,
+}
+
+impl SetupableTrait for TestSuiteFuzz {
+    type Input = SetupInput;
+
+    async fn setup(setup_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let paymaster_private_key =
+            SigningKey::from_secret_scalar(setup_input.paymaster_private_key);
+
+        let mut paymaster_accounts = vec![];
+        for url in &setup_input.urls {
+            let provider = JsonRpcClient::new(HttpTransport::new(url.clone()));
+            let chain_id = get_chain_id(&provider).await?;
+
+            let mut paymaster_account = SingleOwnerAccount::new(
+                provider,
+                LocalWallet::from(paymaster_private_key),
+                setup_input.paymaster_account_address,
+                chain_id,
+                ExecutionEncoding::New,
+            );
+            paymaster_account.set_block_id(BlockId::Tag(BlockTag::Pending));
+
+            paymaster_accounts.push(paymaster_account);
+        }
+
+        Ok(Self {
+            random_paymaster_account: RandomSingleOwnerAccount {
+                accounts: paymaster_accounts,
+            },
+            account_class_hash: setup_input.account_class_hash,
+            urls: setup_input.urls.clone(),
+        })
+    }
+}
+
+impl TestSuiteFuzz {
+    /// Picks a random node and returns its paymaster account along with a fuzzer submitting to
+    /// the same node. The fuzzer seed is logged so that a failing campaign can be replayed.
+    pub fn random_target(
+        &self,
+    ) -> Result<
+        (
+            SingleOwnerAccount<JsonRpcClient<HttpTransport>, LocalWallet>,
+            TxnFuzzer,
+        ),
+        OpenRpcTestGenError,
+    > {
+        if self.urls.is_empty() {
+            return Err(OpenRpcTestGenError::EmptyUrlList(
+                "Accounts list is empty - no urls.".to_string(),
+            ));
+        }
+
+        let mut rng = rand::thread_rng();
+        let index = rng.gen_range(0..self.urls.len());
+        let seed: u64 = rng.gen();
+        info!("Fuzzing {} with seed {}", self.urls[index], seed);
+
+        let account = self.random_paymaster_account.accounts[index].clone();
+        let fuzzer = TxnFuzzer::new(HttpTransport::new(self.urls[index].clone()), seed);
+
+        Ok((account, fuzzer))
+    }
+}
+
+/// Formats findings of a campaign, one minimal reproducer per finding.
+pub fn findings_report(findings: &[Finding]) -> String {
+    let mut report = format!("{} fuzz case(s) violated the specification", findings.len());
+    for finding in findings {
+        report.push('\n');
+        report.push_str(&finding.to_string());
+    }
+    report
+}
+
+#[cfg(not(feature = "rust-analyzer"))]
+include!(concat!(env!("OUT_DIR"), "/generated_tests_suite_fuzz.rs"));
@@ -0,0 +1,49 @@
+use std::sync::Arc;
+
+use crate::{
+    assert_result,
+    suite_fuzz::{findings_report, FUZZ_CASES, SEED_MAX_FEE},
+    utils::v7::{
+        accounts::account::Account, contract::registry::ContractRegistry,
+        endpoints::errors::OpenRpcTestGenError, fuzzing::TxnKind,
+    },
+    RunnableTrait,
+};
+use starknet_types_core::felt::Felt;
+use starknet_types_rpc::v0_7_1::{BroadcastedDeclareTxn, BroadcastedTxn};
+
+#[derive(Clone, Debug)]
+pub struct TestCase {}
+
+impl RunnableTrait for TestCase {
+    type Input = super::TestSuiteFuzz;
+
+    async fn run(test_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let (account, mut fuzzer) = test_input.random_target()?;
+
+        let (flattened_sierra_class, compiled_class_hash) =
+            ContractRegistry::global().get("contracts_sample_contract_4_HelloStarknet")?;
+        let flattened_sierra_class = Arc::new(flattened_sierra_class);
+
+        let mut findings = vec![];
+        for _ in 0..FUZZ_CASES {
+            let prepared = account
+                .declare_v2(flattened_sierra_class.clone(), compiled_class_hash)
+                .max_fee(Felt::from(SEED_MAX_FEE))
+                .prepare()
+                .await?;
+            let declare_request = prepared.get_declare_request(false, false).await?;
+            let seed = serde_json::to_value(BroadcastedTxn::Declare(BroadcastedDeclareTxn::V2(
+                declare_request,
+            )))?;
+
+            if let Some(finding) = fuzzer.fuzz_case(TxnKind::Declare, &seed).await {
+                findings.push(finding);
+            }
+        }
+
+        assert_result!(findings.is_empty(), findings_report(&findings));
+
+        Ok(Self {})
+    }
+}
@@ -0,0 +1,85 @@
+use crate::{
+    assert_result,
+    suite_fuzz::{findings_report, FUZZ_CASES, SEED_MAX_FEE},
+    utils::v7::{
+        accounts::{
+            account::{Account, ConnectedAccount},
+            call::Call,
+            factory::{open_zeppelin::OpenZeppelinAccountFactory, AccountFactory},
+        },
+        endpoints::{
+            errors::OpenRpcTestGenError,
+            utils::{get_selector_from_name, wait_for_sent_transaction},
+        },
+        fuzzing::TxnKind,
+        signers::{key_pair::SigningKey, local_wallet::LocalWallet},
+    },
+    RunnableTrait,
+};
+use starknet_types_core::felt::Felt;
+use starknet_types_rpc::{
+    v0_7_1::{BroadcastedDeployAccountTxn, BroadcastedTxn},
+    BlockId, BlockTag,
+};
+
+#[derive(Clone, Debug)]
+pub struct TestCase {}
+
+impl RunnableTrait for TestCase {
+    type Input = super::TestSuiteFuzz;
+
+    async fn run(test_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let (account, mut fuzzer) = test_input.random_target()?;
+
+        let mut factory = OpenZeppelinAccountFactory::new(
+            test_input.account_class_hash,
+            account.chain_id(),
+            LocalWallet::from(SigningKey::from_random()),
+            account.provider().clone(),
+        )
+        .await?;
+        factory.set_block_id(BlockId::Tag(BlockTag::Pending));
+
+        let salt = Felt::from(rand::random::<u64>());
+        let deploy_address = factory.deploy_v1(salt).address();
+
+        // V1 deployments pay in ETH, so the undeployed account is funded with ETH for the seeds
+        // not to be rejected for insufficient balance before the mutated fields are looked at.
+        let fund_result = account
+            .execute_v3(vec![Call {
+                to: Felt::from_hex_unchecked(
+                    "0x049d36570d4e46f48e99674bd3fcc84644ddd6b96f7c741b1562b82f9e004dc7",
+                ),
+                selector: get_selector_from_name("transfer")?,
+                calldata: vec![
+                    deploy_address,
+                    Felt::from(1_000_000_000_000_000_000_u128),
+                    Felt::ZERO,
+                ],
+            }])
+            .send()
+            .await?;
+        wait_for_sent_transaction(fund_result.transaction_hash, &account).await?;
+
+        let mut findings = vec![];
+        for _ in 0..FUZZ_CASES {
+            let prepared = factory
+                .deploy_v1(salt)
+                .max_fee(Felt::from(SEED_MAX_FEE))
+                .prepare()
+                .await?;
+            let deploy_request = prepared.get_deploy_request(false, false).await?;
+            let seed = serde_json::to_value(BroadcastedTxn::DeployAccount(
+                BroadcastedDeployAccountTxn::V1(deploy_request),
+            ))?;
+
+            if let Some(finding) = fuzzer.fuzz_case(TxnKind::DeployAccount, &seed).await {
+                findings.push(finding);
+            }
+        }
+
+        assert_result!(findings.is_empty(), findings_report(&findings));
+
+        Ok(Self {})
+    }
+}
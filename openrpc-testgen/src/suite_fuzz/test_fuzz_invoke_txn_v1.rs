@@ -0,0 +1,60 @@
+use crate::{
+    assert_result,
+    suite_fuzz::{findings_report, FUZZ_CASES, SEED_MAX_FEE},
+    utils::v7::{
+        accounts::{
+            account::{Account, AccountError},
+            call::Call,
+        },
+        endpoints::{errors::OpenRpcTestGenError, utils::get_selector_from_name},
+        fuzzing::TxnKind,
+    },
+    RunnableTrait,
+};
+use starknet_types_core::felt::Felt;
+use starknet_types_rpc::v0_7_1::{BroadcastedInvokeTxn, BroadcastedTxn};
+
+#[derive(Clone, Debug)]
+pub struct TestCase {}
+
+impl RunnableTrait for TestCase {
+    type Input = super::TestSuiteFuzz;
+
+    async fn run(test_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let (account, mut fuzzer) = test_input.random_target()?;
+
+        // Transferring zero ETH to itself keeps the seed harmless if a mutation gets accepted.
+        let transfer = Call {
+            to: Felt::from_hex_unchecked(
+                "0x049d36570d4e46f48e99674bd3fcc84644ddd6b96f7c741b1562b82f9e004dc7",
+            ),
+            selector: get_selector_from_name("transfer")?,
+            calldata: vec![account.address(), Felt::ZERO, Felt::ZERO],
+        };
+
+        let mut findings = vec![];
+        for _ in 0..FUZZ_CASES {
+            // The seed is rebuilt on every case, as an accepted mutation bumps the nonce.
+            let prepared = account
+                .execute_v1(vec![transfer.clone()])
+                .max_fee(Felt::from(SEED_MAX_FEE))
+                .prepare()
+                .await?;
+            let invoke_request = prepared
+                .get_invoke_request(false, false)
+                .await
+                .map_err(AccountError::Signing)?;
+            let seed = serde_json::to_value(BroadcastedTxn::Invoke(BroadcastedInvokeTxn::V1(
+                invoke_request,
+            )))?;
+
+            if let Some(finding) = fuzzer.fuzz_case(TxnKind::Invoke, &seed).await {
+                findings.push(finding);
+            }
+        }
+
+        assert_result!(findings.is_empty(), findings_report(&findings));
+
+        Ok(Self {})
+    }
+}
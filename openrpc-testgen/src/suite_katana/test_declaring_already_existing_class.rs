@@ -2,10 +2,8 @@ use crate::{
     assert_matches_result, assert_result,
     utils::v7::{
         accounts::account::{Account, AccountError, ConnectedAccount},
-        endpoints::{
-            declare_contract::get_compiled_contract, errors::OpenRpcTestGenError,
-            utils::wait_for_sent_transaction,
-        },
+        contract::registry::ContractRegistry,
+        endpoints::{errors::OpenRpcTestGenError, utils::wait_for_sent_transaction},
         providers::{
             jsonrpc::StarknetError,
             provider::{Provider, ProviderError},
@@ -15,7 +13,7 @@ use crate::{
 };
 use starknet_types_core::felt::Felt;
 use starknet_types_rpc::{BlockId, BlockTag};
-use std::{path::PathBuf, str::FromStr, sync::Arc};
+use std::sync::Arc;
 
 #[derive(Clone, Debug)]
 pub struct TestCase {}
@@ -23,11 +21,8 @@ pub struct TestCase {}
 impl RunnableTrait for TestCase {
     type Input = super::TestSuiteKatana;
     async fn run(test_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
-        let (flattened_sierra_class, compiled_class_hash) = get_compiled_contract(
-            PathBuf::from_str("target/dev/contracts_contracts_sample_contract_2_HelloStarknet.contract_class.json")?,
-            PathBuf::from_str("target/dev/contracts_contracts_sample_contract_2_HelloStarknet.compiled_contract_class.json")?,
-        )
-        .await?;
+        let (flattened_sierra_class, compiled_class_hash) =
+            ContractRegistry::global().get("contracts_sample_contract_2_HelloStarknet")?;
 
         let account = test_input.random_paymaster_account.random_accounts()?;
         let provider = account.provider().clone();
@@ -1,11 +1,10 @@
 use core::fmt;
-use std::{path::PathBuf, str::FromStr, time::Duration};
+use std::time::Duration;
 
 use rand::{rngs::StdRng, RngCore, SeedableRng};
 use starknet_types_core::felt::Felt;
 use starknet_types_rpc::{
-    BlockId, BlockTag, ClassAndTxnHash, DeclareTxn, EventFilterWithPageRequest, Txn,
-    TxnExecutionStatus, TxnFinalityAndExecutionStatus, TxnReceipt, TxnStatus,
+    BlockId, BlockTag, TxnExecutionStatus, TxnFinalityAndExecutionStatus, TxnReceipt, TxnStatus,
 };
 use tracing::info;
 use url::Url;
@@ -15,7 +14,7 @@ use crate::{
         random_single_owner_account::RandomSingleOwnerAccount,
         v7::{
             accounts::{
-                account::{Account, AccountError, ConnectedAccount},
+                account::{Account, ConnectedAccount},
                 call::Call,
                 creation::{
                     create::{create_account, AccountType},
@@ -23,18 +22,15 @@ use crate::{
                 },
                 single_owner::{ExecutionEncoding, SingleOwnerAccount},
             },
-            contract::factory::ContractFactory,
+            contract::{declare_and_deploy::ensure_declared, factory::ContractFactory},
+            devnet::DevnetClient,
             endpoints::{
-                declare_contract::{
-                    extract_class_hash_from_error, get_compiled_contract,
-                    parse_class_hash_from_error, RunnerError,
-                },
                 errors::{CallError, ContinuationTokenError, OpenRpcTestGenError},
                 utils::get_selector_from_name,
             },
             providers::{
                 jsonrpc::{HttpTransport, JsonRpcClient},
-                provider::{Provider, ProviderError},
+                provider::Provider,
             },
             signers::{key_pair::SigningKey, local_wallet::LocalWallet},
         },
@@ -52,7 +48,7 @@ pub struct TestSuiteKatanaNoAccountValidation {
     pub account_class_hash: Felt,
     pub udc_address: Felt,
     pub deployed_contract_address: Felt,
-    pub dev_client: DevClient,
+    pub dev_client: DevnetClient,
 }
 
 #[derive(Clone, Debug)]
@@ -68,17 +64,7 @@ impl SetupableTrait for TestSuiteKatanaNoAccountValidation {
     type Input = SetupInput;
 
     async fn setup(setup_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
-        let (executable_account_flattened_sierra_class, executable_account_compiled_class_hash) =
-            get_compiled_contract(
-                PathBuf::from_str("target/dev/contracts_ExecutableAccount.contract_class.json")?,
-                PathBuf::from_str(
-                    "target/dev/contracts_ExecutableAccount.compiled_contract_class.json",
-                )?,
-            )
-            .await
-            .unwrap();
-
-        let dev_client = DevClient::new(setup_input.urls[0].clone());
+        let dev_client = DevnetClient::katana(setup_input.urls[0].clone());
 
         let provider = JsonRpcClient::new(HttpTransport::new(setup_input.urls[0].clone()));
         let chain_id = get_chain_id(&provider).await?;
@@ -96,56 +82,12 @@ impl SetupableTrait for TestSuiteKatanaNoAccountValidation {
 
         paymaster_account.set_block_id(BlockId::Tag(BlockTag::Pending));
 
-        let declare_executable_account_hash = match paymaster_account
-            .declare_v3(
-                executable_account_flattened_sierra_class.clone(),
-                executable_account_compiled_class_hash,
-            )
-            .send()
-            .await
-        {
-            Ok(result) => {
-                wait_for_sent_transaction_katana(result.transaction_hash, &paymaster_account)
-                    .await?;
-                dev_client.generate_block().await?;
-                Ok(result.class_hash)
-            }
-            Err(AccountError::Signing(sign_error)) => {
-                if sign_error.to_string().contains("is already declared") {
-                    Ok(parse_class_hash_from_error(&sign_error.to_string())?)
-                } else {
-                    Err(OpenRpcTestGenError::RunnerError(
-                        RunnerError::AccountFailure(format!(
-                            "Transaction execution error: {}",
-                            sign_error
-                        )),
-                    ))
-                }
-            }
-
-            Err(AccountError::Provider(ProviderError::Other(starkneterror))) => {
-                if starkneterror.to_string().contains("is already declared") {
-                    Ok(parse_class_hash_from_error(&starkneterror.to_string())?)
-                } else {
-                    Err(OpenRpcTestGenError::RunnerError(
-                        RunnerError::AccountFailure(format!(
-                            "Transaction execution error: {}",
-                            starkneterror
-                        )),
-                    ))
-                }
-            }
-            Err(e) => {
-                let full_error_message = format!("{:?}", e);
-                if full_error_message.contains("is already declared") {
-                    Ok(extract_class_hash_from_error(&full_error_message)?)
-                } else {
-                    Err(OpenRpcTestGenError::AccountError(AccountError::Other(
-                        full_error_message,
-                    )))
-                }
-            }
-        }?;
+        let executable_account_declaration =
+            ensure_declared(&paymaster_account, "ExecutableAccount").await?;
+        if executable_account_declaration.transaction_hash().is_some() {
+            dev_client.create_block().await?;
+        }
+        let declare_executable_account_hash = executable_account_declaration.class_hash();
 
         let executable_account_data = create_account(
             &provider,
@@ -178,7 +120,7 @@ impl SetupableTrait for TestSuiteKatanaNoAccountValidation {
         )
         .await?;
 
-        dev_client.generate_block().await?;
+        dev_client.create_block().await?;
 
         let mut executable_account = SingleOwnerAccount::new(
             provider.clone(),
@@ -223,136 +165,17 @@ impl SetupableTrait for TestSuiteKatanaNoAccountValidation {
             accounts: paymaster_accounts,
         };
 
-        let (flattened_sierra_class, compiled_class_hash) =
-        get_compiled_contract(
-            PathBuf::from_str("target/dev/contracts_contracts_sample_contract_1_HelloStarknet.contract_class.json")?,
-        PathBuf::from_str("target/dev/contracts_contracts_sample_contract_1_HelloStarknet.compiled_contract_class.json")?,
+        let declaration_result = ensure_declared(
+            &random_paymaster_account.random_accounts()?,
+            "contracts_sample_contract_1_HelloStarknet",
         )
         .await?;
-
-        let declaration_result = match random_paymaster_account
-            .declare_v3(flattened_sierra_class, compiled_class_hash)
-            .send()
-            .await
-        {
-            Ok(result) => {
-                wait_for_sent_transaction_katana(
-                    result.transaction_hash,
-                    &random_paymaster_account.random_accounts()?,
-                )
-                .await?;
-                dev_client.generate_block().await?;
-                Ok(result)
-            }
-            Err(AccountError::Signing(sign_error)) => {
-                if sign_error.to_string().contains("is already declared") {
-                    Ok(ClassAndTxnHash {
-                        class_hash: parse_class_hash_from_error(&sign_error.to_string())?,
-                        transaction_hash: Felt::ZERO,
-                    })
-                } else {
-                    Err(OpenRpcTestGenError::RunnerError(
-                        RunnerError::AccountFailure(format!(
-                            "Transaction execution error: {}",
-                            sign_error
-                        )),
-                    ))
-                }
-            }
-
-            Err(AccountError::Provider(ProviderError::Other(starkneterror))) => {
-                if starkneterror.to_string().contains("is already declared") {
-                    Ok(ClassAndTxnHash {
-                        class_hash: parse_class_hash_from_error(&starkneterror.to_string())?,
-                        transaction_hash: Felt::ZERO,
-                    })
-                } else {
-                    Err(OpenRpcTestGenError::RunnerError(
-                        RunnerError::AccountFailure(format!(
-                            "Transaction execution error: {}",
-                            starkneterror
-                        )),
-                    ))
-                }
-            }
-            Err(e) => {
-                let full_error_message = format!("{:?}", e);
-
-                if full_error_message.contains("is already declared") {
-                    let class_hash = extract_class_hash_from_error(&full_error_message)?;
-
-                    let filter = EventFilterWithPageRequest {
-                        address: None,
-                        from_block: Some(BlockId::Number(322421)),
-                        to_block: Some(BlockId::Number(322421)),
-                        keys: Some(vec![vec![]]),
-                        chunk_size: 100,
-                        continuation_token: None,
-                    };
-
-                    let provider = random_paymaster_account.provider();
-                    let random_account_address =
-                        random_paymaster_account.random_accounts()?.address();
-
-                    let mut continuation_token = None;
-                    let mut found_txn_hash = None;
-
-                    loop {
-                        let mut current_filter = filter.clone();
-                        current_filter.continuation_token = continuation_token.clone();
-
-                        let events_chunk = provider.get_events(current_filter).await?;
-
-                        for event in events_chunk.events {
-                            if event.event.data.contains(&random_account_address) {
-                                let txn_hash = event.transaction_hash;
-
-                                let txn_details =
-                                    provider.get_transaction_by_hash(txn_hash).await?;
-
-                                if let Txn::Declare(DeclareTxn::V3(declare_txn)) = txn_details {
-                                    if declare_txn.class_hash == class_hash {
-                                        found_txn_hash = Some(txn_hash);
-                                        break;
-                                    }
-                                }
-                            }
-                        }
-
-                        if found_txn_hash.is_some() {
-                            break;
-                        }
-
-                        if let Some(token) = events_chunk.continuation_token {
-                            continuation_token = Some(token);
-                        } else {
-                            break;
-                        }
-                    }
-
-                    if let Some(tx_hash) = found_txn_hash {
-                        Ok(ClassAndTxnHash {
-                            class_hash,
-                            transaction_hash: tx_hash,
-                        })
-                    } else {
-                        info!("Transaction hash not found for the declared clas");
-                        Err(OpenRpcTestGenError::RunnerError(
-                            RunnerError::AccountFailure(
-                                "Transaction hash not found for the declared class.".to_string(),
-                            ),
-                        ))
-                    }
-                } else {
-                    return Err(OpenRpcTestGenError::AccountError(AccountError::Other(
-                        full_error_message,
-                    )));
-                }
-            }
-        }?;
+        if declaration_result.transaction_hash().is_some() {
+            dev_client.create_block().await?;
+        }
 
         let factory = ContractFactory::new(
-            declaration_result.class_hash,
+            declaration_result.class_hash(),
             random_paymaster_account.random_accounts()?,
         );
         let mut salt_buffer = [0u8; 32];
@@ -369,7 +192,7 @@ impl SetupableTrait for TestSuiteKatanaNoAccountValidation {
             &random_paymaster_account.random_accounts()?,
         )
         .await?;
-        dev_client.generate_block().await?;
+        dev_client.create_block().await?;
 
         let deployment_receipt = random_paymaster_account
             .provider()
@@ -399,7 +222,7 @@ impl SetupableTrait for TestSuiteKatanaNoAccountValidation {
             }
         };
 
-        dev_client.generate_block().await?;
+        dev_client.create_block().await?;
 
         Ok(Self {
             random_executable_account,
@@ -413,33 +236,6 @@ impl SetupableTrait for TestSuiteKatanaNoAccountValidation {
     }
 }
 
-#[derive(Clone, Debug)]
-pub struct DevClient {
-    pub url: Url,
-}
-
-impl DevClient {
-    pub fn new(url: Url) -> Self {
-        Self { url }
-    }
-
-    pub async fn generate_block(&self) -> Result<(), OpenRpcTestGenError> {
-        let client = reqwest::Client::new();
-        client
-            .post(self.url.clone())
-            .json(&serde_json::json!({
-                "jsonrpc": "2.0",
-                "method": "dev_generateBlock",
-                "params": [],
-                "id": 1
-            }))
-            .send()
-            .await
-            .map_err(OpenRpcTestGenError::RequestError)?;
-        Ok(())
-    }
-}
-
 pub async fn wait_for_sent_transaction_katana(
     transaction_hash: Felt,
     user_passed_account: &SingleOwnerAccount<JsonRpcClient<HttpTransport>, LocalWallet>,
@@ -52,7 +52,7 @@ impl RunnableTrait for TestCase {
         }
 
         // Generate a block to include the transactions.
-        dev_client.generate_block().await?;
+        dev_client.create_block().await?;
 
         // Get the traces of the transactions in block.
         let block_id = BlockId::Tag(BlockTag::Latest);
@@ -84,7 +84,7 @@ impl RunnableTrait for TestCase {
         }
 
         // Generate a block to include the transactions. The generated block will have block number 2.
-        dev_client.generate_block().await?;
+        dev_client.create_block().await?;
 
         // Get the traces of the transactions in block.
         let block_id = BlockId::Tag(BlockTag::Latest);
@@ -126,7 +126,7 @@ impl RunnableTrait for TestCase {
         }
 
         // Generate new block for the pending transactions.
-        dev_client.generate_block().await?;
+        dev_client.create_block().await?;
 
         Ok(Self {})
     }
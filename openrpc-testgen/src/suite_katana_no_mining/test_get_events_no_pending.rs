@@ -51,7 +51,7 @@ impl RunnableTrait for TestCase {
         }
 
         // Generate new block for the pending transactions.
-        dev_client.generate_block().await?;
+        dev_client.create_block().await?;
 
         let block_number = provider.block_number().await?;
 
@@ -50,7 +50,7 @@ impl RunnableTrait for TestCase {
         }
 
         // Generate new block for the pending transactions.
-        dev_client.generate_block().await?;
+        dev_client.create_block().await?;
 
         for _ in 0..PENDING_BLOCK_TX_COUNT {
             let res = account
@@ -138,7 +138,7 @@ impl RunnableTrait for TestCase {
         assert_eq_result!(events.len(), 0);
         assert_eq!(new_token, filter.continuation_token);
 
-        dev_client.generate_block().await?;
+        dev_client.create_block().await?;
 
         Ok(Self {})
     }
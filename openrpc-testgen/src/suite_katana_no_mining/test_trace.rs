@@ -52,7 +52,7 @@ impl RunnableTrait for TestCase {
         }
 
         // Generate a block to include the transactions.
-        dev_client.generate_block().await?;
+        dev_client.create_block().await?;
 
         for hash in hashes {
             let trace = provider.trace_transaction(hash).await?;
@@ -74,7 +74,7 @@ impl RunnableTrait for TestCase {
         }
 
         // Generate new block for the pending transactions.
-        dev_client.generate_block().await?;
+        dev_client.create_block().await?;
 
         Ok(Self {})
     }
@@ -0,0 +1,108 @@
+use starknet_types_core::felt::Felt;
+use starknet_types_rpc::{BlockId, BlockTag};
+use url::Url;
+
+use crate::{
+    utils::v7::{
+        accounts::{
+            creation::helpers::get_chain_id,
+            single_owner::{ExecutionEncoding, SingleOwnerAccount},
+        },
+        contract::declare_and_deploy::{ensure_declared, ensure_deployed},
+        devnet::DevnetClient,
+        endpoints::errors::OpenRpcTestGenError,
+        messaging::ethereum::EthereumClient,
+        providers::jsonrpc::{HttpTransport, JsonRpcClient},
+        signers::{key_pair::SigningKey, local_wallet::LocalWallet},
+    },
+    SetupableTrait,
+};
+
+pub mod test_message_to_l1;
+pub mod test_message_to_l2;
+
+/// Fee in wei paid on L1 for the execution of the L1 handler.
+pub const L1_MESSAGE_FEE: u128 = 1_000_000_000_000;
+
+/// Messaging conformance suite, run against the first node only, since Devnet relays messages
+/// for the node `devnet_postmanLoad` was called on.
+#[derive(Clone, Debug)]
+pub struct TestSuiteMessaging {
+    pub paymaster_account: SingleOwnerAccount<JsonRpcClient<HttpTransport>, LocalWallet>,
+    pub devnet_client: DevnetClient,
+    pub chain_id: Felt,
+    pub ethereum_client: EthereumClient,
+    /// `MockStarknetMessaging` contract on L1.
+    pub messaging_contract_address: String,
+    /// `L1L2Messaging` contract on L2.
+    pub l2_contract_address: Felt,
+}
+
+#[derive(Clone, Debug)]
+pub struct SetupInput {
+    pub urls: Vec<Url>,
+    pub paymaster_account_address: Felt,
+    pub paymaster_private_key: Felt,
+    pub account_class_hash: Felt,
+    pub udc_address: Felt,
+    pub l1_url: Url,
+}
+
+impl SetupableTrait for TestSuiteMessaging {
+    type Input = SetupInput;
+
+    async fn setup(setup_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let url = setup_input
+            .urls
+            .first()
+            .ok_or_else(|| OpenRpcTestGenError::EmptyUrlList("No L2 node urls.".to_string()))?
+            .clone();
+
+        let provider = JsonRpcClient::new(HttpTransport::new(url.clone()));
+        let chain_id = get_chain_id(&provider).await?;
+
+        let mut paymaster_account = SingleOwnerAccount::new(
+            provider,
+            LocalWallet::from(SigningKey::from_secret_scalar(
+                setup_input.paymaster_private_key,
+            )),
+            setup_input.paymaster_account_address,
+            chain_id,
+            ExecutionEncoding::New,
+        );
+        paymaster_account.set_block_id(BlockId::Tag(BlockTag::Pending));
+
+        let class_hash = ensure_declared(&paymaster_account, "L1L2Messaging")
+            .await?
+            .class_hash();
+        let l2_contract_address = ensure_deployed(
+            &paymaster_account,
+            setup_input.udc_address,
+            class_hash,
+            Felt::ZERO,
+            vec![],
+        )
+        .await?
+        .contract_address();
+
+        let devnet_client = DevnetClient::devnet(url);
+        let messaging_contract_address = devnet_client
+            .postman_load(&setup_input.l1_url, None)
+            .await?;
+
+        Ok(Self {
+            paymaster_account,
+            devnet_client,
+            chain_id,
+            ethereum_client: EthereumClient::new(setup_input.l1_url.clone()),
+            messaging_contract_address,
+            l2_contract_address,
+        })
+    }
+}
+
+#[cfg(not(feature = "rust-analyzer"))]
+include!(concat!(
+    env!("OUT_DIR"),
+    "/generated_tests_suite_messaging.rs"
+));
@@ -0,0 +1,90 @@
+use crate::{
+    assert_eq_result, assert_result,
+    utils::{
+        conversions::felt_field::felt_field,
+        v7::{
+            accounts::{
+                account::{Account, ConnectedAccount},
+                call::Call,
+            },
+            endpoints::{
+                errors::OpenRpcTestGenError,
+                utils::{get_selector_from_name, wait_for_sent_transaction},
+            },
+            providers::provider::Provider,
+        },
+    },
+    RunnableTrait,
+};
+use rand::Rng;
+use starknet_types_core::felt::Felt;
+
+#[derive(Clone, Debug)]
+pub struct TestCase {}
+
+impl RunnableTrait for TestCase {
+    type Input = super::TestSuiteMessaging;
+
+    async fn run(test_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let account = &test_input.paymaster_account;
+        let provider = account.provider();
+
+        let to_address = Felt::from_hex(test_input.ethereum_client.account())?;
+        let mut rng = rand::thread_rng();
+        let user = Felt::from(rng.gen::<u64>());
+        let amount = Felt::from(rng.gen_range(1..1_000_u64));
+
+        let result = account
+            .execute_v3(vec![Call {
+                to: test_input.l2_contract_address,
+                selector: get_selector_from_name("withdraw")?,
+                calldata: vec![to_address, user, amount],
+            }])
+            .send()
+            .await?;
+        wait_for_sent_transaction(result.transaction_hash, account).await?;
+
+        let receipt = serde_json::to_value(
+            provider
+                .get_transaction_receipt(result.transaction_hash)
+                .await?,
+        )?;
+        let messages_sent = receipt["messages_sent"].as_array().ok_or_else(|| {
+            OpenRpcTestGenError::Other(format!("No messages_sent in receipt {}", receipt))
+        })?;
+
+        assert_eq_result!(
+            messages_sent.len(),
+            1,
+            "Expected a single message sent to L1, got {:?}",
+            messages_sent
+        );
+        let message = &messages_sent[0];
+
+        assert_eq_result!(
+            felt_field(message, "from_address"),
+            Some(test_input.l2_contract_address)
+        );
+        assert_eq_result!(felt_field(message, "to_address"), Some(to_address));
+        assert_eq_result!(
+            serde_json::from_value::<Vec<Felt>>(message["payload"].clone())?,
+            vec![user, amount]
+        );
+
+        let flushed = test_input.devnet_client.postman_flush().await?;
+        assert_result!(
+            flushed.messages_to_l1.iter().any(|flushed_message| {
+                felt_field(flushed_message, "from_address") == Some(test_input.l2_contract_address)
+                    && felt_field(flushed_message, "to_address") == Some(to_address)
+                    && serde_json::from_value::<Vec<Felt>>(flushed_message["payload"].clone())
+                        .is_ok_and(|payload| payload == [user, amount])
+            }),
+            format!(
+                "Flush did not send the withdrawal to L1, sent {:?}",
+                flushed.messages_to_l1
+            )
+        );
+
+        Ok(Self {})
+    }
+}
@@ -0,0 +1,132 @@
+use crate::{
+    assert_eq_result, assert_result,
+    utils::v7::{
+        accounts::account::ConnectedAccount,
+        endpoints::{
+            errors::OpenRpcTestGenError,
+            utils::{get_selector_from_name, wait_for_sent_transaction},
+        },
+        providers::provider::Provider,
+    },
+    RunnableTrait,
+};
+use rand::Rng;
+use starknet_types_core::felt::Felt;
+use starknet_types_rpc::{BlockId, BlockTag, FunctionCall};
+
+#[derive(Clone, Debug)]
+pub struct TestCase {}
+
+impl RunnableTrait for TestCase {
+    type Input = super::TestSuiteMessaging;
+
+    async fn run(test_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let provider = test_input.paymaster_account.provider();
+
+        let mut rng = rand::thread_rng();
+        let user = Felt::from(rng.gen::<u64>());
+        let amount = Felt::from(rng.gen_range(1..1_000_u64));
+        let initial_balance = get_balance(provider, test_input.l2_contract_address, user).await?;
+
+        let sent = test_input
+            .ethereum_client
+            .send_message_to_l2(
+                &test_input.messaging_contract_address,
+                test_input.l2_contract_address,
+                get_selector_from_name("deposit")?,
+                &[user, amount],
+                super::L1_MESSAGE_FEE,
+            )
+            .await?;
+        let message = sent.message;
+
+        let flushed = test_input.devnet_client.postman_flush().await?;
+        let transaction_hash = message.transaction_hash(test_input.chain_id);
+
+        assert_result!(
+            flushed
+                .generated_l2_transactions
+                .contains(&transaction_hash),
+            format!(
+                "L1 handler transaction {:#x} not among the flushed transactions {:?}",
+                transaction_hash, flushed.generated_l2_transactions
+            )
+        );
+
+        wait_for_sent_transaction(transaction_hash, &test_input.paymaster_account).await?;
+
+        let transaction =
+            serde_json::to_value(provider.get_transaction_by_hash(transaction_hash).await?)?;
+        assert_eq_result!(transaction["type"], "L1_HANDLER");
+        assert_eq_result!(
+            serde_json::from_value::<Vec<Felt>>(transaction["calldata"].clone())?,
+            message.calldata()
+        );
+
+        let receipt =
+            serde_json::to_value(provider.get_transaction_receipt(transaction_hash).await?)?;
+        assert_eq_result!(receipt["type"], "L1_HANDLER");
+        assert_eq_result!(receipt["execution_status"], "SUCCEEDED");
+
+        // The message hash is a keccak digest which may not fit in a felt, compare the hex strings.
+        let expected_message_hash: String = message
+            .hash()
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect();
+        let message_hash = receipt["message_hash"].as_str().unwrap_or_default();
+        assert_eq_result!(
+            normalize_hex(message_hash),
+            normalize_hex(&expected_message_hash),
+            "Receipt message hash {}, expected 0x{}",
+            message_hash,
+            expected_message_hash
+        );
+
+        let statuses = provider
+            .get_messages_status(sent.transaction_hash.clone())
+            .await?;
+        assert_result!(
+            statuses
+                .iter()
+                .any(|status| status.transaction_hash == transaction_hash),
+            format!(
+                "getMessagesStatus of {} does not report {:#x}: {:?}",
+                sent.transaction_hash, transaction_hash, statuses
+            )
+        );
+
+        let balance = get_balance(provider, test_input.l2_contract_address, user).await?;
+        assert_eq_result!(balance, initial_balance + amount);
+
+        Ok(Self {})
+    }
+}
+
+async fn get_balance<P: Provider>(
+    provider: &P,
+    contract_address: Felt,
+    user: Felt,
+) -> Result<Felt, OpenRpcTestGenError> {
+    let balance = provider
+        .call(
+            FunctionCall {
+                contract_address,
+                entry_point_selector: get_selector_from_name("get_balance")?,
+                calldata: vec![user],
+            },
+            BlockId::Tag(BlockTag::Pending),
+        )
+        .await?;
+
+    balance.first().copied().ok_or_else(|| {
+        OpenRpcTestGenError::Other(format!("Unexpected get_balance result {:?}", balance))
+    })
+}
+
+fn normalize_hex(value: &str) -> String {
+    value
+        .trim_start_matches("0x")
+        .trim_start_matches('0')
+        .to_lowercase()
+}
@@ -1,5 +1,3 @@
-use std::{path::PathBuf, str::FromStr};
-
 use starknet_types_core::felt::Felt;
 use starknet_types_rpc::{BlockId, BlockTag};
 use url::Url;
@@ -9,7 +7,7 @@ use crate::{
         random_single_owner_account::RandomSingleOwnerAccount,
         v7::{
             accounts::{
-                account::{Account, AccountError},
+                account::Account,
                 call::Call,
                 creation::{
                     create::{create_account, AccountType},
@@ -17,16 +15,16 @@ use crate::{
                 },
                 single_owner::{ExecutionEncoding, SingleOwnerAccount},
             },
+            contract::declare_and_deploy::ensure_declared,
             endpoints::{
-                declare_contract::{
-                    extract_class_hash_from_error, get_compiled_contract,
-                    parse_class_hash_from_error, RunnerError,
-                },
                 errors::OpenRpcTestGenError,
                 utils::{get_selector_from_name, wait_for_sent_transaction},
             },
             providers::{
-                jsonrpc::{HttpTransport, JsonRpcClient},
+                jsonrpc::{
+                    transports::http::HttpTransportError, HttpTransport, JsonRpcClient,
+                    JsonRpcClientError, StarknetError,
+                },
                 provider::ProviderError,
             },
             signers::{key_pair::SigningKey, local_wallet::LocalWallet},
@@ -34,11 +32,25 @@ use crate::{
     },
     SetupableTrait,
 };
+pub mod suite_class_hashes;
+pub mod suite_contract_upgrade;
 pub mod suite_deploy;
+pub mod suite_fee_estimation;
+pub mod suite_historical_state;
+pub mod suite_pending;
+pub mod suite_revert;
+pub mod suite_simulate;
+pub mod suite_storage_proof;
+pub mod suite_udc;
+pub mod test_add_declare_txn_query_version;
+pub mod test_add_deploy_account_txn_query_version;
+pub mod test_add_invoke_txn_query_version;
+pub mod test_declare_legacy_class;
 pub mod test_declare_txn_v2;
 pub mod test_declare_txn_v3;
 pub mod test_deploy_account_outside_execution;
 pub mod test_erc20_transfer;
+pub mod test_estimate_fee_query_version;
 pub mod test_get_block_number;
 pub mod test_get_block_txn_count;
 pub mod test_get_block_with_tx_hashes;
@@ -49,9 +61,32 @@ pub mod test_get_storage_at;
 pub mod test_get_transaction_by_hash;
 pub mod test_get_transaction_by_hash_non_existent;
 pub mod test_get_txn_by_block_id_and_index;
+pub mod test_outside_execution_v1;
+pub mod test_outside_execution_v2;
+pub mod test_outside_execution_v2_invalid_signature;
+pub mod test_remote_signer;
+pub mod test_simulate_query_version;
 // pub mod test_get_txn_by_block_id_and_index_deploy_account_v1;
 // pub mod test_get_txn_by_block_id_and_index_deploy_account_v3;
 
+/// JSON-RPC error code of requests whose params the node can't parse.
+const INVALID_PARAMS: i64 = -32602;
+
+/// Whether the node refused a query version transaction for its version, either answering
+/// `UNSUPPORTED_TX_VERSION` or rejecting the params outright.
+pub fn query_version_rejected(error: &ProviderError) -> bool {
+    match error {
+        ProviderError::StarknetError(StarknetError::UnsupportedTxVersion) => true,
+        ProviderError::Other(error) => matches!(
+            error
+                .as_any()
+                .downcast_ref::<JsonRpcClientError<HttpTransportError>>(),
+            Some(JsonRpcClientError::JsonRpc(error)) if error.code == INVALID_PARAMS
+        ),
+        _ => false,
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct TestSuiteOpenRpc {
     pub random_paymaster_account: RandomSingleOwnerAccount,
@@ -74,16 +109,6 @@ impl SetupableTrait for TestSuiteOpenRpc {
     type Input = SetupInput;
 
     async fn setup(setup_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
-        let (executable_account_flattened_sierra_class, executable_account_compiled_class_hash) =
-            get_compiled_contract(
-                PathBuf::from_str("target/dev/contracts_ExecutableAccount.contract_class.json")?,
-                PathBuf::from_str(
-                    "target/dev/contracts_ExecutableAccount.compiled_contract_class.json",
-                )?,
-            )
-            .await
-            .unwrap();
-
         let provider = JsonRpcClient::new(HttpTransport::new(setup_input.urls[0].clone()));
         let chain_id = get_chain_id(&provider).await?;
 
@@ -100,54 +125,10 @@ impl SetupableTrait for TestSuiteOpenRpc {
 
         paymaster_account.set_block_id(BlockId::Tag(BlockTag::Pending));
 
-        let declare_executable_account_hash = match paymaster_account
-            .declare_v3(
-                executable_account_flattened_sierra_class.clone(),
-                executable_account_compiled_class_hash,
-            )
-            .send()
-            .await
-        {
-            Ok(result) => {
-                wait_for_sent_transaction(result.transaction_hash, &paymaster_account).await?;
-                Ok(result.class_hash)
-            }
-            Err(AccountError::Signing(sign_error)) => {
-                if sign_error.to_string().contains("is already declared") {
-                    Ok(parse_class_hash_from_error(&sign_error.to_string())?)
-                } else {
-                    Err(OpenRpcTestGenError::RunnerError(
-                        RunnerError::AccountFailure(format!(
-                            "Transaction execution error: {}",
-                            sign_error
-                        )),
-                    ))
-                }
-            }
-
-            Err(AccountError::Provider(ProviderError::Other(starkneterror))) => {
-                if starkneterror.to_string().contains("is already declared") {
-                    Ok(parse_class_hash_from_error(&starkneterror.to_string())?)
-                } else {
-                    Err(OpenRpcTestGenError::RunnerError(
-                        RunnerError::AccountFailure(format!(
-                            "Transaction execution error: {}",
-                            starkneterror
-                        )),
-                    ))
-                }
-            }
-            Err(e) => {
-                let full_error_message = format!("{:?}", e);
-                if full_error_message.contains("is already declared") {
-                    Ok(extract_class_hash_from_error(&full_error_message)?)
-                } else {
-                    Err(OpenRpcTestGenError::AccountError(AccountError::Other(
-                        full_error_message,
-                    )))
-                }
-            }
-        }?;
+        let declare_executable_account_hash =
+            ensure_declared(&paymaster_account, "ExecutableAccount")
+                .await?
+                .class_hash();
 
         let executable_account_data = create_account(
             &provider,
@@ -0,0 +1,117 @@
+use starknet_types_core::felt::Felt;
+use starknet_types_rpc::v0_7_1::ContractClass;
+
+use super::RandomSingleOwnerAccount;
+use crate::{
+    utils::v7::{
+        accounts::account::ConnectedAccount,
+        contract::{
+            declare_and_deploy::{ensure_declared, ensure_deployed},
+            registry::ContractRegistry,
+            HashAndFlatten,
+        },
+        endpoints::errors::OpenRpcTestGenError,
+        providers::provider::Provider,
+    },
+    RandomizableAccountsTrait, SetupableTrait,
+};
+
+pub mod test_get_class_at_hashes;
+pub mod test_get_class_hashes;
+pub mod test_state_update_declared_classes;
+
+/// Artifacts other tests declare themselves to check a fresh declaration, left undeclared by the
+/// setup as it runs before the simulation and fee estimation suites.
+const FRESH_DECLARE_ARTIFACTS: &[&str] = &[
+    "contracts_sample_contract_1_HelloStarknet",
+    "contracts_sample_contract_2_HelloStarknet",
+    "SimulatedDeclareV2",
+    "SimulatedDeclareV3",
+    "EstimatedDeclareV2",
+    "EstimatedDeclareV3",
+];
+
+/// Contract from `contracts/` declared by the suite setup.
+#[derive(Clone, Debug)]
+pub struct DeclaredClass {
+    pub name: String,
+    /// Sierra class hash computed locally from the compiler artifact.
+    pub class_hash: Felt,
+    pub compiled_class_hash: Felt,
+    pub contract_class: ContractClass<Felt>,
+    /// Hash of the declare transaction, `None` when the class was declared before the run.
+    pub transaction_hash: Option<Felt>,
+    /// Instance deployed through the UDC with a zero salt, only for contracts without a
+    /// constructor.
+    pub contract_address: Option<Felt>,
+}
+
+#[derive(Clone, Debug)]
+pub struct TestSuiteClassHashes {
+    pub random_paymaster_account: RandomSingleOwnerAccount,
+    pub declared_classes: Vec<DeclaredClass>,
+    /// Latest block before the first declaration of the suite.
+    pub first_block: u64,
+}
+
+impl SetupableTrait for TestSuiteClassHashes {
+    type Input = super::TestSuiteOpenRpc;
+
+    async fn setup(setup_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let registry = ContractRegistry::global();
+        let first_block = setup_input
+            .random_paymaster_account
+            .provider()
+            .block_number()
+            .await?;
+
+        let mut declared_classes = vec![];
+        for name in registry.names()? {
+            if FRESH_DECLARE_ARTIFACTS.contains(&name.as_str()) {
+                continue;
+            }
+
+            let class_hash = registry.sierra_class(&name)?.class_hash()?;
+            let (contract_class, compiled_class_hash) = registry.get(&name)?;
+
+            let account = setup_input.random_paymaster_account.random_accounts()?;
+            let transaction_hash = ensure_declared(&account, &name).await?.transaction_hash();
+
+            let contract_address = if contract_class.entry_points_by_type.constructor.is_empty() {
+                let deployment = ensure_deployed(
+                    &account,
+                    setup_input.udc_address,
+                    class_hash,
+                    Felt::ZERO,
+                    vec![],
+                )
+                .await?;
+
+                Some(deployment.contract_address())
+            } else {
+                None
+            };
+
+            declared_classes.push(DeclaredClass {
+                name,
+                class_hash,
+                compiled_class_hash,
+                contract_class,
+                transaction_hash,
+                contract_address,
+            });
+        }
+
+        Ok(Self {
+            random_paymaster_account: setup_input.random_paymaster_account.clone(),
+            declared_classes,
+            first_block,
+        })
+    }
+}
+
+#[cfg(not(feature = "rust-analyzer"))]
+include!(concat!(
+    env!("OUT_DIR"),
+    "/generated_tests_suite_openrpc_suite_class_hashes.rs"
+));
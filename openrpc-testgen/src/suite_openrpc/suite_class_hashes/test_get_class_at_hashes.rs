@@ -0,0 +1,51 @@
+use crate::{
+    assert_eq_result,
+    utils::v7::{
+        accounts::account::{ConnectedAccount, ContractClassHasher},
+        endpoints::errors::OpenRpcTestGenError,
+        providers::provider::Provider,
+    },
+    RunnableTrait,
+};
+use starknet_types_rpc::{BlockId, BlockTag};
+
+#[derive(Clone, Debug)]
+pub struct TestCase {}
+
+impl RunnableTrait for TestCase {
+    type Input = super::TestSuiteClassHashes;
+
+    async fn run(test_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let provider = test_input.random_paymaster_account.provider();
+
+        for declared_class in &test_input.declared_classes {
+            let Some(contract_address) = declared_class.contract_address else {
+                continue;
+            };
+
+            let class_hash = provider
+                .get_class_hash_at(BlockId::Tag(BlockTag::Latest), contract_address)
+                .await?;
+
+            assert_eq_result!(
+                class_hash,
+                declared_class.class_hash,
+                "getClassHashAt mismatch for {}",
+                declared_class.name
+            );
+
+            let served_class = provider
+                .get_class_at(BlockId::Tag(BlockTag::Latest), contract_address)
+                .await?;
+
+            assert_eq_result!(
+                served_class.class_hash(),
+                declared_class.class_hash,
+                "Class hash of the class served by getClassAt mismatch for {}",
+                declared_class.name
+            );
+        }
+
+        Ok(Self {})
+    }
+}
@@ -0,0 +1,68 @@
+use crate::{
+    assert_eq_result, assert_result,
+    utils::v7::{
+        accounts::account::{starknet_keccak, ConnectedAccount, ContractClassHasher},
+        endpoints::errors::OpenRpcTestGenError,
+        providers::provider::Provider,
+    },
+    RunnableTrait,
+};
+use starknet_types_rpc::{BlockId, BlockTag};
+
+#[derive(Clone, Debug)]
+pub struct TestCase {}
+
+impl RunnableTrait for TestCase {
+    type Input = super::TestSuiteClassHashes;
+
+    async fn run(test_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let provider = test_input.random_paymaster_account.provider();
+
+        for declared_class in &test_input.declared_classes {
+            let served_class = provider
+                .get_class(BlockId::Tag(BlockTag::Latest), declared_class.class_hash)
+                .await?;
+
+            assert_eq_result!(
+                served_class.contract_class_version,
+                declared_class.contract_class.contract_class_version,
+                "Contract class version mismatch for {}",
+                declared_class.name
+            );
+
+            // The class hash commits to the ABI string as served, so compare its hash first to
+            // tell ABI formatting differences apart from other mismatches.
+            let served_abi = served_class.abi.clone().unwrap_or_default();
+            let local_abi = declared_class
+                .contract_class
+                .abi
+                .clone()
+                .unwrap_or_default();
+            assert_result!(
+                !served_abi.is_empty(),
+                format!("Class {} served without an ABI", declared_class.name)
+            );
+            assert_eq_result!(
+                starknet_keccak(served_abi.as_bytes()),
+                starknet_keccak(local_abi.as_bytes()),
+                "ABI string hash mismatch for {}",
+                declared_class.name
+            );
+
+            assert_eq_result!(
+                served_class.class_hash(),
+                declared_class.class_hash,
+                "Class hash of the class served by getClass mismatch for {}",
+                declared_class.name
+            );
+            assert_eq_result!(
+                declared_class.contract_class.class_hash(),
+                declared_class.class_hash,
+                "Class hash of the flattened class mismatch for {}",
+                declared_class.name
+            );
+        }
+
+        Ok(Self {})
+    }
+}
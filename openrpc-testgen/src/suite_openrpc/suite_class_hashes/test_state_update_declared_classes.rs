@@ -0,0 +1,62 @@
+use std::collections::HashMap;
+
+use crate::{
+    assert_eq_result,
+    utils::v7::{
+        accounts::account::ConnectedAccount, endpoints::errors::OpenRpcTestGenError,
+        providers::provider::Provider,
+    },
+    RunnableTrait,
+};
+use starknet_types_rpc::{BlockId, MaybePendingStateUpdate};
+
+#[derive(Clone, Debug)]
+pub struct TestCase {}
+
+impl RunnableTrait for TestCase {
+    type Input = super::TestSuiteClassHashes;
+
+    async fn run(test_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let provider = test_input.random_paymaster_account.provider();
+        let latest_block = provider.block_number().await?;
+
+        // Compiled class hashes of every class declared since the suite setup started.
+        let mut declared_classes = HashMap::new();
+        for block_number in test_input.first_block..=latest_block {
+            match provider
+                .get_state_update(BlockId::Number(block_number))
+                .await?
+            {
+                MaybePendingStateUpdate::Block(state_update) => {
+                    for declared_class in state_update.state_diff.declared_classes {
+                        declared_classes.insert(
+                            declared_class.class_hash,
+                            declared_class.compiled_class_hash,
+                        );
+                    }
+                }
+                MaybePendingStateUpdate::Pending(_) => {
+                    return Err(OpenRpcTestGenError::Other(format!(
+                        "Pending state update returned for block {}",
+                        block_number
+                    )));
+                }
+            }
+        }
+
+        for declared_class in &test_input.declared_classes {
+            if declared_class.transaction_hash.is_none() {
+                continue;
+            }
+
+            assert_eq_result!(
+                declared_classes.get(&declared_class.class_hash),
+                Some(&declared_class.compiled_class_hash),
+                "DECLARED_CLASSES entry mismatch for {}",
+                declared_class.name
+            );
+        }
+
+        Ok(Self {})
+    }
+}
@@ -0,0 +1,113 @@
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+use starknet_types_core::felt::Felt;
+use starknet_types_rpc::{BlockId, MaybePendingBlockWithTxHashes};
+
+use super::RandomSingleOwnerAccount;
+use crate::{
+    utils::v7::{
+        contract::{declare_and_deploy::ensure_declared, factory::ContractFactory},
+        endpoints::{errors::OpenRpcTestGenError, utils::wait_for_sent_transaction},
+        providers::provider::Provider,
+    },
+    RandomizableAccountsTrait, SetupableTrait,
+};
+
+pub mod test_library_call;
+pub mod test_replace_class;
+
+#[derive(Clone, Debug)]
+pub struct TestSuiteContractUpgrade {
+    pub random_paymaster_account: RandomSingleOwnerAccount,
+    pub udc_address: Felt,
+    pub upgradeable_v1_class_hash: Felt,
+    pub upgradeable_v2_class_hash: Felt,
+    pub library_caller_address: Felt,
+}
+
+impl SetupableTrait for TestSuiteContractUpgrade {
+    type Input = super::TestSuiteOpenRpc;
+
+    async fn setup(setup_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let account = &setup_input.random_paymaster_account;
+        let declaring_account = account.random_accounts()?;
+
+        let upgradeable_v1_class_hash = ensure_declared(&declaring_account, "UpgradeableV1")
+            .await?
+            .class_hash();
+        let upgradeable_v2_class_hash = ensure_declared(&declaring_account, "UpgradeableV2")
+            .await?
+            .class_hash();
+        let library_caller_class_hash = ensure_declared(&declaring_account, "LibraryCaller")
+            .await?
+            .class_hash();
+
+        let library_caller_address =
+            deploy(account, setup_input.udc_address, library_caller_class_hash).await?;
+
+        Ok(Self {
+            random_paymaster_account: account.clone(),
+            udc_address: setup_input.udc_address,
+            upgradeable_v1_class_hash,
+            upgradeable_v2_class_hash,
+            library_caller_address,
+        })
+    }
+}
+
+/// Deploys a contract without constructor arguments through the UDC, returning its address.
+pub async fn deploy(
+    account: &RandomSingleOwnerAccount,
+    udc_address: Felt,
+    class_hash: Felt,
+) -> Result<Felt, OpenRpcTestGenError> {
+    let factory =
+        ContractFactory::new_with_udc(class_hash, account.random_accounts()?, udc_address);
+
+    let mut salt_buffer = [0u8; 32];
+    let mut rng = StdRng::from_entropy();
+    rng.fill_bytes(&mut salt_buffer[1..]);
+
+    let deployment = factory.deploy_v3(vec![], Felt::from_bytes_be(&salt_buffer), true);
+    let contract_address = deployment.deployed_address();
+    let deployment_result = deployment.send().await?;
+
+    wait_for_sent_transaction(
+        deployment_result.transaction_hash,
+        &account.random_accounts()?,
+    )
+    .await?;
+
+    Ok(contract_address)
+}
+
+/// Number of the block including the transaction, searched from `from_block` up to the latest
+/// block.
+pub async fn find_transaction_block<P: Provider>(
+    provider: &P,
+    transaction_hash: Felt,
+    from_block: u64,
+) -> Result<u64, OpenRpcTestGenError> {
+    let latest_block = provider.block_number().await?;
+
+    for block_number in from_block..=latest_block {
+        if let MaybePendingBlockWithTxHashes::Block(block) = provider
+            .get_block_with_tx_hashes(BlockId::Number(block_number))
+            .await?
+        {
+            if block.transactions.contains(&transaction_hash) {
+                return Ok(block_number);
+            }
+        }
+    }
+
+    Err(OpenRpcTestGenError::Other(format!(
+        "Transaction {:#x} not found in blocks {}..={}",
+        transaction_hash, from_block, latest_block
+    )))
+}
+
+#[cfg(not(feature = "rust-analyzer"))]
+include!(concat!(
+    env!("OUT_DIR"),
+    "/generated_tests_suite_openrpc_suite_contract_upgrade.rs"
+));
@@ -0,0 +1,104 @@
+use crate::{
+    assert_eq_result, assert_result,
+    utils::{
+        conversions::felt_field::felt_field,
+        v7::{
+            accounts::{
+                account::{Account, ConnectedAccount},
+                call::Call,
+            },
+            endpoints::{
+                errors::OpenRpcTestGenError,
+                utils::{get_selector_from_name, wait_for_sent_transaction},
+            },
+            providers::provider::Provider,
+        },
+    },
+    RandomizableAccountsTrait, RunnableTrait,
+};
+use serde_json::Value;
+use starknet_types_core::felt::Felt;
+use starknet_types_rpc::{BlockId, BlockTag, FunctionCall};
+
+#[derive(Clone, Debug)]
+pub struct TestCase {}
+
+impl RunnableTrait for TestCase {
+    type Input = super::TestSuiteContractUpgrade;
+
+    async fn run(test_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let account = &test_input.random_paymaster_account;
+        let provider = account.provider();
+
+        // `UpgradeableV2::set_value` doubles the value, in the storage of the library caller.
+        let value = Felt::from(21_u8);
+        let result = account
+            .execute_v3(vec![Call {
+                to: test_input.library_caller_address,
+                selector: get_selector_from_name("set_value_with_library")?,
+                calldata: vec![test_input.upgradeable_v2_class_hash, value],
+            }])
+            .send()
+            .await?;
+        wait_for_sent_transaction(result.transaction_hash, &account.random_accounts()?).await?;
+
+        let stored_value = provider
+            .call(
+                FunctionCall {
+                    contract_address: test_input.library_caller_address,
+                    entry_point_selector: get_selector_from_name("get_value")?,
+                    calldata: vec![],
+                },
+                BlockId::Tag(BlockTag::Latest),
+            )
+            .await?;
+        assert_eq_result!(stored_value, vec![value * Felt::TWO]);
+
+        let trace =
+            serde_json::to_value(provider.trace_transaction(result.transaction_hash).await?)?;
+        let execute_invocation = &trace["execute_invocation"];
+        assert_result!(
+            !execute_invocation.is_null() && execute_invocation.get("revert_reason").is_none(),
+            format!("Unexpected execute invocation {}", execute_invocation)
+        );
+
+        let library_call = find_call(execute_invocation, &|call: &Value| {
+            call["call_type"] == "LIBRARY_CALL"
+                && felt_field(call, "class_hash") == Some(test_input.upgradeable_v2_class_hash)
+        })
+        .ok_or_else(|| {
+            OpenRpcTestGenError::Other(format!(
+                "No LIBRARY_CALL to class {:#x} in trace {}",
+                test_input.upgradeable_v2_class_hash, execute_invocation
+            ))
+        })?;
+
+        // Library calls run in the context of the calling contract.
+        assert_eq_result!(
+            felt_field(library_call, "contract_address"),
+            Some(test_input.library_caller_address)
+        );
+        assert_eq_result!(
+            felt_field(library_call, "caller_address"),
+            Some(test_input.library_caller_address)
+        );
+        assert_eq_result!(
+            felt_field(library_call, "entry_point_selector"),
+            Some(get_selector_from_name("set_value")?)
+        );
+
+        Ok(Self {})
+    }
+}
+
+/// Depth first search of the invocation tree of a trace for a call matching the predicate.
+fn find_call<'a>(invocation: &'a Value, predicate: &dyn Fn(&Value) -> bool) -> Option<&'a Value> {
+    if predicate(invocation) {
+        return Some(invocation);
+    }
+
+    invocation["calls"]
+        .as_array()?
+        .iter()
+        .find_map(|call| find_call(call, predicate))
+}
@@ -0,0 +1,161 @@
+use crate::{
+    assert_eq_result, assert_result,
+    utils::v7::{
+        accounts::{
+            account::{Account, ConnectedAccount},
+            call::Call,
+        },
+        endpoints::{
+            errors::OpenRpcTestGenError,
+            utils::{get_selector_from_name, wait_for_sent_transaction},
+        },
+        providers::provider::Provider,
+    },
+    RandomizableAccountsTrait, RunnableTrait,
+};
+use starknet_types_core::felt::Felt;
+use starknet_types_rpc::{BlockId, BlockTag, FunctionCall, MaybePendingStateUpdate};
+
+#[derive(Clone, Debug)]
+pub struct TestCase {}
+
+impl RunnableTrait for TestCase {
+    type Input = super::TestSuiteContractUpgrade;
+
+    async fn run(test_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let account = &test_input.random_paymaster_account;
+        let provider = account.provider();
+
+        let contract_address = super::deploy(
+            account,
+            test_input.udc_address,
+            test_input.upgradeable_v1_class_hash,
+        )
+        .await?;
+
+        let value = Felt::from(7_u8);
+        let set_value_result = account
+            .execute_v3(vec![Call {
+                to: contract_address,
+                selector: get_selector_from_name("set_value")?,
+                calldata: vec![value],
+            }])
+            .send()
+            .await?;
+        wait_for_sent_transaction(
+            set_value_result.transaction_hash,
+            &account.random_accounts()?,
+        )
+        .await?;
+
+        let block_before_upgrade = provider.block_number().await?;
+
+        let upgrade_result = account
+            .execute_v3(vec![Call {
+                to: contract_address,
+                selector: get_selector_from_name("upgrade")?,
+                calldata: vec![test_input.upgradeable_v2_class_hash],
+            }])
+            .send()
+            .await?;
+        wait_for_sent_transaction(upgrade_result.transaction_hash, &account.random_accounts()?)
+            .await?;
+
+        let upgrade_block = super::find_transaction_block(
+            provider,
+            upgrade_result.transaction_hash,
+            block_before_upgrade,
+        )
+        .await?;
+
+        let class_hash_before = provider
+            .get_class_hash_at(BlockId::Number(upgrade_block - 1), contract_address)
+            .await?;
+        assert_eq_result!(
+            class_hash_before,
+            test_input.upgradeable_v1_class_hash,
+            "Class hash before the upgrade block"
+        );
+
+        let class_hash_after = provider
+            .get_class_hash_at(BlockId::Number(upgrade_block), contract_address)
+            .await?;
+        assert_eq_result!(
+            class_hash_after,
+            test_input.upgradeable_v2_class_hash,
+            "Class hash at the upgrade block"
+        );
+
+        let state_update = match provider
+            .get_state_update(BlockId::Number(upgrade_block))
+            .await?
+        {
+            MaybePendingStateUpdate::Block(state_update) => state_update,
+            MaybePendingStateUpdate::Pending(_) => {
+                return Err(OpenRpcTestGenError::Other(format!(
+                    "Pending state update returned for block {}",
+                    upgrade_block
+                )));
+            }
+        };
+        let replaced = state_update
+            .state_diff
+            .replaced_classes
+            .iter()
+            .any(|replaced_class| {
+                replaced_class.contract_address == contract_address
+                    && replaced_class.class_hash == test_input.upgradeable_v2_class_hash
+            });
+        assert_result!(
+            replaced,
+            format!(
+                "REPLACED_CLASSES of block {} misses contract {:#x}",
+                upgrade_block, contract_address
+            )
+        );
+
+        let version_selector = get_selector_from_name("version")?;
+        let version_before = provider
+            .call(
+                FunctionCall {
+                    contract_address,
+                    entry_point_selector: version_selector,
+                    calldata: vec![],
+                },
+                BlockId::Number(upgrade_block - 1),
+            )
+            .await?;
+        assert_eq_result!(
+            version_before,
+            vec![Felt::ONE],
+            "Version before the upgrade"
+        );
+
+        let version_after = provider
+            .call(
+                FunctionCall {
+                    contract_address,
+                    entry_point_selector: version_selector,
+                    calldata: vec![],
+                },
+                BlockId::Tag(BlockTag::Latest),
+            )
+            .await?;
+        assert_eq_result!(version_after, vec![Felt::TWO], "Version after the upgrade");
+
+        // Replacing the class keeps the contract storage.
+        let stored_value = provider
+            .call(
+                FunctionCall {
+                    contract_address,
+                    entry_point_selector: get_selector_from_name("get_value")?,
+                    calldata: vec![],
+                },
+                BlockId::Tag(BlockTag::Latest),
+            )
+            .await?;
+        assert_eq_result!(stored_value, vec![value], "Storage after the upgrade");
+
+        Ok(Self {})
+    }
+}
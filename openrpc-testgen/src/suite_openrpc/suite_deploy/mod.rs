@@ -1,26 +1,13 @@
-use std::path::PathBuf;
-
 use starknet_types_core::felt::Felt;
-use starknet_types_rpc::{BlockId, ClassAndTxnHash, DeclareTxn, EventFilterWithPageRequest, Txn};
-use tracing::info;
+use starknet_types_rpc::ClassAndTxnHash;
 
 use super::RandomSingleOwnerAccount;
 use crate::{
     utils::v7::{
-        accounts::account::{Account, AccountError, ConnectedAccount},
-        endpoints::{
-            declare_contract::{
-                extract_class_hash_from_error, get_compiled_contract, parse_class_hash_from_error,
-                RunnerError,
-            },
-            errors::OpenRpcTestGenError,
-            utils::wait_for_sent_transaction,
-        },
-        providers::provider::{Provider, ProviderError},
+        contract::declare_and_deploy::ensure_declared, endpoints::errors::OpenRpcTestGenError,
     },
     RandomizableAccountsTrait, SetupableTrait,
 };
-use std::str::FromStr;
 pub mod suite_contract_calls;
 pub mod test_deploy_txn_v1;
 pub mod test_deploy_txn_v3;
@@ -39,135 +26,16 @@ impl SetupableTrait for TestSuiteDeploy {
     type Input = super::TestSuiteOpenRpc;
 
     async fn setup(setup_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
-        let (flattened_sierra_class, compiled_class_hash) =
-            get_compiled_contract(
-                PathBuf::from_str("target/dev/contracts_contracts_sample_contract_3_HelloStarknet.contract_class.json")?,
-            PathBuf::from_str("target/dev/contracts_contracts_sample_contract_3_HelloStarknet.compiled_contract_class.json")?,
-            )
-            .await?;
-
-        let declaration_result = match setup_input
-            .random_paymaster_account
-            .declare_v3(flattened_sierra_class, compiled_class_hash)
-            .send()
-            .await
-        {
-            Ok(result) => {
-                wait_for_sent_transaction(
-                    result.transaction_hash,
-                    &setup_input.random_paymaster_account.random_accounts()?,
-                )
-                .await?;
-                Ok(result)
-            }
-            Err(AccountError::Signing(sign_error)) => {
-                if sign_error.to_string().contains("is already declared") {
-                    Ok(ClassAndTxnHash {
-                        class_hash: parse_class_hash_from_error(&sign_error.to_string())?,
-                        transaction_hash: Felt::ZERO,
-                    })
-                } else {
-                    Err(OpenRpcTestGenError::RunnerError(
-                        RunnerError::AccountFailure(format!(
-                            "Transaction execution error: {}",
-                            sign_error
-                        )),
-                    ))
-                }
-            }
-
-            Err(AccountError::Provider(ProviderError::Other(starkneterror))) => {
-                if starkneterror.to_string().contains("is already declared") {
-                    Ok(ClassAndTxnHash {
-                        class_hash: parse_class_hash_from_error(&starkneterror.to_string())?,
-                        transaction_hash: Felt::ZERO,
-                    })
-                } else {
-                    Err(OpenRpcTestGenError::RunnerError(
-                        RunnerError::AccountFailure(format!(
-                            "Transaction execution error: {}",
-                            starkneterror
-                        )),
-                    ))
-                }
-            }
-            Err(e) => {
-                let full_error_message = format!("{:?}", e);
-
-                if full_error_message.contains("is already declared") {
-                    let class_hash = extract_class_hash_from_error(&full_error_message)?;
-
-                    let filter = EventFilterWithPageRequest {
-                        address: None,
-                        from_block: Some(BlockId::Number(322421)),
-                        to_block: Some(BlockId::Number(322421)),
-                        keys: Some(vec![vec![]]),
-                        chunk_size: 100,
-                        continuation_token: None,
-                    };
-
-                    let provider = setup_input.random_paymaster_account.provider();
-                    let random_account_address = setup_input
-                        .random_paymaster_account
-                        .random_accounts()?
-                        .address();
-
-                    let mut continuation_token = None;
-                    let mut found_txn_hash = None;
-
-                    loop {
-                        let mut current_filter = filter.clone();
-                        current_filter.continuation_token = continuation_token.clone();
-
-                        let events_chunk = provider.get_events(current_filter).await?;
-
-                        for event in events_chunk.events {
-                            if event.event.data.contains(&random_account_address) {
-                                let txn_hash = event.transaction_hash;
-
-                                let txn_details =
-                                    provider.get_transaction_by_hash(txn_hash).await?;
-
-                                if let Txn::Declare(DeclareTxn::V3(declare_txn)) = txn_details {
-                                    if declare_txn.class_hash == class_hash {
-                                        found_txn_hash = Some(txn_hash);
-                                        break;
-                                    }
-                                }
-                            }
-                        }
-
-                        if found_txn_hash.is_some() {
-                            break;
-                        }
-
-                        if let Some(token) = events_chunk.continuation_token {
-                            continuation_token = Some(token);
-                        } else {
-                            break;
-                        }
-                    }
-
-                    if let Some(tx_hash) = found_txn_hash {
-                        Ok(ClassAndTxnHash {
-                            class_hash,
-                            transaction_hash: tx_hash,
-                        })
-                    } else {
-                        info!("Transaction hash not found for the declared clas");
-                        Err(OpenRpcTestGenError::RunnerError(
-                            RunnerError::AccountFailure(
-                                "Transaction hash not found for the declared class.".to_string(),
-                            ),
-                        ))
-                    }
-                } else {
-                    return Err(OpenRpcTestGenError::AccountError(AccountError::Other(
-                        full_error_message,
-                    )));
-                }
-            }
-        }?;
+        let declaration = ensure_declared(
+            &setup_input.random_paymaster_account.random_accounts()?,
+            "contracts_sample_contract_3_HelloStarknet",
+        )
+        .await?;
+        // The hash of the declare transaction is unknown when the class was already declared.
+        let declaration_result = ClassAndTxnHash {
+            class_hash: declaration.class_hash(),
+            transaction_hash: declaration.transaction_hash().unwrap_or(Felt::ZERO),
+        };
 
         Ok(Self {
             random_paymaster_account: setup_input.random_paymaster_account.clone(),
@@ -0,0 +1,100 @@
+use crate::{
+    assert_eq_result, assert_result,
+    utils::v7::{
+        accounts::account::{Account, ConnectedAccount},
+        contract::{
+            bindings::{
+                hello_starknet::HelloStarknet,
+                test_token::{self, ERC20ComponentEvent, TestToken, TestTokenEvent, Transfer},
+            },
+            declare_and_deploy::ensure_declared,
+            factory::ContractFactory,
+        },
+        endpoints::{errors::OpenRpcTestGenError, utils::wait_for_sent_transaction},
+        providers::provider::Provider,
+    },
+    RandomizableAccountsTrait, RunnableTrait,
+};
+use cainome_cairo_serde::{ContractAddress, U256};
+use starknet_types_core::felt::Felt;
+use starknet_types_rpc::{BlockId, BlockTag, TxnExecutionStatus};
+
+#[derive(Clone, Debug)]
+pub struct TestCase {}
+
+impl RunnableTrait for TestCase {
+    type Input = super::TestSuiteContractCalls;
+
+    async fn run(test_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let mut account = test_input.random_paymaster_account.random_accounts()?;
+        account.set_block_id(BlockId::Tag(BlockTag::Pending));
+        let contract = HelloStarknet::new(test_input.deployed_contract_address, account.clone());
+
+        let initial_balance = contract.get_balance().await?;
+        let amount = Felt::from_hex("0x50")?;
+
+        let result = contract.increase_balance(&amount).send().await?;
+        let status = wait_for_sent_transaction(result.transaction_hash, &contract.account).await?;
+        assert_eq_result!(status.execution_status, Some(TxnExecutionStatus::Succeeded));
+
+        let balance = contract.get_balance().await?;
+        assert_eq_result!(
+            balance,
+            initial_balance + amount,
+            "Balance read through the bindings mismatch"
+        );
+
+        let receipt = account
+            .provider()
+            .get_transaction_receipt(result.transaction_hash)
+            .await?;
+        let events = contract.events(&receipt)?;
+        assert_result!(
+            events.is_empty(),
+            format!("increase_balance emitted unexpected events: {:?}", events)
+        );
+
+        // HelloStarknet only emits events from its L1 handler, so the invoke events are checked
+        // on a token deployed for the test.
+        let token_class_hash = ensure_declared(&account, "TestToken").await?.class_hash();
+        let factory = ContractFactory::new(token_class_hash, account.clone());
+        let deployment = factory.deploy_v3(
+            test_token::constructor_calldata(),
+            Felt::from(rand::random::<u64>()),
+            true,
+        );
+        let token_address = deployment.deployed_address();
+        let result = deployment.send().await?;
+        wait_for_sent_transaction(result.transaction_hash, &account).await?;
+
+        let token = TestToken::new(token_address, account.clone());
+        let recipient = ContractAddress(account.address());
+        let minted = U256 {
+            low: 0x1234,
+            high: 0,
+        };
+
+        let result = token.mint(&recipient, &minted).send().await?;
+        let status = wait_for_sent_transaction(result.transaction_hash, &account).await?;
+        assert_eq_result!(status.execution_status, Some(TxnExecutionStatus::Succeeded));
+
+        let receipt = account
+            .provider()
+            .get_transaction_receipt(result.transaction_hash)
+            .await?;
+        let events = token.events(&receipt)?;
+        assert_eq_result!(
+            events,
+            vec![TestTokenEvent::ERC20Event(ERC20ComponentEvent::Transfer(
+                Transfer {
+                    from: ContractAddress(Felt::ZERO),
+                    to: recipient,
+                    value: minted,
+                }
+            ))],
+            "Events decoded through the bindings mismatch"
+        );
+
+        Ok(Self {})
+    }
+}
@@ -0,0 +1,162 @@
+use starknet_types_core::felt::{Felt, NonZeroFelt};
+use starknet_types_rpc::{BlockId, FeeEstimate};
+
+use super::RandomSingleOwnerAccount;
+use crate::{
+    assert_eq_result, assert_result,
+    utils::v7::{
+        accounts::{account::ConnectedAccount, single_owner::SingleOwnerAccount},
+        contract::declare_and_deploy::ensure_counter,
+        endpoints::errors::OpenRpcTestGenError,
+        providers::{
+            jsonrpc::{HttpTransport, JsonRpcClient},
+            provider::Provider,
+        },
+        signers::local_wallet::LocalWallet,
+    },
+    RandomizableAccountsTrait, SetupableTrait,
+};
+
+pub mod test_declare_fee_accuracy;
+pub mod test_dependent_batch_estimate;
+pub mod test_deploy_account_fee_accuracy;
+pub mod test_invoke_fee_accuracy;
+
+/// Largest difference allowed between an estimated amount and the one charged, in percent of
+/// the estimate, as the signature of query transactions and the block prices may differ.
+pub const FEE_TOLERANCE_PERCENT: u64 = 10;
+
+#[derive(Clone, Debug)]
+pub struct TestSuiteFeeEstimation {
+    pub random_paymaster_account: RandomSingleOwnerAccount,
+    pub account_class_hash: Felt,
+    pub udc_address: Felt,
+    pub counter_class_hash: Felt,
+    /// `Counter` contract increased by the invokes of the tests.
+    pub counter_address: Felt,
+}
+
+impl SetupableTrait for TestSuiteFeeEstimation {
+    type Input = super::TestSuiteOpenRpc;
+
+    async fn setup(setup_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let account = setup_input.random_paymaster_account.random_accounts()?;
+
+        let counter = ensure_counter(&account, setup_input.udc_address).await?;
+
+        Ok(Self {
+            random_paymaster_account: setup_input.random_paymaster_account.clone(),
+            account_class_hash: setup_input.account_class_hash,
+            udc_address: setup_input.udc_address,
+            counter_class_hash: counter.class_hash,
+            counter_address: counter.address,
+        })
+    }
+}
+
+/// Compares the estimate of a transaction with what its receipt reports once accepted:
+/// - both the estimate and `actual_fee` are in the `unit` of the transaction version,
+/// - `actual_fee` is within [FEE_TOLERANCE_PERCENT] of `overall_fee`,
+/// - the L1 data gas of the receipt is within the tolerance of `data_gas_consumed`,
+/// - the L1 gas, derived from the fee and the prices of the including block, is within the
+///   tolerance of `gas_consumed`.
+pub async fn check_fee_accuracy(
+    account: &SingleOwnerAccount<JsonRpcClient<HttpTransport>, LocalWallet>,
+    estimate: &FeeEstimate<Felt>,
+    transaction_hash: Felt,
+    unit: &str,
+) -> Result<(), OpenRpcTestGenError> {
+    let provider = account.provider();
+    let receipt = provider
+        .get_transaction_receipt_with_block_info(transaction_hash)
+        .await?;
+    let block_number = receipt.block_number.ok_or_else(|| {
+        OpenRpcTestGenError::Other(format!(
+            "Receipt of accepted transaction {:#x} has no block",
+            transaction_hash
+        ))
+    })?;
+    let receipt = serde_json::to_value(receipt)?;
+
+    let estimated_unit = serde_json::to_value(&estimate.unit)?;
+    assert_eq_result!(
+        estimated_unit.as_str(),
+        Some(unit),
+        "Estimated fee unit of transaction {:#x}",
+        transaction_hash
+    );
+    assert_eq_result!(
+        receipt["actual_fee"]["unit"].as_str(),
+        Some(unit),
+        "Fee unit of transaction {:#x}",
+        transaction_hash
+    );
+    let price_field = if unit == "WEI" {
+        "price_in_wei"
+    } else {
+        "price_in_fri"
+    };
+
+    let block = serde_json::to_value(
+        provider
+            .get_block_with_tx_hashes(BlockId::Number(block_number))
+            .await?,
+    )?;
+    let gas_price: Felt = serde_json::from_value(block["l1_gas_price"][price_field].clone())?;
+    let data_gas_price: Felt =
+        serde_json::from_value(block["l1_data_gas_price"][price_field].clone())?;
+
+    let fee: Felt = serde_json::from_value(receipt["actual_fee"]["amount"].clone())?;
+    let data_gas: Felt = serde_json::from_value(
+        receipt["execution_resources"]["data_availability"]["l1_data_gas"].clone(),
+    )?;
+    let data_gas_fee = data_gas * data_gas_price;
+    assert_result!(
+        data_gas_fee <= fee,
+        format!(
+            "Data gas fee {} of transaction {:#x} exceeds its fee {}",
+            data_gas_fee, transaction_hash, fee
+        )
+    );
+    // Nodes without gas prices charge no fee for gas.
+    let gas = NonZeroFelt::try_from(gas_price)
+        .map(|gas_price| (fee - data_gas_fee).floor_div(&gas_price))
+        .unwrap_or_default();
+
+    check_within_tolerance("Fee", estimate.overall_fee, fee, transaction_hash)?;
+    check_within_tolerance("Gas", estimate.gas_consumed, gas, transaction_hash)?;
+    check_within_tolerance(
+        "Data gas",
+        estimate.data_gas_consumed,
+        data_gas,
+        transaction_hash,
+    )?;
+
+    Ok(())
+}
+
+fn check_within_tolerance(
+    amount: &str,
+    estimated: Felt,
+    actual: Felt,
+    transaction_hash: Felt,
+) -> Result<(), OpenRpcTestGenError> {
+    let difference = estimated.max(actual) - estimated.min(actual);
+    let within_tolerance =
+        difference * Felt::from(100) <= estimated * Felt::from(FEE_TOLERANCE_PERCENT);
+    assert_result!(
+        within_tolerance,
+        format!(
+            "{} of transaction {:#x} is {}, estimated {}",
+            amount, transaction_hash, actual, estimated
+        )
+    );
+
+    Ok(())
+}
+
+#[cfg(not(feature = "rust-analyzer"))]
+include!(concat!(
+    env!("OUT_DIR"),
+    "/generated_tests_suite_openrpc_suite_fee_estimation.rs"
+));
@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use tracing::info;
+
+use super::check_fee_accuracy;
+use crate::{
+    utils::v7::{
+        accounts::account::Account,
+        contract::{declare_and_deploy::is_declared, registry::ContractRegistry},
+        endpoints::{errors::OpenRpcTestGenError, utils::wait_for_sent_transaction},
+    },
+    RandomizableAccountsTrait, RunnableTrait,
+};
+
+#[derive(Clone, Debug)]
+pub struct TestCase {}
+
+impl RunnableTrait for TestCase {
+    type Input = super::TestSuiteFeeEstimation;
+
+    async fn run(test_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let account = test_input.random_paymaster_account.random_accounts()?;
+        let registry = ContractRegistry::global();
+
+        if is_declared(&account, "EstimatedDeclareV2").await? {
+            info!("Skipping V2 declare fee accuracy, EstimatedDeclareV2 is already declared");
+        } else {
+            let (contract_class, compiled_class_hash) = registry.get("EstimatedDeclareV2")?;
+
+            let declaration = account.declare_v2(Arc::new(contract_class), compiled_class_hash);
+            let estimate = declaration.estimate_fee().await?;
+            let result = declaration.send().await?;
+            wait_for_sent_transaction(result.transaction_hash, &account).await?;
+            check_fee_accuracy(&account, &estimate, result.transaction_hash, "WEI").await?;
+        }
+
+        if is_declared(&account, "EstimatedDeclareV3").await? {
+            info!("Skipping V3 declare fee accuracy, EstimatedDeclareV3 is already declared");
+        } else {
+            let (contract_class, compiled_class_hash) = registry.get("EstimatedDeclareV3")?;
+
+            let declaration = account.declare_v3(contract_class, compiled_class_hash);
+            let estimate = declaration.estimate_fee().await?;
+            let result = declaration.send().await?;
+            wait_for_sent_transaction(result.transaction_hash, &account).await?;
+            check_fee_accuracy(&account, &estimate, result.transaction_hash, "FRI").await?;
+        }
+
+        Ok(Self {})
+    }
+}
@@ -0,0 +1,111 @@
+use starknet_types_core::felt::Felt;
+use starknet_types_rpc::{
+    v0_7_1::{BroadcastedInvokeTxn, BroadcastedTxn},
+    BlockId, BlockTag,
+};
+
+use super::check_fee_accuracy;
+use crate::{
+    assert_eq_result, assert_result,
+    utils::v7::{
+        accounts::account::{Account, AccountError, ConnectedAccount, ExecutionV1},
+        contract::{declare_and_deploy::increase_call, factory::ContractFactory},
+        endpoints::{errors::OpenRpcTestGenError, utils::wait_for_sent_transaction},
+        providers::provider::Provider,
+    },
+    RandomizableAccountsTrait, RunnableTrait,
+};
+
+#[derive(Clone, Debug)]
+pub struct TestCase {}
+
+impl RunnableTrait for TestCase {
+    type Input = super::TestSuiteFeeEstimation;
+
+    /// Estimates a batch deploying a counter and then increasing it, where the second transaction
+    /// is only valid after the nonce and state changes of the first one.
+    async fn run(test_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let account = test_input.random_paymaster_account.random_accounts()?;
+        let nonce = account.get_nonce().await?;
+
+        let factory = ContractFactory::new_with_udc(
+            test_input.counter_class_hash,
+            account.clone(),
+            test_input.udc_address,
+        );
+        let deployment = factory.deploy_v1(vec![], Felt::from(rand::random::<u64>()), false);
+        let counter_address = deployment.deployed_address();
+        let increase = vec![increase_call(counter_address, Felt::ONE)?];
+
+        let deploy_estimate = deployment.estimate_fee().await?;
+        let increase_estimate = account
+            .execute_v1(increase.clone())
+            .nonce(nonce + Felt::ONE)
+            .estimate_fee()
+            .await;
+        let increase_failed = increase_estimate.is_err();
+        assert_result!(
+            increase_failed,
+            format!(
+                "Increase of undeployed counter {:#x} with nonce {:#x} estimated alone: {:?}",
+                counter_address,
+                nonce + Felt::ONE,
+                increase_estimate
+            )
+        );
+
+        let deploy_request = ExecutionV1::from(&deployment)
+            .nonce(nonce)
+            .max_fee(Felt::ZERO)
+            .prepare()
+            .await?
+            .get_invoke_request(true, false)
+            .await
+            .map_err(AccountError::Signing)?;
+        let increase_request = account
+            .execute_v1(increase.clone())
+            .nonce(nonce + Felt::ONE)
+            .max_fee(Felt::ZERO)
+            .prepare()
+            .await?
+            .get_invoke_request(true, false)
+            .await
+            .map_err(AccountError::Signing)?;
+
+        let estimates = account
+            .provider()
+            .estimate_fee(
+                vec![
+                    BroadcastedTxn::Invoke(BroadcastedInvokeTxn::QueryV1(deploy_request)),
+                    BroadcastedTxn::Invoke(BroadcastedInvokeTxn::QueryV1(increase_request)),
+                ],
+                vec![],
+                BlockId::Tag(BlockTag::Pending),
+            )
+            .await?;
+        assert_eq_result!(
+            estimates.len(),
+            2,
+            "Number of estimates of the deploy and increase batch"
+        );
+        assert_eq_result!(
+            estimates[0].overall_fee,
+            deploy_estimate.overall_fee,
+            "Fee of the deployment estimated first in a batch and alone"
+        );
+
+        let result = deployment.nonce(nonce).send().await?;
+        wait_for_sent_transaction(result.transaction_hash, &account).await?;
+        check_fee_accuracy(&account, &estimates[0], result.transaction_hash, "WEI").await?;
+
+        let result = account
+            .execute_v1(increase)
+            .nonce(nonce + Felt::ONE)
+            .send()
+            .await?;
+        wait_for_sent_transaction(result.transaction_hash, &account).await?;
+        check_fee_accuracy(&account, &estimates[1], result.transaction_hash, "WEI").await?;
+
+        Ok(Self {})
+    }
+}
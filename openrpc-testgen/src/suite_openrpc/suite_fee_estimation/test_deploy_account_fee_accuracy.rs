@@ -0,0 +1,73 @@
+use starknet_types_core::felt::Felt;
+use starknet_types_rpc::{BlockId, BlockTag};
+
+use super::check_fee_accuracy;
+use crate::{
+    utils::v7::{
+        accounts::{
+            account::{Account, ConnectedAccount},
+            factory::{open_zeppelin::OpenZeppelinAccountFactory, AccountFactory},
+        },
+        endpoints::{
+            errors::OpenRpcTestGenError,
+            utils::{
+                setup_generated_account, wait_for_sent_transaction, ETH_ADDRESS, STRK_ADDRESS,
+            },
+        },
+        signers::{key_pair::SigningKey, local_wallet::LocalWallet},
+    },
+    RandomizableAccountsTrait, RunnableTrait,
+};
+
+/// Amount of each fee token sent to the deployed accounts.
+const ACCOUNT_FUNDING: u128 = 100_000_000_000_000_000;
+
+#[derive(Clone, Debug)]
+pub struct TestCase {}
+
+impl RunnableTrait for TestCase {
+    type Input = super::TestSuiteFeeEstimation;
+
+    async fn run(test_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let account = test_input.random_paymaster_account.random_accounts()?;
+
+        let mut factory = OpenZeppelinAccountFactory::new(
+            test_input.account_class_hash,
+            account.chain_id(),
+            LocalWallet::from(SigningKey::from_random()),
+            account.provider().clone(),
+        )
+        .await?;
+        factory.set_block_id(BlockId::Tag(BlockTag::Pending));
+
+        let deployment_v1 = factory.deploy_v1(Felt::from(rand::random::<u64>()));
+        setup_generated_account(
+            account.clone(),
+            ETH_ADDRESS,
+            STRK_ADDRESS,
+            Felt::from(ACCOUNT_FUNDING),
+            deployment_v1.address(),
+        )
+        .await?;
+        let estimate = deployment_v1.estimate_fee().await?;
+        let result = deployment_v1.send().await?;
+        wait_for_sent_transaction(result.transaction_hash, &account).await?;
+        check_fee_accuracy(&account, &estimate, result.transaction_hash, "WEI").await?;
+
+        let deployment_v3 = factory.deploy_v3(Felt::from(rand::random::<u64>()));
+        setup_generated_account(
+            account.clone(),
+            ETH_ADDRESS,
+            STRK_ADDRESS,
+            Felt::from(ACCOUNT_FUNDING),
+            deployment_v3.address(),
+        )
+        .await?;
+        let estimate = deployment_v3.estimate_fee().await?;
+        let result = deployment_v3.send().await?;
+        wait_for_sent_transaction(result.transaction_hash, &account).await?;
+        check_fee_accuracy(&account, &estimate, result.transaction_hash, "FRI").await?;
+
+        Ok(Self {})
+    }
+}
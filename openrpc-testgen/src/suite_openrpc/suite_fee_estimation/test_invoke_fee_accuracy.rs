@@ -0,0 +1,38 @@
+use starknet_types_core::felt::Felt;
+
+use super::check_fee_accuracy;
+use crate::{
+    utils::v7::{
+        accounts::account::Account,
+        contract::declare_and_deploy::increase_call,
+        endpoints::{errors::OpenRpcTestGenError, utils::wait_for_sent_transaction},
+    },
+    RandomizableAccountsTrait, RunnableTrait,
+};
+
+#[derive(Clone, Debug)]
+pub struct TestCase {}
+
+impl RunnableTrait for TestCase {
+    type Input = super::TestSuiteFeeEstimation;
+
+    async fn run(test_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let account = test_input.random_paymaster_account.random_accounts()?;
+
+        let execution_v1 =
+            account.execute_v1(vec![increase_call(test_input.counter_address, Felt::ONE)?]);
+        let estimate = execution_v1.estimate_fee().await?;
+        let result = execution_v1.send().await?;
+        wait_for_sent_transaction(result.transaction_hash, &account).await?;
+        check_fee_accuracy(&account, &estimate, result.transaction_hash, "WEI").await?;
+
+        let execution_v3 =
+            account.execute_v3(vec![increase_call(test_input.counter_address, Felt::ONE)?]);
+        let estimate = execution_v3.estimate_fee().await?;
+        let result = execution_v3.send().await?;
+        wait_for_sent_transaction(result.transaction_hash, &account).await?;
+        check_fee_accuracy(&account, &estimate, result.transaction_hash, "FRI").await?;
+
+        Ok(Self {})
+    }
+}
@@ -0,0 +1,203 @@
+use std::fmt::Debug;
+
+use starknet_types_core::felt::Felt;
+use starknet_types_rpc::{BlockId, FunctionCall};
+
+use super::RandomSingleOwnerAccount;
+use crate::{
+    assert_result,
+    utils::v7::{
+        accounts::{
+            account::{Account, ConnectedAccount},
+            single_owner::SingleOwnerAccount,
+        },
+        contract::{
+            declare_and_deploy::{ensure_declared, increase_call},
+            factory::ContractFactory,
+        },
+        endpoints::{
+            errors::OpenRpcTestGenError,
+            utils::{get_selector_from_name, wait_for_sent_transaction},
+        },
+        providers::{
+            jsonrpc::{HttpTransport, JsonRpcClient, StarknetError},
+            provider::{Provider, ProviderError},
+        },
+        signers::local_wallet::LocalWallet,
+    },
+    RandomizableAccountsTrait, SetupableTrait,
+};
+
+pub mod test_call_at_block_ids;
+pub mod test_class_hash_at_block_ids;
+pub mod test_missing_block_errors;
+pub mod test_nonce_at_block_ids;
+pub mod test_pre_deploy_errors;
+pub mod test_storage_at_block_ids;
+
+/// Number of transactions increasing the counter, each awaited before the next one so that they
+/// land in consecutive blocks.
+pub const WRITES: u64 = 4;
+
+/// Block including a transaction of the setup.
+#[derive(Clone, Copy, Debug)]
+pub struct IncludedIn {
+    pub block_number: u64,
+    pub block_hash: Felt,
+}
+
+/// State written by a transaction of the setup, as of the block including it.
+#[derive(Clone, Copy, Debug)]
+pub struct HistoricalWrite {
+    pub block: IncludedIn,
+    /// Value of the counter after the transaction.
+    pub counter: Felt,
+    /// Nonce of the sender after the transaction.
+    pub nonce: Felt,
+}
+
+#[derive(Clone, Debug)]
+pub struct TestSuiteHistoricalState {
+    pub random_paymaster_account: RandomSingleOwnerAccount,
+    /// Address of the sender of the writes.
+    pub sender_address: Felt,
+    pub counter_class_hash: Felt,
+    /// `Counter` contract deployed by the setup, at a random address.
+    pub counter_address: Felt,
+    /// Block including the deployment of the counter.
+    pub deployment: IncludedIn,
+    pub writes: Vec<HistoricalWrite>,
+}
+
+impl SetupableTrait for TestSuiteHistoricalState {
+    type Input = super::TestSuiteOpenRpc;
+
+    async fn setup(setup_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let account = setup_input.random_paymaster_account.random_accounts()?;
+
+        let counter_class_hash = ensure_declared(&account, "Counter").await?.class_hash();
+        let factory = ContractFactory::new_with_udc(
+            counter_class_hash,
+            account.clone(),
+            setup_input.udc_address,
+        );
+        let deployment = factory.deploy_v3(vec![], Felt::from(rand::random::<u64>()), false);
+        let counter_address = deployment.deployed_address();
+        let result = deployment.send().await?;
+        wait_for_sent_transaction(result.transaction_hash, &account).await?;
+        let deployment = included_in(&account, result.transaction_hash).await?;
+
+        let mut writes = vec![];
+        let mut counter = Felt::ZERO;
+        for amount in 1..=WRITES {
+            let nonce = account.get_nonce().await?;
+            let result = account
+                .execute_v3(vec![increase_call(counter_address, Felt::from(amount))?])
+                .nonce(nonce)
+                .send()
+                .await?;
+            wait_for_sent_transaction(result.transaction_hash, &account).await?;
+
+            counter += Felt::from(amount);
+            writes.push(HistoricalWrite {
+                block: included_in(&account, result.transaction_hash).await?,
+                counter,
+                nonce: nonce + Felt::ONE,
+            });
+        }
+
+        Ok(Self {
+            random_paymaster_account: setup_input.random_paymaster_account.clone(),
+            sender_address: account.address(),
+            counter_class_hash,
+            counter_address,
+            deployment,
+            writes,
+        })
+    }
+}
+
+/// Block including the accepted transaction, from its receipt.
+async fn included_in(
+    account: &SingleOwnerAccount<JsonRpcClient<HttpTransport>, LocalWallet>,
+    transaction_hash: Felt,
+) -> Result<IncludedIn, OpenRpcTestGenError> {
+    let receipt = account
+        .provider()
+        .get_transaction_receipt_with_block_info(transaction_hash)
+        .await?;
+
+    match (receipt.block_number, receipt.block_hash) {
+        (Some(block_number), Some(block_hash)) => Ok(IncludedIn {
+            block_number,
+            block_hash,
+        }),
+        _ => Err(OpenRpcTestGenError::Other(format!(
+            "Receipt of accepted transaction {:#x} has no block",
+            transaction_hash
+        ))),
+    }
+}
+
+/// Checks that `getStorageAt`, `getNonce`, `getClassHashAt` and `call` on the counter all fail
+/// with the expected error at the block built by `block_id`, described by `block`.
+pub async fn check_counter_queries_fail(
+    test_input: &TestSuiteHistoricalState,
+    block_id: impl Fn() -> BlockId<Felt>,
+    expected: StarknetError,
+    block: &str,
+) -> Result<(), OpenRpcTestGenError> {
+    let account = test_input.random_paymaster_account.random_accounts()?;
+    let provider = account.provider();
+    let address = test_input.counter_address;
+
+    let result = provider
+        .get_storage_at(address, get_selector_from_name("counter")?, block_id())
+        .await;
+    check_starknet_error(result, &expected, "getStorageAt", block)?;
+
+    let result = provider.get_nonce(block_id(), address).await;
+    check_starknet_error(result, &expected, "getNonce", block)?;
+
+    let result = provider.get_class_hash_at(block_id(), address).await;
+    check_starknet_error(result, &expected, "getClassHashAt", block)?;
+
+    let result = provider
+        .call(
+            FunctionCall {
+                contract_address: address,
+                entry_point_selector: get_selector_from_name("get_counter")?,
+                calldata: vec![],
+            },
+            block_id(),
+        )
+        .await;
+    check_starknet_error(result, &expected, "call", block)?;
+
+    Ok(())
+}
+
+fn check_starknet_error<T: Debug>(
+    result: Result<T, ProviderError>,
+    expected: &StarknetError,
+    method: &str,
+    block: &str,
+) -> Result<(), OpenRpcTestGenError> {
+    let failed_as_expected =
+        matches!(&result, Err(ProviderError::StarknetError(error)) if error == expected);
+    assert_result!(
+        failed_as_expected,
+        format!(
+            "{} at {} returned {:?} instead of {:?}",
+            method, block, result, expected
+        )
+    );
+
+    Ok(())
+}
+
+#[cfg(not(feature = "rust-analyzer"))]
+include!(concat!(
+    env!("OUT_DIR"),
+    "/generated_tests_suite_openrpc_suite_historical_state.rs"
+));
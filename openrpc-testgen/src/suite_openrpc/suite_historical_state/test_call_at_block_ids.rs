@@ -0,0 +1,71 @@
+use starknet_types_rpc::{BlockId, BlockTag, FunctionCall};
+
+use crate::{
+    assert_eq_result,
+    utils::v7::{
+        accounts::account::ConnectedAccount,
+        endpoints::{errors::OpenRpcTestGenError, utils::get_selector_from_name},
+        providers::provider::Provider,
+    },
+    RandomizableAccountsTrait, RunnableTrait,
+};
+
+#[derive(Clone, Debug)]
+pub struct TestCase {}
+
+impl RunnableTrait for TestCase {
+    type Input = super::TestSuiteHistoricalState;
+
+    async fn run(test_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let account = test_input.random_paymaster_account.random_accounts()?;
+        let provider = account.provider();
+        let get_counter = FunctionCall {
+            contract_address: test_input.counter_address,
+            entry_point_selector: get_selector_from_name("get_counter")?,
+            calldata: vec![],
+        };
+
+        for write in &test_input.writes {
+            let by_number = provider
+                .call(
+                    get_counter.clone(),
+                    BlockId::Number(write.block.block_number),
+                )
+                .await?;
+            assert_eq_result!(
+                by_number,
+                vec![write.counter],
+                "Call result at block number {}",
+                write.block.block_number
+            );
+
+            let by_hash = provider
+                .call(get_counter.clone(), BlockId::Hash(write.block.block_hash))
+                .await?;
+            assert_eq_result!(
+                by_hash,
+                vec![write.counter],
+                "Call result at block hash {:#x}",
+                write.block.block_hash
+            );
+        }
+
+        let last_counter = test_input
+            .writes
+            .last()
+            .ok_or_else(|| OpenRpcTestGenError::Other("No historical writes".to_string()))?
+            .counter;
+
+        let latest = provider
+            .call(get_counter.clone(), BlockId::Tag(BlockTag::Latest))
+            .await?;
+        assert_eq_result!(latest, vec![last_counter], "Call result at latest block");
+
+        let pending = provider
+            .call(get_counter, BlockId::Tag(BlockTag::Pending))
+            .await?;
+        assert_eq_result!(pending, vec![last_counter], "Call result at pending block");
+
+        Ok(Self {})
+    }
+}
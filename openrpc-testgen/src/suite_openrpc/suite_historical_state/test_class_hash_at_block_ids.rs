@@ -0,0 +1,63 @@
+use starknet_types_rpc::{BlockId, BlockTag};
+
+use crate::{
+    assert_eq_result,
+    utils::v7::{
+        accounts::account::ConnectedAccount, endpoints::errors::OpenRpcTestGenError,
+        providers::provider::Provider,
+    },
+    RandomizableAccountsTrait, RunnableTrait,
+};
+
+#[derive(Clone, Debug)]
+pub struct TestCase {}
+
+impl RunnableTrait for TestCase {
+    type Input = super::TestSuiteHistoricalState;
+
+    async fn run(test_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let account = test_input.random_paymaster_account.random_accounts()?;
+        let provider = account.provider();
+
+        let blocks = std::iter::once(&test_input.deployment)
+            .chain(test_input.writes.iter().map(|write| &write.block));
+        for block in blocks {
+            let by_number = provider
+                .get_class_hash_at(
+                    BlockId::Number(block.block_number),
+                    test_input.counter_address,
+                )
+                .await?;
+            assert_eq_result!(
+                by_number,
+                test_input.counter_class_hash,
+                "Class hash at block number {}",
+                block.block_number
+            );
+
+            let by_hash = provider
+                .get_class_hash_at(BlockId::Hash(block.block_hash), test_input.counter_address)
+                .await?;
+            assert_eq_result!(
+                by_hash,
+                test_input.counter_class_hash,
+                "Class hash at block hash {:#x}",
+                block.block_hash
+            );
+        }
+
+        for (tag, name) in [(BlockTag::Latest, "latest"), (BlockTag::Pending, "pending")] {
+            let by_tag = provider
+                .get_class_hash_at(BlockId::Tag(tag), test_input.counter_address)
+                .await?;
+            assert_eq_result!(
+                by_tag,
+                test_input.counter_class_hash,
+                "Class hash at {} block",
+                name
+            );
+        }
+
+        Ok(Self {})
+    }
+}
@@ -0,0 +1,46 @@
+use starknet_types_core::felt::Felt;
+use starknet_types_rpc::BlockId;
+
+use crate::{
+    utils::v7::{
+        accounts::account::ConnectedAccount,
+        endpoints::errors::OpenRpcTestGenError,
+        providers::{jsonrpc::StarknetError, provider::Provider},
+    },
+    RandomizableAccountsTrait, RunnableTrait,
+};
+
+/// Distance past the latest block of the queried block number, far enough not to be built while
+/// the test runs.
+const FUTURE_BLOCKS: u64 = 1000;
+
+#[derive(Clone, Debug)]
+pub struct TestCase {}
+
+impl RunnableTrait for TestCase {
+    type Input = super::TestSuiteHistoricalState;
+
+    async fn run(test_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let account = test_input.random_paymaster_account.random_accounts()?;
+        let future_block_number = account.provider().block_number().await? + FUTURE_BLOCKS;
+
+        super::check_counter_queries_fail(
+            test_input,
+            || BlockId::Number(future_block_number),
+            StarknetError::BlockNotFound,
+            &format!("future block number {}", future_block_number),
+        )
+        .await?;
+
+        let unknown_block_hash = Felt::from(rand::random::<u64>());
+        super::check_counter_queries_fail(
+            test_input,
+            || BlockId::Hash(unknown_block_hash),
+            StarknetError::BlockNotFound,
+            &format!("unknown block hash {:#x}", unknown_block_hash),
+        )
+        .await?;
+
+        Ok(Self {})
+    }
+}
@@ -0,0 +1,80 @@
+use starknet_types_rpc::{BlockId, BlockTag};
+
+use crate::{
+    assert_eq_result, assert_result,
+    utils::v7::{
+        accounts::account::ConnectedAccount, endpoints::errors::OpenRpcTestGenError,
+        providers::provider::Provider,
+    },
+    RandomizableAccountsTrait, RunnableTrait,
+};
+
+#[derive(Clone, Debug)]
+pub struct TestCase {}
+
+impl RunnableTrait for TestCase {
+    type Input = super::TestSuiteHistoricalState;
+
+    async fn run(test_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let account = test_input.random_paymaster_account.random_accounts()?;
+        let provider = account.provider();
+
+        for write in &test_input.writes {
+            let by_number = provider
+                .get_nonce(
+                    BlockId::Number(write.block.block_number),
+                    test_input.sender_address,
+                )
+                .await?;
+            assert_eq_result!(
+                by_number,
+                write.nonce,
+                "Nonce at block number {}",
+                write.block.block_number
+            );
+
+            let by_hash = provider
+                .get_nonce(
+                    BlockId::Hash(write.block.block_hash),
+                    test_input.sender_address,
+                )
+                .await?;
+            assert_eq_result!(
+                by_hash,
+                write.nonce,
+                "Nonce at block hash {:#x}",
+                write.block.block_hash
+            );
+        }
+
+        let last_nonce = test_input
+            .writes
+            .last()
+            .ok_or_else(|| OpenRpcTestGenError::Other("No historical writes".to_string()))?
+            .nonce;
+
+        // Other suites keep sending transactions from the same accounts after the setup.
+        let latest = provider
+            .get_nonce(BlockId::Tag(BlockTag::Latest), test_input.sender_address)
+            .await?;
+        let pending = provider
+            .get_nonce(BlockId::Tag(BlockTag::Pending), test_input.sender_address)
+            .await?;
+        assert_result!(
+            latest >= last_nonce,
+            format!(
+                "Latest nonce {:#x} below nonce {:#x} of last historical write",
+                latest, last_nonce
+            )
+        );
+        assert_result!(
+            pending >= latest,
+            format!(
+                "Pending nonce {:#x} below latest nonce {:#x}",
+                pending, latest
+            )
+        );
+
+        Ok(Self {})
+    }
+}
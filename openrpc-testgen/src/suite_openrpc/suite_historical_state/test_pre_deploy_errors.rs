@@ -0,0 +1,32 @@
+use starknet_types_rpc::BlockId;
+use tracing::info;
+
+use crate::{
+    utils::v7::{endpoints::errors::OpenRpcTestGenError, providers::jsonrpc::StarknetError},
+    RunnableTrait,
+};
+
+#[derive(Clone, Debug)]
+pub struct TestCase {}
+
+impl RunnableTrait for TestCase {
+    type Input = super::TestSuiteHistoricalState;
+
+    async fn run(test_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let Some(pre_deploy_block_number) = test_input.deployment.block_number.checked_sub(1)
+        else {
+            info!("Skipping pre-deploy queries, the counter was deployed in the genesis block");
+            return Ok(Self {});
+        };
+
+        super::check_counter_queries_fail(
+            test_input,
+            || BlockId::Number(pre_deploy_block_number),
+            StarknetError::ContractNotFound,
+            &format!("pre-deploy block number {}", pre_deploy_block_number),
+        )
+        .await?;
+
+        Ok(Self {})
+    }
+}
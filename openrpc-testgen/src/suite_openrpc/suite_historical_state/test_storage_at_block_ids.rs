@@ -0,0 +1,80 @@
+use starknet_types_rpc::{BlockId, BlockTag};
+
+use crate::{
+    assert_eq_result,
+    utils::v7::{
+        accounts::account::ConnectedAccount,
+        endpoints::{errors::OpenRpcTestGenError, utils::get_selector_from_name},
+        providers::provider::Provider,
+    },
+    RandomizableAccountsTrait, RunnableTrait,
+};
+
+#[derive(Clone, Debug)]
+pub struct TestCase {}
+
+impl RunnableTrait for TestCase {
+    type Input = super::TestSuiteHistoricalState;
+
+    async fn run(test_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let account = test_input.random_paymaster_account.random_accounts()?;
+        let provider = account.provider();
+        let counter_key = get_selector_from_name("counter")?;
+
+        for write in &test_input.writes {
+            let by_number = provider
+                .get_storage_at(
+                    test_input.counter_address,
+                    counter_key,
+                    BlockId::Number(write.block.block_number),
+                )
+                .await?;
+            assert_eq_result!(
+                by_number,
+                write.counter,
+                "Storage at block number {}",
+                write.block.block_number
+            );
+
+            let by_hash = provider
+                .get_storage_at(
+                    test_input.counter_address,
+                    counter_key,
+                    BlockId::Hash(write.block.block_hash),
+                )
+                .await?;
+            assert_eq_result!(
+                by_hash,
+                write.counter,
+                "Storage at block hash {:#x}",
+                write.block.block_hash
+            );
+        }
+
+        let last_counter = test_input
+            .writes
+            .last()
+            .ok_or_else(|| OpenRpcTestGenError::Other("No historical writes".to_string()))?
+            .counter;
+
+        let latest = provider
+            .get_storage_at(
+                test_input.counter_address,
+                counter_key,
+                BlockId::Tag(BlockTag::Latest),
+            )
+            .await?;
+        assert_eq_result!(latest, last_counter, "Storage at latest block");
+
+        let pending = provider
+            .get_storage_at(
+                test_input.counter_address,
+                counter_key,
+                BlockId::Tag(BlockTag::Pending),
+            )
+            .await?;
+        assert_eq_result!(pending, last_counter, "Storage at pending block");
+
+        Ok(Self {})
+    }
+}
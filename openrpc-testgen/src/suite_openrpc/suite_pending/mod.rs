@@ -0,0 +1,106 @@
+use std::time::{Duration, Instant};
+
+use starknet_types_core::felt::Felt;
+use starknet_types_rpc::{BlockId, BlockTag, MaybePendingBlockWithTxHashes};
+
+use super::RandomSingleOwnerAccount;
+use crate::{
+    utils::v7::{
+        accounts::{
+            account::{Account, ConnectedAccount},
+            single_owner::SingleOwnerAccount,
+        },
+        contract::declare_and_deploy::{ensure_counter, increase_call},
+        endpoints::errors::OpenRpcTestGenError,
+        providers::{
+            jsonrpc::{HttpTransport, JsonRpcClient, StarknetError},
+            provider::{Provider, ProviderError, TxnReceiptWithBlockInfo},
+        },
+        signers::local_wallet::LocalWallet,
+    },
+    RandomizableAccountsTrait, SetupableTrait,
+};
+
+pub mod test_pending_block;
+pub mod test_pending_receipt;
+pub mod test_pending_state_reads;
+pub mod test_pending_state_update;
+
+#[derive(Clone, Debug)]
+pub struct TestSuitePending {
+    pub random_paymaster_account: RandomSingleOwnerAccount,
+    /// `Counter` contract increased by the transactions of the tests.
+    pub counter_address: Felt,
+}
+
+impl SetupableTrait for TestSuitePending {
+    type Input = super::TestSuiteOpenRpc;
+
+    async fn setup(setup_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let account = setup_input.random_paymaster_account.random_accounts()?;
+
+        let counter = ensure_counter(&account, setup_input.udc_address).await?;
+
+        Ok(Self {
+            random_paymaster_account: setup_input.random_paymaster_account.clone(),
+            counter_address: counter.address,
+        })
+    }
+}
+
+/// Whether the node serves a pending block. Nodes without pending support either reject the
+/// `pending` tag or serve the latest block for it, and the tests skip their pending checks.
+pub async fn pending_supported(
+    provider: &JsonRpcClient<HttpTransport>,
+) -> Result<bool, OpenRpcTestGenError> {
+    match provider
+        .get_block_with_tx_hashes(BlockId::Tag(BlockTag::Pending))
+        .await
+    {
+        Ok(MaybePendingBlockWithTxHashes::Pending(_)) => Ok(true),
+        Ok(MaybePendingBlockWithTxHashes::Block(_)) => Ok(false),
+        Err(ProviderError::StarknetError(StarknetError::BlockNotFound)) => Ok(false),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Sends a transaction increasing the counter by `amount` and returns its hash with the first
+/// receipt served for it, which lacks the block fields while the transaction is pending.
+pub async fn send_increase(
+    account: &SingleOwnerAccount<JsonRpcClient<HttpTransport>, LocalWallet>,
+    counter_address: Felt,
+    amount: Felt,
+) -> Result<(Felt, TxnReceiptWithBlockInfo), OpenRpcTestGenError> {
+    let transaction_hash = account
+        .execute_v3(vec![increase_call(counter_address, amount)?])
+        .send()
+        .await?
+        .transaction_hash;
+
+    let start = Instant::now();
+    loop {
+        match account
+            .provider()
+            .get_transaction_receipt_with_block_info(transaction_hash)
+            .await
+        {
+            Ok(receipt) => return Ok((transaction_hash, receipt)),
+            Err(ProviderError::StarknetError(StarknetError::TransactionHashNotFound)) => {}
+            Err(e) => return Err(e.into()),
+        }
+
+        if start.elapsed() > Duration::from_secs(60) {
+            return Err(OpenRpcTestGenError::Timeout(format!(
+                "No receipt for transaction {:#x} in 60 seconds.",
+                transaction_hash
+            )));
+        }
+        tokio::time::sleep(Duration::from_millis(500)).await;
+    }
+}
+
+#[cfg(not(feature = "rust-analyzer"))]
+include!(concat!(
+    env!("OUT_DIR"),
+    "/generated_tests_suite_openrpc_suite_pending.rs"
+));
@@ -0,0 +1,51 @@
+use starknet_types_rpc::{BlockId, BlockTag, MaybePendingBlockWithTxs};
+use tracing::info;
+
+use crate::{
+    assert_result,
+    utils::v7::{
+        accounts::account::ConnectedAccount, endpoints::errors::OpenRpcTestGenError,
+        providers::provider::Provider,
+    },
+    RandomizableAccountsTrait, RunnableTrait,
+};
+
+#[derive(Clone, Debug)]
+pub struct TestCase {}
+
+impl RunnableTrait for TestCase {
+    type Input = super::TestSuitePending;
+
+    async fn run(test_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let account = test_input.random_paymaster_account.random_accounts()?;
+        let provider = account.provider();
+
+        if !super::pending_supported(provider).await? {
+            info!("Skipping pending block checks, the node doesn't serve a pending block");
+            return Ok(Self {});
+        }
+
+        let latest_before = provider.block_hash_and_number().await?.block_hash;
+        let pending_block = provider
+            .get_block_with_txs(BlockId::Tag(BlockTag::Pending))
+            .await?;
+        let latest_after = provider.block_hash_and_number().await?.block_hash;
+
+        let MaybePendingBlockWithTxs::Pending(pending_block) = pending_block else {
+            return Err(OpenRpcTestGenError::Other(
+                "Pending block has a block hash and number".to_string(),
+            ));
+        };
+
+        let parent_hash = pending_block.pending_block_header.parent_hash;
+        assert_result!(
+            parent_hash == latest_before || parent_hash == latest_after,
+            format!(
+                "Pending block parent hash {:#x} is not the latest block hash {:#x}",
+                parent_hash, latest_after
+            )
+        );
+
+        Ok(Self {})
+    }
+}
@@ -0,0 +1,70 @@
+use starknet_types_core::felt::Felt;
+use tracing::info;
+
+use crate::{
+    assert_eq_result, assert_result,
+    utils::v7::{
+        accounts::account::ConnectedAccount,
+        endpoints::{errors::OpenRpcTestGenError, utils::wait_for_sent_transaction},
+        providers::provider::Provider,
+    },
+    RandomizableAccountsTrait, RunnableTrait,
+};
+
+#[derive(Clone, Debug)]
+pub struct TestCase {}
+
+impl RunnableTrait for TestCase {
+    type Input = super::TestSuitePending;
+
+    async fn run(test_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let account = test_input.random_paymaster_account.random_accounts()?;
+        let provider = account.provider();
+
+        if !super::pending_supported(provider).await? {
+            info!("Skipping pending receipt checks, the node doesn't serve a pending block");
+            return Ok(Self {});
+        }
+
+        let (transaction_hash, receipt) =
+            super::send_increase(&account, test_input.counter_address, Felt::ONE).await?;
+
+        if receipt.block_hash.is_some() {
+            info!(
+                "Skipping pending receipt checks, transaction {:#x} was included in a block before its receipt was fetched",
+                transaction_hash
+            );
+        } else {
+            assert_result!(
+                receipt.block_number.is_none(),
+                format!(
+                    "Pending receipt of transaction {:#x} has block number {:?}",
+                    transaction_hash, receipt.block_number
+                )
+            );
+
+            let receipt = serde_json::to_value(&receipt)?;
+            assert_eq_result!(
+                receipt["finality_status"].as_str(),
+                Some("ACCEPTED_ON_L2"),
+                "Finality status of pending transaction {:#x}",
+                transaction_hash
+            );
+        }
+
+        wait_for_sent_transaction(transaction_hash, &account).await?;
+
+        let receipt = provider
+            .get_transaction_receipt_with_block_info(transaction_hash)
+            .await?;
+        assert_result!(
+            receipt.block_hash.is_some() && receipt.block_number.is_some(),
+            format!(
+                "Receipt of accepted transaction {:#x} has no block: {:?}",
+                transaction_hash, receipt
+            )
+        );
+
+        Ok(Self {})
+    }
+}
@@ -0,0 +1,109 @@
+use starknet_types_core::felt::Felt;
+use starknet_types_rpc::{BlockId, BlockTag, FunctionCall};
+use tracing::info;
+
+use crate::{
+    assert_eq_result, assert_result,
+    utils::v7::{
+        accounts::account::{Account, ConnectedAccount},
+        contract::declare_and_deploy::increase_call,
+        endpoints::{
+            errors::OpenRpcTestGenError,
+            utils::{get_selector_from_name, wait_for_sent_transaction},
+        },
+        providers::provider::Provider,
+    },
+    RandomizableAccountsTrait, RunnableTrait,
+};
+
+#[derive(Clone, Debug)]
+pub struct TestCase {}
+
+impl RunnableTrait for TestCase {
+    type Input = super::TestSuitePending;
+
+    async fn run(test_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let mut account = test_input.random_paymaster_account.random_accounts()?;
+        account.set_block_id(BlockId::Tag(BlockTag::Pending));
+        let provider = account.provider();
+
+        if !super::pending_supported(provider).await? {
+            info!("Skipping pending state reads, the node doesn't serve a pending block");
+            return Ok(Self {});
+        }
+
+        let counter_key = get_selector_from_name("counter")?;
+        let get_counter = FunctionCall {
+            contract_address: test_input.counter_address,
+            entry_point_selector: get_selector_from_name("get_counter")?,
+            calldata: vec![],
+        };
+
+        let nonce_before = provider
+            .get_nonce(BlockId::Tag(BlockTag::Pending), account.address())
+            .await?;
+        let counter_before = provider
+            .get_storage_at(
+                test_input.counter_address,
+                counter_key,
+                BlockId::Tag(BlockTag::Pending),
+            )
+            .await?;
+
+        let amount = Felt::from(rand::random::<u32>());
+        let (transaction_hash, _) =
+            super::send_increase(&account, test_input.counter_address, amount).await?;
+
+        let nonce = provider
+            .get_nonce(BlockId::Tag(BlockTag::Pending), account.address())
+            .await?;
+        assert_eq_result!(
+            nonce,
+            nonce_before + Felt::ONE,
+            "Pending nonce after transaction {:#x}",
+            transaction_hash
+        );
+
+        let counter = provider
+            .get_storage_at(
+                test_input.counter_address,
+                counter_key,
+                BlockId::Tag(BlockTag::Pending),
+            )
+            .await?;
+        assert_eq_result!(
+            counter,
+            counter_before + amount,
+            "Pending storage after transaction {:#x}",
+            transaction_hash
+        );
+
+        let called_counter = provider
+            .call(get_counter, BlockId::Tag(BlockTag::Pending))
+            .await?;
+        assert_eq_result!(
+            called_counter,
+            vec![counter_before + amount],
+            "Pending call result after transaction {:#x}",
+            transaction_hash
+        );
+
+        // The next nonce is only valid on top of the pending state.
+        let estimate = account
+            .execute_v3(vec![increase_call(test_input.counter_address, amount)?])
+            .nonce(nonce)
+            .estimate_fee()
+            .await?;
+        assert_result!(
+            estimate.overall_fee != Felt::ZERO,
+            format!(
+                "Zero fee estimated at pending nonce {:#x} after transaction {:#x}",
+                nonce, transaction_hash
+            )
+        );
+
+        wait_for_sent_transaction(transaction_hash, &account).await?;
+
+        Ok(Self {})
+    }
+}
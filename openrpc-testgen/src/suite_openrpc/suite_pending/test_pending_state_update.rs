@@ -0,0 +1,63 @@
+use starknet_types_core::felt::Felt;
+use starknet_types_rpc::{BlockId, BlockTag, MaybePendingStateUpdate};
+use tracing::info;
+
+use crate::{
+    assert_result,
+    utils::v7::{
+        accounts::account::ConnectedAccount, endpoints::errors::OpenRpcTestGenError,
+        providers::provider::Provider,
+    },
+    RandomizableAccountsTrait, RunnableTrait,
+};
+
+#[derive(Clone, Debug)]
+pub struct TestCase {}
+
+impl RunnableTrait for TestCase {
+    type Input = super::TestSuitePending;
+
+    async fn run(test_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let account = test_input.random_paymaster_account.random_accounts()?;
+        let provider = account.provider();
+
+        if !super::pending_supported(provider).await? {
+            info!("Skipping pending state update checks, the node doesn't serve a pending block");
+            return Ok(Self {});
+        }
+
+        let latest_root_before = latest_new_root(provider).await?;
+        let state_update = provider
+            .get_state_update(BlockId::Tag(BlockTag::Pending))
+            .await?;
+        let latest_root_after = latest_new_root(provider).await?;
+
+        let MaybePendingStateUpdate::Pending(state_update) = state_update else {
+            return Err(OpenRpcTestGenError::Other(
+                "Pending state update has a block hash and a new root".to_string(),
+            ));
+        };
+        let old_root = state_update.old_root;
+        assert_result!(
+            old_root == latest_root_before || old_root == latest_root_after,
+            format!(
+                "Pending state update old root {:#x} is not the latest new root {:#x}",
+                old_root, latest_root_after
+            )
+        );
+
+        Ok(Self {})
+    }
+}
+
+async fn latest_new_root<P: Provider>(provider: &P) -> Result<Felt, OpenRpcTestGenError> {
+    match provider
+        .get_state_update(BlockId::Tag(BlockTag::Latest))
+        .await?
+    {
+        MaybePendingStateUpdate::Block(state_update) => Ok(state_update.new_root),
+        MaybePendingStateUpdate::Pending(_) => Err(OpenRpcTestGenError::Other(
+            "Latest state update is pending".to_string(),
+        )),
+    }
+}
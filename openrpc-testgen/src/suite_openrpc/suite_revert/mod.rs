@@ -0,0 +1,197 @@
+use serde_json::Value;
+use starknet_types_core::felt::Felt;
+use starknet_types_rpc::{BlockId, BlockTag, FunctionCall, TxnExecutionStatus};
+
+use super::RandomSingleOwnerAccount;
+use crate::{
+    assert_eq_result, assert_result,
+    utils::v7::{
+        accounts::{
+            account::{Account, ConnectedAccount},
+            call::Call,
+            single_owner::SingleOwnerAccount,
+        },
+        contract::declare_and_deploy::{ensure_declared, ensure_deployed},
+        endpoints::{
+            errors::OpenRpcTestGenError,
+            utils::{get_selector_from_name, wait_for_sent_transaction, STRK_ADDRESS},
+        },
+        providers::{
+            jsonrpc::{HttpTransport, JsonRpcClient},
+            provider::Provider,
+        },
+        signers::local_wallet::LocalWallet,
+    },
+    RandomizableAccountsTrait, SetupableTrait,
+};
+
+pub mod test_nested_revert_reason;
+pub mod test_out_of_resources_revert;
+pub mod test_revert_reason;
+
+/// Gas bound of the reverting transactions, as `estimateFee` fails for them.
+pub const REVERTED_TXN_GAS: u64 = 100_000;
+
+#[derive(Clone, Debug)]
+pub struct TestSuiteRevert {
+    pub random_paymaster_account: RandomSingleOwnerAccount,
+    /// `Reverter` contract panicking on every call.
+    pub reverter_address: Felt,
+}
+
+impl SetupableTrait for TestSuiteRevert {
+    type Input = super::TestSuiteOpenRpc;
+
+    async fn setup(setup_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let account = setup_input.random_paymaster_account.random_accounts()?;
+
+        let reverter_class_hash = ensure_declared(&account, "Reverter").await?.class_hash();
+        let reverter_address = ensure_deployed(
+            &account,
+            setup_input.udc_address,
+            reverter_class_hash,
+            Felt::ZERO,
+            vec![],
+        )
+        .await?
+        .contract_address();
+
+        Ok(Self {
+            random_paymaster_account: setup_input.random_paymaster_account.clone(),
+            reverter_address,
+        })
+    }
+}
+
+/// Invokes `function` of the reverter, passing the panic message if any, and checks that the
+/// transaction is included as reverted:
+/// - `getTransactionStatus` reports `REVERTED`,
+/// - the receipt has a `revert_reason` and a fee actually charged to the sender,
+/// - `traceTransaction` reports the `revert_reason` in `execute_invocation`.
+///
+/// Both revert reasons must mention the message, either as text or as a felt.
+pub async fn check_reverted_invoke(
+    test_input: &TestSuiteRevert,
+    function: &str,
+    message: Option<&str>,
+) -> Result<(), OpenRpcTestGenError> {
+    let account = test_input.random_paymaster_account.random_accounts()?;
+    let provider = account.provider();
+
+    let call = Call {
+        to: test_input.reverter_address,
+        selector: get_selector_from_name(function)?,
+        calldata: message
+            .map(|message| Felt::from_bytes_be_slice(message.as_bytes()))
+            .into_iter()
+            .collect(),
+    };
+
+    let balance_before = strk_balance(&account).await?;
+    let result = account
+        .execute_v3(vec![call])
+        .gas(REVERTED_TXN_GAS)
+        .send()
+        .await?;
+    let transaction_hash = result.transaction_hash;
+
+    match wait_for_sent_transaction(transaction_hash, &account).await {
+        Err(OpenRpcTestGenError::TransactionFailed(_)) => {}
+        Ok(_) => {
+            return Err(OpenRpcTestGenError::Other(format!(
+                "Transaction {:#x} calling {} succeeded",
+                transaction_hash, function
+            )))
+        }
+        Err(e) => return Err(e),
+    }
+
+    let status = provider.get_transaction_status(transaction_hash).await?;
+    assert_eq_result!(
+        status.execution_status,
+        Some(TxnExecutionStatus::Reverted),
+        "Execution status of transaction {:#x}",
+        transaction_hash
+    );
+
+    let receipt = serde_json::to_value(provider.get_transaction_receipt(transaction_hash).await?)?;
+    assert_eq_result!(
+        receipt["execution_status"].as_str(),
+        Some("REVERTED"),
+        "Execution status in receipt {}",
+        receipt
+    );
+    check_revert_reason(&receipt["revert_reason"], message)?;
+
+    let fee: Felt = serde_json::from_value(receipt["actual_fee"]["amount"].clone())?;
+    assert_result!(
+        fee != Felt::ZERO,
+        format!(
+            "No fee charged for reverted transaction {:#x}",
+            transaction_hash
+        )
+    );
+    let balance_after = strk_balance(&account).await?;
+    assert_eq_result!(
+        balance_before - balance_after,
+        fee,
+        "STRK balance change of sender after reverted transaction {:#x}",
+        transaction_hash
+    );
+
+    let trace = serde_json::to_value(provider.trace_transaction(transaction_hash).await?)?;
+    check_revert_reason(&trace["execute_invocation"]["revert_reason"], message)?;
+
+    Ok(())
+}
+
+fn check_revert_reason(
+    revert_reason: &Value,
+    message: Option<&str>,
+) -> Result<(), OpenRpcTestGenError> {
+    let revert_reason = revert_reason.as_str().unwrap_or_default();
+    assert_result!(!revert_reason.is_empty(), "Missing revert reason");
+
+    if let Some(message) = message {
+        let message_felt = format!("{:#x}", Felt::from_bytes_be_slice(message.as_bytes()));
+        assert_result!(
+            revert_reason.contains(message) || revert_reason.contains(&message_felt),
+            format!(
+                "Revert reason {:?} doesn't mention {:?} ({})",
+                revert_reason, message, message_felt
+            )
+        );
+    }
+
+    Ok(())
+}
+
+/// Low part of the STRK balance of the account at the pending block.
+async fn strk_balance(
+    account: &SingleOwnerAccount<JsonRpcClient<HttpTransport>, LocalWallet>,
+) -> Result<Felt, OpenRpcTestGenError> {
+    let balance = account
+        .provider()
+        .call(
+            FunctionCall {
+                contract_address: STRK_ADDRESS,
+                entry_point_selector: get_selector_from_name("balanceOf")?,
+                calldata: vec![account.address()],
+            },
+            BlockId::Tag(BlockTag::Pending),
+        )
+        .await?;
+
+    balance.first().copied().ok_or_else(|| {
+        OpenRpcTestGenError::Other(format!(
+            "Empty balanceOf result for {:#x}",
+            account.address()
+        ))
+    })
+}
+
+#[cfg(not(feature = "rust-analyzer"))]
+include!(concat!(
+    env!("OUT_DIR"),
+    "/generated_tests_suite_openrpc_suite_revert.rs"
+));
@@ -0,0 +1,14 @@
+use crate::{utils::v7::endpoints::errors::OpenRpcTestGenError, RunnableTrait};
+
+#[derive(Clone, Debug)]
+pub struct TestCase {}
+
+impl RunnableTrait for TestCase {
+    type Input = super::TestSuiteRevert;
+
+    async fn run(test_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        super::check_reverted_invoke(test_input, "exhaust_resources", None).await?;
+
+        Ok(Self {})
+    }
+}
@@ -0,0 +1,199 @@
+use serde_json::Value;
+use starknet_types_core::felt::Felt;
+use starknet_types_rpc::{FeeEstimate, SimulateTransactionsResult};
+
+use super::RandomSingleOwnerAccount;
+use crate::{
+    assert_eq_result, assert_result,
+    utils::{
+        conversions::felt_to_u128::felt_to_u128,
+        v7::{
+            accounts::{account::ConnectedAccount, single_owner::SingleOwnerAccount},
+            contract::declare_and_deploy::{ensure_declared, ensure_deployed},
+            endpoints::{
+                errors::OpenRpcTestGenError,
+                utils::{ETH_ADDRESS, STRK_ADDRESS},
+            },
+            providers::{
+                jsonrpc::{HttpTransport, JsonRpcClient},
+                provider::Provider,
+            },
+            signers::local_wallet::LocalWallet,
+        },
+    },
+    RandomizableAccountsTrait, SetupableTrait,
+};
+
+pub mod test_simulate_declare;
+pub mod test_simulate_deploy_account;
+pub mod test_simulate_invoke;
+
+/// Every combination of `(SKIP_VALIDATE, SKIP_FEE_CHARGE)`, starting with the unflagged one.
+pub const SIMULATION_FLAGS: [(bool, bool); 4] =
+    [(false, false), (true, false), (false, true), (true, true)];
+
+#[derive(Clone, Debug)]
+pub struct TestSuiteSimulate {
+    pub random_paymaster_account: RandomSingleOwnerAccount,
+    pub account_class_hash: Felt,
+    /// `SimulatedStorage` contract invoked by the tests.
+    pub storage_address: Felt,
+}
+
+impl SetupableTrait for TestSuiteSimulate {
+    type Input = super::TestSuiteOpenRpc;
+
+    async fn setup(setup_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let account = setup_input.random_paymaster_account.random_accounts()?;
+
+        let storage_class_hash = ensure_declared(&account, "SimulatedStorage")
+            .await?
+            .class_hash();
+        let storage_address = ensure_deployed(
+            &account,
+            setup_input.udc_address,
+            storage_class_hash,
+            Felt::ZERO,
+            vec![],
+        )
+        .await?
+        .contract_address();
+
+        Ok(Self {
+            random_paymaster_account: setup_input.random_paymaster_account.clone(),
+            account_class_hash: setup_input.account_class_hash,
+            storage_address,
+        })
+    }
+}
+
+/// Checks the invocations present in the trace of a simulation run with the given flags and its
+/// fee estimation against `estimateFee`, returning the normalized state diff of the trace.
+///
+/// Skipping validation only removes work, so the fee may then be lower than the estimate.
+pub fn check_simulation(
+    simulation: &SimulateTransactionsResult<Felt>,
+    estimate: &FeeEstimate<Felt>,
+    skip_validate: bool,
+    skip_fee_charge: bool,
+) -> Result<Value, OpenRpcTestGenError> {
+    let simulation = serde_json::to_value(simulation)?;
+    let trace = &simulation["transaction_trace"];
+
+    assert_eq_result!(
+        has_invocation(trace, "validate_invocation"),
+        !skip_validate,
+        "validate_invocation in trace simulated with SKIP_VALIDATE: {}, SKIP_FEE_CHARGE: {}",
+        skip_validate,
+        skip_fee_charge
+    );
+    assert_eq_result!(
+        has_invocation(trace, "fee_transfer_invocation"),
+        !skip_fee_charge,
+        "fee_transfer_invocation in trace simulated with SKIP_VALIDATE: {}, SKIP_FEE_CHARGE: {}",
+        skip_validate,
+        skip_fee_charge
+    );
+
+    let fee_estimation: FeeEstimate<Felt> =
+        serde_json::from_value(simulation["fee_estimation"].clone())?;
+    if skip_validate {
+        assert_result!(
+            fee_estimation.overall_fee <= estimate.overall_fee,
+            format!(
+                "Simulated fee {} without validation exceeds estimated fee {}",
+                fee_estimation.overall_fee, estimate.overall_fee
+            )
+        );
+    } else {
+        assert_eq_result!(
+            fee_estimation.overall_fee,
+            estimate.overall_fee,
+            "Simulated fee with SKIP_FEE_CHARGE: {}",
+            skip_fee_charge
+        );
+    }
+
+    Ok(normalized_state_diff(&trace["state_diff"]))
+}
+
+/// Compares the state diff of the trace of a submitted transaction with the one simulated
+/// without flags.
+pub async fn check_state_diff(
+    account: &SingleOwnerAccount<JsonRpcClient<HttpTransport>, LocalWallet>,
+    transaction_hash: Felt,
+    simulated_state_diff: &Value,
+) -> Result<(), OpenRpcTestGenError> {
+    let trace = serde_json::to_value(
+        account
+            .provider()
+            .trace_transaction(transaction_hash)
+            .await?,
+    )?;
+
+    assert_eq_result!(
+        &normalized_state_diff(&trace["state_diff"]),
+        simulated_state_diff,
+        "State diff of transaction {:#x}",
+        transaction_hash
+    );
+
+    Ok(())
+}
+
+/// Resource bounds `(gas, gas_price)` of V3 transactions covering the estimated fee twice over.
+pub fn v3_resource_bounds(
+    estimate: &FeeEstimate<Felt>,
+) -> Result<(u64, u128), OpenRpcTestGenError> {
+    let overall_fee = felt_to_u128(estimate.overall_fee)?;
+    let gas_price = felt_to_u128(estimate.gas_price)?.max(1);
+    let gas = u64::try_from(overall_fee.div_ceil(gas_price) * 2).map_err(|_| {
+        OpenRpcTestGenError::Other(format!("Gas of fee {} out of range", overall_fee))
+    })?;
+
+    Ok((gas, gas_price * 2))
+}
+
+fn has_invocation(trace: &Value, field: &str) -> bool {
+    trace
+        .get(field)
+        .is_some_and(|invocation| !invocation.is_null())
+}
+
+/// Sorts the lists of a state diff and drops the storage values of the fee tokens, as the
+/// balance of the sequencer also depends on the other transactions of the block.
+fn normalized_state_diff(state_diff: &Value) -> Value {
+    let mut state_diff = state_diff.clone();
+
+    if let Some(storage_diffs) = state_diff["storage_diffs"].as_array_mut() {
+        for storage_diff in storage_diffs.iter_mut() {
+            let is_fee_token = storage_diff["address"]
+                .as_str()
+                .and_then(|address| Felt::from_hex(address).ok())
+                .is_some_and(|address| address == ETH_ADDRESS || address == STRK_ADDRESS);
+
+            if let Some(entries) = storage_diff["storage_entries"].as_array_mut() {
+                if is_fee_token {
+                    for entry in entries.iter_mut() {
+                        entry["value"] = Value::Null;
+                    }
+                }
+                entries.sort_by_key(Value::to_string);
+            }
+        }
+    }
+
+    if let Some(fields) = state_diff.as_object_mut() {
+        for list in fields.values_mut().filter_map(Value::as_array_mut) {
+            list.sort_by_key(Value::to_string);
+        }
+    }
+
+    state_diff
+}
+
+#[cfg(not(feature = "rust-analyzer"))]
+include!(concat!(
+    env!("OUT_DIR"),
+    "/generated_tests_suite_openrpc_suite_simulate.rs"
+));
@@ -0,0 +1,82 @@
+use std::sync::Arc;
+
+use serde_json::Value;
+use starknet_types_core::felt::Felt;
+use tracing::info;
+
+use super::{check_simulation, check_state_diff, v3_resource_bounds, SIMULATION_FLAGS};
+use crate::{
+    utils::v7::{
+        accounts::account::Account,
+        contract::{declare_and_deploy::is_declared, registry::ContractRegistry},
+        endpoints::{errors::OpenRpcTestGenError, utils::wait_for_sent_transaction},
+    },
+    RandomizableAccountsTrait, RunnableTrait,
+};
+
+#[derive(Clone, Debug)]
+pub struct TestCase {}
+
+impl RunnableTrait for TestCase {
+    type Input = super::TestSuiteSimulate;
+
+    async fn run(test_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let account = test_input.random_paymaster_account.random_accounts()?;
+        let registry = ContractRegistry::global();
+
+        if is_declared(&account, "SimulatedDeclareV2").await? {
+            info!("Skipping V2 declare simulation, SimulatedDeclareV2 is already declared");
+        } else {
+            let (contract_class, compiled_class_hash) = registry.get("SimulatedDeclareV2")?;
+            let contract_class = Arc::new(contract_class);
+
+            let declaration = account.declare_v2(contract_class.clone(), compiled_class_hash);
+            let estimate = declaration.estimate_fee().await?;
+            let mut state_diff = Value::Null;
+            for (skip_validate, skip_fee_charge) in SIMULATION_FLAGS {
+                let simulation = account
+                    .declare_v2(contract_class.clone(), compiled_class_hash)
+                    .max_fee(estimate.overall_fee * Felt::TWO)
+                    .simulate(skip_validate, skip_fee_charge)
+                    .await?;
+                let simulated_state_diff =
+                    check_simulation(&simulation, &estimate, skip_validate, skip_fee_charge)?;
+                if !skip_validate && !skip_fee_charge {
+                    state_diff = simulated_state_diff;
+                }
+            }
+            let result = declaration.send().await?;
+            wait_for_sent_transaction(result.transaction_hash, &account).await?;
+            check_state_diff(&account, result.transaction_hash, &state_diff).await?;
+        }
+
+        if is_declared(&account, "SimulatedDeclareV3").await? {
+            info!("Skipping V3 declare simulation, SimulatedDeclareV3 is already declared");
+        } else {
+            let (contract_class, compiled_class_hash) = registry.get("SimulatedDeclareV3")?;
+
+            let declaration = account.declare_v3(contract_class.clone(), compiled_class_hash);
+            let estimate = declaration.estimate_fee().await?;
+            let (gas, gas_price) = v3_resource_bounds(&estimate)?;
+            let mut state_diff = Value::Null;
+            for (skip_validate, skip_fee_charge) in SIMULATION_FLAGS {
+                let simulation = account
+                    .declare_v3(contract_class.clone(), compiled_class_hash)
+                    .gas(gas)
+                    .gas_price(gas_price)
+                    .simulate(skip_validate, skip_fee_charge)
+                    .await?;
+                let simulated_state_diff =
+                    check_simulation(&simulation, &estimate, skip_validate, skip_fee_charge)?;
+                if !skip_validate && !skip_fee_charge {
+                    state_diff = simulated_state_diff;
+                }
+            }
+            let result = declaration.send().await?;
+            wait_for_sent_transaction(result.transaction_hash, &account).await?;
+            check_state_diff(&account, result.transaction_hash, &state_diff).await?;
+        }
+
+        Ok(Self {})
+    }
+}
@@ -0,0 +1,104 @@
+use serde_json::Value;
+use starknet_types_core::felt::Felt;
+use starknet_types_rpc::{BlockId, BlockTag};
+
+use super::{check_simulation, check_state_diff, v3_resource_bounds, SIMULATION_FLAGS};
+use crate::{
+    utils::v7::{
+        accounts::{
+            account::{Account, ConnectedAccount},
+            factory::{open_zeppelin::OpenZeppelinAccountFactory, AccountFactory},
+        },
+        endpoints::{
+            errors::OpenRpcTestGenError,
+            utils::{
+                setup_generated_account, wait_for_sent_transaction, ETH_ADDRESS, STRK_ADDRESS,
+            },
+        },
+        signers::{key_pair::SigningKey, local_wallet::LocalWallet},
+    },
+    RandomizableAccountsTrait, RunnableTrait,
+};
+
+/// Amount of each fee token sent to the deployed accounts.
+const ACCOUNT_FUNDING: u128 = 100_000_000_000_000_000;
+
+#[derive(Clone, Debug)]
+pub struct TestCase {}
+
+impl RunnableTrait for TestCase {
+    type Input = super::TestSuiteSimulate;
+
+    async fn run(test_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let account = test_input.random_paymaster_account.random_accounts()?;
+
+        let mut factory = OpenZeppelinAccountFactory::new(
+            test_input.account_class_hash,
+            account.chain_id(),
+            LocalWallet::from(SigningKey::from_random()),
+            account.provider().clone(),
+        )
+        .await?;
+        factory.set_block_id(BlockId::Tag(BlockTag::Pending));
+
+        let salt = Felt::from(rand::random::<u64>());
+        let deployment_v1 = factory.deploy_v1(salt);
+        setup_generated_account(
+            account.clone(),
+            ETH_ADDRESS,
+            STRK_ADDRESS,
+            Felt::from(ACCOUNT_FUNDING),
+            deployment_v1.address(),
+        )
+        .await?;
+        let estimate = deployment_v1.estimate_fee().await?;
+        let mut state_diff = Value::Null;
+        for (skip_validate, skip_fee_charge) in SIMULATION_FLAGS {
+            let simulation = factory
+                .deploy_v1(salt)
+                .max_fee(estimate.overall_fee * Felt::TWO)
+                .simulate(skip_validate, skip_fee_charge)
+                .await?;
+            let simulated_state_diff =
+                check_simulation(&simulation, &estimate, skip_validate, skip_fee_charge)?;
+            if !skip_validate && !skip_fee_charge {
+                state_diff = simulated_state_diff;
+            }
+        }
+        let result = deployment_v1.send().await?;
+        wait_for_sent_transaction(result.transaction_hash, &account).await?;
+        check_state_diff(&account, result.transaction_hash, &state_diff).await?;
+
+        let salt = Felt::from(rand::random::<u64>());
+        let deployment_v3 = factory.deploy_v3(salt);
+        setup_generated_account(
+            account.clone(),
+            ETH_ADDRESS,
+            STRK_ADDRESS,
+            Felt::from(ACCOUNT_FUNDING),
+            deployment_v3.address(),
+        )
+        .await?;
+        let estimate = deployment_v3.estimate_fee().await?;
+        let (gas, gas_price) = v3_resource_bounds(&estimate)?;
+        let mut state_diff = Value::Null;
+        for (skip_validate, skip_fee_charge) in SIMULATION_FLAGS {
+            let simulation = factory
+                .deploy_v3(salt)
+                .gas(gas)
+                .gas_price(gas_price)
+                .simulate(skip_validate, skip_fee_charge)
+                .await?;
+            let simulated_state_diff =
+                check_simulation(&simulation, &estimate, skip_validate, skip_fee_charge)?;
+            if !skip_validate && !skip_fee_charge {
+                state_diff = simulated_state_diff;
+            }
+        }
+        let result = deployment_v3.send().await?;
+        wait_for_sent_transaction(result.transaction_hash, &account).await?;
+        check_state_diff(&account, result.transaction_hash, &state_diff).await?;
+
+        Ok(Self {})
+    }
+}
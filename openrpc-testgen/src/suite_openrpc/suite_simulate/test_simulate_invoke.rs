@@ -0,0 +1,77 @@
+use serde_json::Value;
+use starknet_types_core::felt::Felt;
+
+use super::{check_simulation, check_state_diff, v3_resource_bounds, SIMULATION_FLAGS};
+use crate::{
+    utils::v7::{
+        accounts::{account::Account, call::Call},
+        endpoints::{
+            errors::OpenRpcTestGenError,
+            utils::{get_selector_from_name, wait_for_sent_transaction},
+        },
+    },
+    RandomizableAccountsTrait, RunnableTrait,
+};
+
+#[derive(Clone, Debug)]
+pub struct TestCase {}
+
+impl RunnableTrait for TestCase {
+    type Input = super::TestSuiteSimulate;
+
+    async fn run(test_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let account = test_input.random_paymaster_account.random_accounts()?;
+        let call = set_value_call(test_input.storage_address)?;
+        let execution_v1 = account.execute_v1(vec![call.clone()]);
+        let estimate = execution_v1.estimate_fee().await?;
+        let mut state_diff = Value::Null;
+        for (skip_validate, skip_fee_charge) in SIMULATION_FLAGS {
+            let simulation = account
+                .execute_v1(vec![call.clone()])
+                .max_fee(estimate.overall_fee * Felt::TWO)
+                .simulate(skip_validate, skip_fee_charge)
+                .await?;
+            let simulated_state_diff =
+                check_simulation(&simulation, &estimate, skip_validate, skip_fee_charge)?;
+            if !skip_validate && !skip_fee_charge {
+                state_diff = simulated_state_diff;
+            }
+        }
+        let result = execution_v1.send().await?;
+        wait_for_sent_transaction(result.transaction_hash, &account).await?;
+        check_state_diff(&account, result.transaction_hash, &state_diff).await?;
+
+        let call = set_value_call(test_input.storage_address)?;
+        let execution_v3 = account.execute_v3(vec![call.clone()]);
+        let estimate = execution_v3.estimate_fee().await?;
+        let (gas, gas_price) = v3_resource_bounds(&estimate)?;
+        let mut state_diff = Value::Null;
+        for (skip_validate, skip_fee_charge) in SIMULATION_FLAGS {
+            let simulation = account
+                .execute_v3(vec![call.clone()])
+                .gas(gas)
+                .gas_price(gas_price)
+                .simulate(skip_validate, skip_fee_charge)
+                .await?;
+            let simulated_state_diff =
+                check_simulation(&simulation, &estimate, skip_validate, skip_fee_charge)?;
+            if !skip_validate && !skip_fee_charge {
+                state_diff = simulated_state_diff;
+            }
+        }
+        let result = execution_v3.send().await?;
+        wait_for_sent_transaction(result.transaction_hash, &account).await?;
+        check_state_diff(&account, result.transaction_hash, &state_diff).await?;
+
+        Ok(Self {})
+    }
+}
+
+/// Call writing a fresh value, so that every transaction changes the storage.
+fn set_value_call(storage_address: Felt) -> Result<Call, OpenRpcTestGenError> {
+    Ok(Call {
+        to: storage_address,
+        selector: get_selector_from_name("set_value")?,
+        calldata: vec![Felt::from(rand::random::<u64>())],
+    })
+}
@@ -0,0 +1,100 @@
+use starknet_types_core::felt::Felt;
+use starknet_types_rpc::{BlockId, MaybePendingStateUpdate, StateUpdate};
+
+use super::RandomSingleOwnerAccount;
+use crate::{
+    utils::v7::{
+        accounts::account::ConnectedAccount,
+        contract::{
+            declare_and_deploy::{ensure_counter, increase_call},
+            registry::ContractRegistry,
+        },
+        endpoints::{errors::OpenRpcTestGenError, utils::wait_for_sent_transaction},
+        providers::{
+            jsonrpc::{transports::http::HttpTransportError, JsonRpcClientError, StarknetError},
+            provider::{Provider, ProviderError},
+        },
+    },
+    RandomizableAccountsTrait, SetupableTrait,
+};
+
+pub mod test_pathfinder_proof;
+pub mod test_storage_proof;
+
+/// JSON-RPC error code of methods the node doesn't serve.
+const METHOD_NOT_FOUND: i64 = -32601;
+
+#[derive(Clone, Debug)]
+pub struct TestSuiteStorageProof {
+    pub random_paymaster_account: RandomSingleOwnerAccount,
+    pub counter_class_hash: Felt,
+    pub counter_compiled_class_hash: Felt,
+    /// `Counter` contract with a non-zero counter, whose storage is proven by the tests.
+    pub counter_address: Felt,
+}
+
+impl SetupableTrait for TestSuiteStorageProof {
+    type Input = super::TestSuiteOpenRpc;
+
+    async fn setup(setup_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let account = setup_input.random_paymaster_account.random_accounts()?;
+
+        let counter = ensure_counter(&account, setup_input.udc_address).await?;
+        let (_, counter_compiled_class_hash) = ContractRegistry::global().get("Counter")?;
+
+        let result = account
+            .execute_v3(vec![increase_call(counter.address, Felt::ONE)?])
+            .send()
+            .await?;
+        wait_for_sent_transaction(result.transaction_hash, &account).await?;
+
+        Ok(Self {
+            random_paymaster_account: setup_input.random_paymaster_account.clone(),
+            counter_class_hash: counter.class_hash,
+            counter_compiled_class_hash,
+            counter_address: counter.address,
+        })
+    }
+}
+
+/// Whether the error shows that the node doesn't serve the proof, either lacking the method or
+/// refusing the block. The tests skip their checks on such nodes.
+pub fn proof_unsupported(error: &ProviderError) -> bool {
+    match error {
+        ProviderError::StarknetError(StarknetError::StorageProofNotSupported) => true,
+        ProviderError::Other(error) => matches!(
+            error
+                .as_any()
+                .downcast_ref::<JsonRpcClientError<HttpTransportError>>(),
+            Some(JsonRpcClientError::JsonRpc(error)) if error.code == METHOD_NOT_FOUND
+        ),
+        _ => false,
+    }
+}
+
+/// Latest accepted block the proofs are requested at, by its state update holding the block
+/// hash and the `new_root` the proofs must hash up to.
+pub async fn latest_state_update<P: Provider>(
+    provider: &P,
+) -> Result<(u64, StateUpdate<Felt>), OpenRpcTestGenError> {
+    let block_number = provider.block_number().await?;
+
+    match provider
+        .get_state_update(BlockId::Number(block_number))
+        .await?
+    {
+        MaybePendingStateUpdate::Block(state_update) => Ok((block_number, state_update)),
+        MaybePendingStateUpdate::Pending(_) => {
+            Err(OpenRpcTestGenError::UnexpectedBlockResponseType(format!(
+                "Pending state update returned for block {}",
+                block_number
+            )))
+        }
+    }
+}
+
+#[cfg(not(feature = "rust-analyzer"))]
+include!(concat!(
+    env!("OUT_DIR"),
+    "/generated_tests_suite_openrpc_suite_storage_proof.rs"
+));
@@ -0,0 +1,109 @@
+use starknet_types_core::felt::Felt;
+use starknet_types_rpc::BlockId;
+use tracing::info;
+
+use crate::{
+    assert_eq_result, assert_result,
+    utils::v7::{
+        accounts::account::ConnectedAccount,
+        endpoints::{errors::OpenRpcTestGenError, utils::get_selector_from_name},
+        providers::provider::Provider,
+        storage_proof::verify_pathfinder_proof,
+    },
+    RandomizableAccountsTrait, RunnableTrait,
+};
+
+#[derive(Clone, Debug)]
+pub struct TestCase {}
+
+impl RunnableTrait for TestCase {
+    type Input = super::TestSuiteStorageProof;
+
+    async fn run(test_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let account = test_input.random_paymaster_account.random_accounts()?;
+        let provider = account.provider();
+        let (block_number, state_update) = super::latest_state_update(provider).await?;
+
+        let counter_key = get_selector_from_name("counter")?;
+        let unset_key = Felt::from(rand::random::<u64>());
+        let keys = vec![counter_key, unset_key];
+
+        let proof = match provider
+            .pathfinder_get_proof(
+                BlockId::Number(block_number),
+                test_input.counter_address,
+                keys.clone(),
+            )
+            .await
+        {
+            Ok(proof) => proof,
+            Err(e) if super::proof_unsupported(&e) => {
+                info!(
+                    "Skipping pathfinder proof checks, the node doesn't serve them: {}",
+                    e
+                );
+                return Ok(Self {});
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        let proven = verify_pathfinder_proof(&proof, test_input.counter_address, &keys)?;
+        assert_eq_result!(
+            proven.state_commitment,
+            state_update.new_root,
+            "State commitment of the proof at block {}",
+            block_number
+        );
+
+        let contract_data = proof.contract_data.as_ref().ok_or_else(|| {
+            OpenRpcTestGenError::Other(format!(
+                "No contract data proven for counter {:#x}",
+                test_input.counter_address
+            ))
+        })?;
+        assert_eq_result!(
+            contract_data.class_hash,
+            test_input.counter_class_hash,
+            "Proven class hash of counter {:#x}",
+            test_input.counter_address
+        );
+
+        let counter = provider
+            .get_storage_at(
+                test_input.counter_address,
+                counter_key,
+                BlockId::Number(block_number),
+            )
+            .await?;
+        assert_eq_result!(
+            proven.storage,
+            vec![vec![counter, Felt::ZERO]],
+            "Proven storage of counter {:#x} at keys {:#x} and unset {:#x}",
+            test_input.counter_address,
+            counter_key,
+            unset_key
+        );
+
+        // A random address holds no contract, which the contract proof must show.
+        let undeployed_address = Felt::from(rand::random::<u64>());
+        let proof = provider
+            .pathfinder_get_proof(BlockId::Number(block_number), undeployed_address, vec![])
+            .await?;
+        let proven = verify_pathfinder_proof(&proof, undeployed_address, &[])?;
+        assert_result!(
+            proof.contract_data.is_none(),
+            format!(
+                "Contract data proven for undeployed address {:#x}",
+                undeployed_address
+            )
+        );
+        assert_eq_result!(
+            proven.state_commitment,
+            state_update.new_root,
+            "State commitment of the proof of undeployed address {:#x}",
+            undeployed_address
+        );
+
+        Ok(Self {})
+    }
+}
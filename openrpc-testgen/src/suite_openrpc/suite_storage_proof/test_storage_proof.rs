@@ -0,0 +1,132 @@
+use production_nodes_types::pathfinder_types::types::proof::class_leaf_hash;
+use starknet_types_core::felt::Felt;
+use starknet_types_rpc::BlockId;
+use tracing::info;
+
+use crate::{
+    assert_eq_result,
+    utils::v7::{
+        accounts::account::{Account, ConnectedAccount},
+        endpoints::{errors::OpenRpcTestGenError, utils::get_selector_from_name},
+        providers::provider::{ContractStorageKeys, Provider},
+        storage_proof::verify_storage_proof,
+    },
+    RandomizableAccountsTrait, RunnableTrait,
+};
+
+#[derive(Clone, Debug)]
+pub struct TestCase {}
+
+impl RunnableTrait for TestCase {
+    type Input = super::TestSuiteStorageProof;
+
+    async fn run(test_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let account = test_input.random_paymaster_account.random_accounts()?;
+        let provider = account.provider();
+        let (block_number, state_update) = super::latest_state_update(provider).await?;
+
+        let undeclared_class_hash = Felt::from(rand::random::<u64>());
+        let class_hashes = vec![test_input.counter_class_hash, undeclared_class_hash];
+        let contract_addresses = vec![test_input.counter_address, account.address()];
+        let counter_key = get_selector_from_name("counter")?;
+        let unset_key = Felt::from(rand::random::<u64>());
+        let contracts_storage_keys = vec![ContractStorageKeys {
+            contract_address: test_input.counter_address,
+            storage_keys: vec![counter_key, unset_key],
+        }];
+
+        let proof = match provider
+            .get_storage_proof(
+                BlockId::Number(block_number),
+                class_hashes.clone(),
+                contract_addresses.clone(),
+                contracts_storage_keys.clone(),
+            )
+            .await
+        {
+            Ok(proof) => proof,
+            Err(e) if super::proof_unsupported(&e) => {
+                info!(
+                    "Skipping storage proof checks, the node doesn't serve them: {}",
+                    e
+                );
+                return Ok(Self {});
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        let proven = verify_storage_proof(
+            &proof,
+            &class_hashes,
+            &contract_addresses,
+            &contracts_storage_keys,
+        )?;
+
+        assert_eq_result!(
+            proof.global_roots.block_hash,
+            state_update.block_hash,
+            "Block hash of the proof at block {}",
+            block_number
+        );
+        assert_eq_result!(
+            proven.state_commitment,
+            state_update.new_root,
+            "State commitment of the global roots at block {}",
+            block_number
+        );
+
+        assert_eq_result!(
+            proven.class_leaves,
+            vec![
+                class_leaf_hash(test_input.counter_compiled_class_hash),
+                Felt::ZERO
+            ],
+            "Proven leaves of classes {:#x} and undeclared {:#x}",
+            test_input.counter_class_hash,
+            undeclared_class_hash
+        );
+
+        for (&contract_address, leaf) in contract_addresses
+            .iter()
+            .zip(&proof.contracts_proof.contract_leaves_data)
+        {
+            let class_hash = provider
+                .get_class_hash_at(BlockId::Number(block_number), contract_address)
+                .await?;
+            assert_eq_result!(
+                leaf.class_hash,
+                class_hash,
+                "Proven class hash of contract {:#x}",
+                contract_address
+            );
+
+            let nonce = provider
+                .get_nonce(BlockId::Number(block_number), contract_address)
+                .await?;
+            assert_eq_result!(
+                leaf.nonce,
+                nonce,
+                "Proven nonce of contract {:#x}",
+                contract_address
+            );
+        }
+
+        let counter = provider
+            .get_storage_at(
+                test_input.counter_address,
+                counter_key,
+                BlockId::Number(block_number),
+            )
+            .await?;
+        assert_eq_result!(
+            proven.storage,
+            vec![vec![counter, Felt::ZERO]],
+            "Proven storage of counter {:#x} at keys {:#x} and unset {:#x}",
+            test_input.counter_address,
+            counter_key,
+            unset_key
+        );
+
+        Ok(Self {})
+    }
+}
@@ -0,0 +1,188 @@
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+use serde_json::Value;
+use starknet_types_core::felt::Felt;
+
+use starknet_types_rpc::{BlockId, BlockTag, FunctionCall};
+
+use super::RandomSingleOwnerAccount;
+use crate::{
+    assert_eq_result,
+    utils::{
+        conversions::felt_field::felt_field,
+        v7::{
+            accounts::account::{Account, ConnectedAccount},
+            contract::{
+                declare_and_deploy::{ensure_declared, ensure_deployed},
+                factory::ContractFactory,
+                helpers::UdcVersion,
+            },
+            endpoints::{
+                errors::OpenRpcTestGenError,
+                utils::{get_selector_from_name, wait_for_sent_transaction},
+            },
+            providers::provider::Provider,
+        },
+    },
+    RandomizableAccountsTrait, SetupableTrait,
+};
+
+pub mod test_deploy_collision;
+pub mod test_deploy_syscall;
+pub mod test_udc_v0_deploy;
+pub mod test_udc_v1_deploy;
+
+#[derive(Clone, Debug)]
+pub struct TestSuiteUdc {
+    pub random_paymaster_account: RandomSingleOwnerAccount,
+    /// Cairo 0 UDC of the node.
+    pub udc_address: Felt,
+    pub syscall_deployer_address: Felt,
+    /// Class deployed by the tests, storing the single constructor argument.
+    pub constructor_value_class_hash: Felt,
+}
+
+impl SetupableTrait for TestSuiteUdc {
+    type Input = super::TestSuiteOpenRpc;
+
+    async fn setup(setup_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let account = setup_input.random_paymaster_account.random_accounts()?;
+
+        let syscall_deployer_class_hash = ensure_declared(&account, "SyscallDeployer")
+            .await?
+            .class_hash();
+        let constructor_value_class_hash = ensure_declared(&account, "ConstructorValue")
+            .await?
+            .class_hash();
+
+        let syscall_deployer_address = ensure_deployed(
+            &account,
+            setup_input.udc_address,
+            syscall_deployer_class_hash,
+            Felt::ZERO,
+            vec![],
+        )
+        .await?
+        .contract_address();
+
+        Ok(Self {
+            random_paymaster_account: setup_input.random_paymaster_account.clone(),
+            udc_address: setup_input.udc_address,
+            syscall_deployer_address,
+            constructor_value_class_hash,
+        })
+    }
+}
+
+/// Deploys [TestSuiteUdc::constructor_value_class_hash] through the UDC and checks the computed
+/// address against `getClassHashAt`, the constructor effects and the `ContractDeployed` event.
+pub async fn check_udc_deployment(
+    test_input: &TestSuiteUdc,
+    udc_address: Felt,
+    udc_version: UdcVersion,
+    unique: bool,
+) -> Result<(), OpenRpcTestGenError> {
+    let account = test_input.random_paymaster_account.random_accounts()?;
+    let provider = account.provider();
+    let deployer_address = account.address();
+    let class_hash = test_input.constructor_value_class_hash;
+
+    let factory = ContractFactory::new_with_udc(class_hash, account.clone(), udc_address)
+        .udc_version(udc_version);
+    let salt = random_salt();
+    let value = random_salt();
+
+    let deployment = factory.deploy_v3(vec![value], salt, unique);
+    let contract_address = deployment.deployed_address();
+    let result = deployment.send().await?;
+    wait_for_sent_transaction(result.transaction_hash, &account).await?;
+
+    let deployed_class_hash = provider
+        .get_class_hash_at(BlockId::Tag(BlockTag::Latest), contract_address)
+        .await?;
+    assert_eq_result!(
+        deployed_class_hash,
+        class_hash,
+        "Class hash at computed address {:#x}",
+        contract_address
+    );
+
+    let stored_value = provider
+        .call(
+            FunctionCall {
+                contract_address,
+                entry_point_selector: get_selector_from_name("get_value")?,
+                calldata: vec![],
+            },
+            BlockId::Tag(BlockTag::Latest),
+        )
+        .await?;
+    assert_eq_result!(stored_value, vec![value]);
+
+    let receipt = serde_json::to_value(
+        provider
+            .get_transaction_receipt(result.transaction_hash)
+            .await?,
+    )?;
+    let event_data = contract_deployed_event(&receipt, udc_address)?;
+    assert_eq_result!(
+        event_data,
+        vec![
+            contract_address,
+            deployer_address,
+            udc_version.uniqueness_flag(unique),
+            class_hash,
+            Felt::ONE,
+            value,
+            salt,
+        ],
+        "Unexpected ContractDeployed event of {:?} UDC, unique: {}",
+        udc_version,
+        unique
+    );
+
+    Ok(())
+}
+
+pub fn random_salt() -> Felt {
+    let mut salt_buffer = [0u8; 32];
+    let mut rng = StdRng::from_entropy();
+    rng.fill_bytes(&mut salt_buffer[1..]);
+
+    Felt::from_bytes_be(&salt_buffer)
+}
+
+/// Data of the `ContractDeployed` event emitted by the UDC, from a receipt serialized to JSON:
+/// `[address, deployer, unique, class_hash, calldata_len, ...calldata, salt]`.
+pub fn contract_deployed_event(
+    receipt: &Value,
+    udc_address: Felt,
+) -> Result<Vec<Felt>, OpenRpcTestGenError> {
+    let selector = get_selector_from_name("ContractDeployed")?;
+
+    receipt["events"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .find(|event| {
+            felt_field(event, "from_address") == Some(udc_address)
+                && event["keys"]
+                    .get(0)
+                    .and_then(Value::as_str)
+                    .and_then(|key| Felt::from_hex(key).ok())
+                    == Some(selector)
+        })
+        .map(|event| serde_json::from_value(event["data"].clone()))
+        .transpose()?
+        .ok_or_else(|| {
+            OpenRpcTestGenError::Other(format!(
+                "No ContractDeployed event of UDC {:#x} in receipt {}",
+                udc_address, receipt
+            ))
+        })
+}
+
+#[cfg(not(feature = "rust-analyzer"))]
+include!(concat!(
+    env!("OUT_DIR"),
+    "/generated_tests_suite_openrpc_suite_udc.rs"
+));
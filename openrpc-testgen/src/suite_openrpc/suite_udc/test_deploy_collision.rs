@@ -0,0 +1,87 @@
+use crate::{
+    assert_result,
+    utils::v7::{
+        accounts::{
+            account::{Account, AccountError},
+            call::Call,
+            single_owner::SingleOwnerAccount,
+        },
+        contract::factory::ContractFactory,
+        endpoints::{
+            errors::OpenRpcTestGenError,
+            utils::{get_selector_from_name, wait_for_sent_transaction},
+        },
+        providers::{
+            jsonrpc::{HttpTransport, JsonRpcClient, StarknetError},
+            provider::ProviderError,
+        },
+        signers::local_wallet::LocalWallet,
+    },
+    RandomizableAccountsTrait, RunnableTrait,
+};
+use starknet_types_core::felt::Felt;
+use starknet_types_rpc::v0_7_1::AddInvokeTransactionResult;
+
+#[derive(Clone, Debug)]
+pub struct TestCase {}
+
+impl RunnableTrait for TestCase {
+    type Input = super::TestSuiteUdc;
+
+    async fn run(test_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let account = test_input.random_paymaster_account.random_accounts()?;
+        let class_hash = test_input.constructor_value_class_hash;
+        let salt = super::random_salt();
+        let value = super::random_salt();
+
+        let factory =
+            ContractFactory::new_with_udc(class_hash, account.clone(), test_input.udc_address);
+        let result = factory.deploy_v3(vec![value], salt, false).send().await?;
+        wait_for_sent_transaction(result.transaction_hash, &account).await?;
+
+        // Same deployment again.
+        let redeployment = factory.deploy_v3(vec![value], salt, false).send().await;
+        assert_result!(
+            deployment_failed(redeployment, &account).await?,
+            "Deploying twice to the same address through the UDC succeeded"
+        );
+
+        // Deployments from zero do not depend on the deployer, the syscall targets the same
+        // address as the non unique UDC deployment.
+        let syscall_deployment = account
+            .execute_v3(vec![Call {
+                to: test_input.syscall_deployer_address,
+                selector: get_selector_from_name("deploy")?,
+                calldata: vec![class_hash, salt, Felt::ONE, Felt::ONE, value],
+            }])
+            .send()
+            .await;
+        assert_result!(
+            deployment_failed(syscall_deployment, &account).await?,
+            "Deploying from zero to the address of a UDC deployment succeeded"
+        );
+
+        Ok(Self {})
+    }
+}
+
+/// Whether the deployment failed to execute during fee estimation or reverted.
+async fn deployment_failed<E>(
+    result: Result<AddInvokeTransactionResult<Felt>, AccountError<E>>,
+    account: &SingleOwnerAccount<JsonRpcClient<HttpTransport>, LocalWallet>,
+) -> Result<bool, OpenRpcTestGenError>
+where
+    OpenRpcTestGenError: From<AccountError<E>>,
+{
+    match result {
+        Err(AccountError::Provider(ProviderError::StarknetError(
+            StarknetError::TransactionExecutionError(_),
+        ))) => Ok(true),
+        Err(e) => Err(e.into()),
+        Ok(result) => match wait_for_sent_transaction(result.transaction_hash, account).await {
+            Err(OpenRpcTestGenError::TransactionFailed(_)) => Ok(true),
+            Err(e) => Err(e),
+            Ok(_) => Ok(false),
+        },
+    }
+}
@@ -0,0 +1,75 @@
+use crate::{
+    assert_eq_result,
+    utils::v7::{
+        accounts::{
+            account::{Account, ConnectedAccount},
+            call::Call,
+            deployment::helpers::get_contract_address,
+        },
+        endpoints::{
+            errors::OpenRpcTestGenError,
+            utils::{get_selector_from_name, wait_for_sent_transaction},
+        },
+        providers::provider::Provider,
+    },
+    RandomizableAccountsTrait, RunnableTrait,
+};
+use starknet_types_core::felt::Felt;
+use starknet_types_rpc::{BlockId, BlockTag};
+
+#[derive(Clone, Debug)]
+pub struct TestCase {}
+
+impl RunnableTrait for TestCase {
+    type Input = super::TestSuiteUdc;
+
+    async fn run(test_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let account = test_input.random_paymaster_account.random_accounts()?;
+        let class_hash = test_input.constructor_value_class_hash;
+
+        for deploy_from_zero in [false, true] {
+            let salt = super::random_salt();
+            let value = super::random_salt();
+
+            let result = account
+                .execute_v3(vec![Call {
+                    to: test_input.syscall_deployer_address,
+                    selector: get_selector_from_name("deploy")?,
+                    calldata: vec![
+                        class_hash,
+                        salt,
+                        Felt::from(deploy_from_zero),
+                        Felt::ONE,
+                        value,
+                    ],
+                }])
+                .send()
+                .await?;
+            wait_for_sent_transaction(result.transaction_hash, &account).await?;
+
+            // The syscall leaves the salt as is, the deployer is the calling contract unless
+            // deploying from zero.
+            let deployer_address = if deploy_from_zero {
+                Felt::ZERO
+            } else {
+                test_input.syscall_deployer_address
+            };
+            let contract_address =
+                get_contract_address(salt, class_hash, &[value], deployer_address);
+
+            let deployed_class_hash = account
+                .provider()
+                .get_class_hash_at(BlockId::Tag(BlockTag::Latest), contract_address)
+                .await?;
+            assert_eq_result!(
+                deployed_class_hash,
+                class_hash,
+                "Class hash at computed address {:#x}, deploy_from_zero: {}",
+                contract_address,
+                deploy_from_zero
+            );
+        }
+
+        Ok(Self {})
+    }
+}
@@ -0,0 +1,20 @@
+use crate::{
+    utils::v7::{contract::helpers::UdcVersion, endpoints::errors::OpenRpcTestGenError},
+    RunnableTrait,
+};
+
+#[derive(Clone, Debug)]
+pub struct TestCase {}
+
+impl RunnableTrait for TestCase {
+    type Input = super::TestSuiteUdc;
+
+    async fn run(test_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        for unique in [false, true] {
+            super::check_udc_deployment(test_input, test_input.udc_address, UdcVersion::V0, unique)
+                .await?;
+        }
+
+        Ok(Self {})
+    }
+}
@@ -0,0 +1,48 @@
+use starknet_types_rpc::{BlockId, BlockTag};
+use tracing::info;
+
+use crate::{
+    utils::v7::{
+        accounts::account::ConnectedAccount,
+        contract::{factory::UDC_V1_ADDRESS, helpers::UdcVersion},
+        endpoints::errors::OpenRpcTestGenError,
+        providers::{
+            jsonrpc::StarknetError,
+            provider::{Provider, ProviderError},
+        },
+    },
+    RandomizableAccountsTrait, RunnableTrait,
+};
+
+#[derive(Clone, Debug)]
+pub struct TestCase {}
+
+impl RunnableTrait for TestCase {
+    type Input = super::TestSuiteUdc;
+
+    async fn run(test_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let account = test_input.random_paymaster_account.random_accounts()?;
+
+        match account
+            .provider()
+            .get_class_hash_at(BlockId::Tag(BlockTag::Pending), UDC_V1_ADDRESS)
+            .await
+        {
+            Ok(_) => {}
+            Err(ProviderError::StarknetError(StarknetError::ContractNotFound)) => {
+                info!(
+                    "Skipping Cairo 1 UDC deployments, no UDC predeployed at {:#x}",
+                    UDC_V1_ADDRESS
+                );
+                return Ok(Self {});
+            }
+            Err(e) => return Err(e.into()),
+        }
+
+        for unique in [false, true] {
+            super::check_udc_deployment(test_input, UDC_V1_ADDRESS, UdcVersion::V1, unique).await?;
+        }
+
+        Ok(Self {})
+    }
+}
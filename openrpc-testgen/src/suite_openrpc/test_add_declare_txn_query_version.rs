@@ -0,0 +1,63 @@
+use crate::{
+    assert_result,
+    utils::v7::{
+        accounts::account::{Account, ConnectedAccount},
+        contract::registry::ContractRegistry,
+        endpoints::errors::OpenRpcTestGenError,
+        providers::provider::Provider,
+    },
+    RandomizableAccountsTrait, RunnableTrait,
+};
+use serde_json::{json, Value};
+use starknet_types_core::felt::Felt;
+use starknet_types_rpc::v0_7_1::{BroadcastedDeclareTxn, BroadcastedTxn};
+
+#[derive(Clone, Debug)]
+pub struct TestCase {}
+
+impl RunnableTrait for TestCase {
+    type Input = super::TestSuiteOpenRpc;
+
+    async fn run(test_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let account = test_input.random_paymaster_account.random_accounts()?;
+
+        let (mut flattened_sierra_class, compiled_class_hash) =
+            ContractRegistry::global().get("contracts_sample_contract_5_HelloStarknet")?;
+
+        // A randomly named event in the ABI gives a class hash no node has seen yet, so the
+        // version is the only reason left to reject the declaration.
+        let mut abi: Vec<Value> =
+            serde_json::from_str(flattened_sierra_class.abi.as_deref().unwrap_or("[]"))?;
+        abi.push(json!({
+            "type": "event",
+            "name": format!("QueryVersion{:#x}", Felt::from(rand::random::<u64>())),
+            "kind": "struct",
+            "members": [],
+        }));
+        flattened_sierra_class.abi = Some(serde_json::to_string(&abi)?);
+
+        let prepared = account
+            .declare_v3(flattened_sierra_class, compiled_class_hash)
+            .gas(100_000)
+            .prepare()
+            .await?;
+        let declare_request = prepared.get_declare_request(true, false).await?;
+
+        let result = account
+            .provider()
+            .add_declare_transaction(BroadcastedTxn::Declare(BroadcastedDeclareTxn::QueryV3(
+                declare_request,
+            )))
+            .await;
+
+        assert_result!(
+            result.as_ref().is_err_and(super::query_version_rejected),
+            format!(
+                "Query version declare was not rejected for its version: {:?}",
+                result
+            )
+        );
+
+        Ok(Self {})
+    }
+}
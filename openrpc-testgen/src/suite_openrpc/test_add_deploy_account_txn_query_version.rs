@@ -0,0 +1,74 @@
+use crate::{
+    assert_result,
+    utils::v7::{
+        accounts::{
+            account::{Account, ConnectedAccount},
+            factory::{open_zeppelin::OpenZeppelinAccountFactory, AccountFactory},
+        },
+        endpoints::{
+            errors::OpenRpcTestGenError,
+            utils::{setup_generated_account, ETH_ADDRESS, STRK_ADDRESS},
+        },
+        providers::provider::Provider,
+        signers::{key_pair::SigningKey, local_wallet::LocalWallet},
+    },
+    RandomizableAccountsTrait, RunnableTrait,
+};
+use starknet_types_core::felt::Felt;
+use starknet_types_rpc::{
+    v0_7_1::{BroadcastedDeployAccountTxn, BroadcastedTxn},
+    BlockId, BlockTag,
+};
+
+/// Amount of each fee token sent to the account, enough that only the version is wrong.
+const ACCOUNT_FUNDING: u128 = 100_000_000_000_000_000;
+
+#[derive(Clone, Debug)]
+pub struct TestCase {}
+
+impl RunnableTrait for TestCase {
+    type Input = super::TestSuiteOpenRpc;
+
+    async fn run(test_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let account = test_input.random_paymaster_account.random_accounts()?;
+
+        let mut factory = OpenZeppelinAccountFactory::new(
+            test_input.account_class_hash,
+            account.chain_id(),
+            LocalWallet::from(SigningKey::from_random()),
+            account.provider().clone(),
+        )
+        .await?;
+        factory.set_block_id(BlockId::Tag(BlockTag::Pending));
+
+        let deployment = factory.deploy_v3(Felt::from(rand::random::<u64>()));
+        setup_generated_account(
+            account.clone(),
+            ETH_ADDRESS,
+            STRK_ADDRESS,
+            Felt::from(ACCOUNT_FUNDING),
+            deployment.address(),
+        )
+        .await?;
+
+        let prepared = deployment.gas(100_000).prepare().await?;
+        let deploy_request = prepared.get_deploy_request(true, false).await?;
+
+        let result = account
+            .provider()
+            .add_deploy_account_transaction(BroadcastedTxn::DeployAccount(
+                BroadcastedDeployAccountTxn::QueryV3(deploy_request),
+            ))
+            .await;
+
+        assert_result!(
+            result.as_ref().is_err_and(super::query_version_rejected),
+            format!(
+                "Query version deploy account was not rejected for its version: {:?}",
+                result
+            )
+        );
+
+        Ok(Self {})
+    }
+}
@@ -0,0 +1,179 @@
+use crate::{
+    assert_eq_result,
+    utils::v7::{
+        accounts::account::{Account, ConnectedAccount},
+        contract::{
+            factory::ContractFactory,
+            legacy::{LegacyContractClass, LegacyEntryPoints},
+        },
+        endpoints::{
+            errors::OpenRpcTestGenError,
+            utils::{get_selector_from_name, wait_for_sent_transaction},
+        },
+        providers::{
+            jsonrpc::StarknetError,
+            provider::{Provider, ProviderError},
+        },
+    },
+    RandomizableAccountsTrait, RunnableTrait,
+};
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+use starknet_types_core::felt::Felt;
+use starknet_types_rpc::{BlockId, BlockTag, FunctionCall};
+use std::{fs::File, path::Path, sync::Arc};
+
+#[derive(Clone, Debug)]
+pub struct TestCase {}
+
+impl RunnableTrait for TestCase {
+    type Input = super::TestSuiteOpenRpc;
+
+    async fn run(test_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("src/suite_openrpc/test_data/ERC20_Mintable_OZ_0.2.0.json");
+        let contract_class: LegacyContractClass = serde_json::from_reader(File::open(path)?)?;
+        let expected_class_hash = contract_class.class_hash()?;
+        let expected_selectors: Vec<Felt> = contract_class
+            .entry_points_by_type
+            .external
+            .iter()
+            .map(|entry_point| entry_point.selector)
+            .collect();
+
+        // A class already served under the locally computed hash was declared with it.
+        let class_hash = match test_input
+            .random_paymaster_account
+            .provider()
+            .get_legacy_class(BlockId::Tag(BlockTag::Pending), expected_class_hash)
+            .await
+        {
+            Ok(_) => expected_class_hash,
+            Err(ProviderError::StarknetError(StarknetError::ClassHashNotFound)) => {
+                let result = test_input
+                    .random_paymaster_account
+                    .declare_legacy(Arc::new(contract_class))
+                    .send()
+                    .await?;
+                wait_for_sent_transaction(
+                    result.transaction_hash,
+                    &test_input.random_paymaster_account.random_accounts()?,
+                )
+                .await?;
+
+                result.class_hash
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        assert_eq_result!(
+            class_hash,
+            expected_class_hash,
+            "Node computed class hash {:#x}, expected {:#x}",
+            class_hash,
+            expected_class_hash
+        );
+
+        let served_class = test_input
+            .random_paymaster_account
+            .provider()
+            .get_legacy_class(BlockId::Tag(BlockTag::Pending), class_hash)
+            .await?;
+        let served_selectors = external_selectors(&served_class.entry_points_by_type)?;
+
+        assert_eq_result!(
+            served_selectors,
+            expected_selectors,
+            "Class served by getClass has different external entry points"
+        );
+
+        let account = test_input.random_paymaster_account.random_accounts()?;
+        let recipient = account.address();
+        let factory = ContractFactory::new_with_udc(class_hash, account, test_input.udc_address);
+
+        let mut salt_buffer = [0u8; 32];
+        let mut rng = StdRng::from_entropy();
+        rng.fill_bytes(&mut salt_buffer[1..]);
+
+        let initial_supply = Felt::from(1_000_000_u64);
+        let deployment = factory.deploy_v1(
+            vec![
+                Felt::from_bytes_be_slice(b"Legacy Token"),
+                Felt::from_bytes_be_slice(b"LGC"),
+                Felt::from(18_u8),
+                initial_supply,
+                Felt::ZERO,
+                recipient,
+                recipient,
+            ],
+            Felt::from_bytes_be(&salt_buffer),
+            false,
+        );
+        let contract_address = deployment.deployed_address();
+        let deployment_result = deployment.send().await?;
+
+        wait_for_sent_transaction(
+            deployment_result.transaction_hash,
+            &test_input.random_paymaster_account.random_accounts()?,
+        )
+        .await?;
+
+        let provider = test_input.random_paymaster_account.provider();
+
+        let deployed_class_hash = provider
+            .get_class_hash_at(BlockId::Tag(BlockTag::Pending), contract_address)
+            .await?;
+
+        assert_eq_result!(
+            deployed_class_hash,
+            class_hash,
+            "Deployed contract class hash {:#x}, expected {:#x}",
+            deployed_class_hash,
+            class_hash
+        );
+
+        let served_class = provider
+            .get_legacy_class_at(BlockId::Tag(BlockTag::Pending), contract_address)
+            .await?;
+        let served_selectors = external_selectors(&served_class.entry_points_by_type)?;
+
+        assert_eq_result!(
+            served_selectors,
+            expected_selectors,
+            "Class served by getClassAt has different external entry points"
+        );
+
+        let balance = provider
+            .call(
+                FunctionCall {
+                    contract_address,
+                    entry_point_selector: get_selector_from_name("balanceOf")?,
+                    calldata: vec![recipient],
+                },
+                BlockId::Tag(BlockTag::Pending),
+            )
+            .await?;
+
+        assert_eq_result!(
+            balance,
+            vec![initial_supply, Felt::ZERO],
+            "Unexpected legacy ERC20 balance {:?}",
+            balance
+        );
+
+        Ok(Self {})
+    }
+}
+
+/// Selectors of the external entry points of a class served by the node, in the order served.
+fn external_selectors<T: serde::Serialize>(
+    entry_points_by_type: &T,
+) -> Result<Vec<Felt>, OpenRpcTestGenError> {
+    let entry_points: LegacyEntryPoints =
+        serde_json::from_value(serde_json::to_value(entry_points_by_type)?)?;
+
+    Ok(entry_points
+        .external
+        .into_iter()
+        .map(|entry_point| entry_point.selector)
+        .collect())
+}
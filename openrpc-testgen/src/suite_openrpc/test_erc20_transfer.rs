@@ -1,37 +1,35 @@
 use crate::{
-    assert_result,
+    assert_eq_result, assert_result, cairo_calldata,
     utils::{
         conversions::felts_to_biguint::felts_slice_to_biguint,
         v7::{
             accounts::{
-                account::{Account, AccountError, ConnectedAccount},
+                account::{Account, ConnectedAccount},
                 call::Call,
             },
-            contract::factory::ContractFactory,
+            contract::{
+                bindings::test_token::{ERC20ComponentEvent, TestToken, TestTokenEvent, Transfer},
+                declare_and_deploy::ensure_declared,
+                factory::ContractFactory,
+            },
             endpoints::{
-                declare_contract::{
-                    extract_class_hash_from_error, get_compiled_contract,
-                    parse_class_hash_from_error, RunnerError,
-                },
                 endpoints_functions::OutsideExecution,
                 errors::{CallError, OpenRpcTestGenError},
                 utils::{get_selector_from_name, wait_for_sent_transaction},
             },
-            providers::provider::{Provider, ProviderError},
+            providers::provider::Provider,
         },
     },
     RandomizableAccountsTrait, RunnableTrait,
 };
-use cainome_cairo_serde::CairoSerde;
+use cainome_cairo_serde::{CairoSerde, ContractAddress, U256};
 use rand::{rngs::StdRng, RngCore, SeedableRng};
 use starknet::core::crypto::ecdsa_sign;
 use starknet_types_core::{
     felt::Felt,
     hash::{Poseidon, StarkHash},
 };
-use starknet_types_rpc::{BlockId, BlockTag, FunctionCall, TxnReceipt};
-use std::path::PathBuf;
-use std::str::FromStr;
+use starknet_types_rpc::{BlockId, BlockTag, TxnReceipt};
 
 #[derive(Clone, Debug)]
 pub struct TestCase {}
@@ -40,64 +38,12 @@ impl RunnableTrait for TestCase {
     type Input = super::TestSuiteOpenRpc;
 
     async fn run(test_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
-        let (erc_20_flattened_sierra_class, erc_20_compiled_class_hash) = get_compiled_contract(
-            PathBuf::from_str("target/dev/contracts_TestToken.contract_class.json")?,
-            PathBuf::from_str("target/dev/contracts_TestToken.compiled_contract_class.json")?,
+        let declaration_hash = ensure_declared(
+            &test_input.random_paymaster_account.random_accounts()?,
+            "TestToken",
         )
-        .await?;
-
-        let declaration_hash = match test_input
-            .random_paymaster_account
-            .declare_v3(erc_20_flattened_sierra_class, erc_20_compiled_class_hash)
-            .send()
-            .await
-        {
-            Ok(result) => {
-                wait_for_sent_transaction(
-                    result.transaction_hash,
-                    &test_input.random_paymaster_account.random_accounts()?,
-                )
-                .await?;
-
-                Ok(result.class_hash)
-            }
-            Err(AccountError::Signing(sign_error)) => {
-                if sign_error.to_string().contains("is already declared") {
-                    Ok(parse_class_hash_from_error(&sign_error.to_string())?)
-                } else {
-                    Err(OpenRpcTestGenError::RunnerError(
-                        RunnerError::AccountFailure(format!(
-                            "Transaction execution error: {}",
-                            sign_error
-                        )),
-                    ))
-                }
-            }
-
-            Err(AccountError::Provider(ProviderError::Other(starkneterror))) => {
-                if starkneterror.to_string().contains("is already declared") {
-                    Ok(parse_class_hash_from_error(&starkneterror.to_string())?)
-                } else {
-                    Err(OpenRpcTestGenError::RunnerError(
-                        RunnerError::AccountFailure(format!(
-                            "Transaction execution error: {}",
-                            starkneterror
-                        )),
-                    ))
-                }
-            }
-            Err(e) => {
-                let full_error_message = format!("{:?}", e);
-
-                if full_error_message.contains("is already declared") {
-                    Ok(extract_class_hash_from_error(&full_error_message)?)
-                } else {
-                    let full_error_message = format!("{:?}", e);
-
-                    panic!("err {:?}", full_error_message);
-                }
-            }
-        }?;
+        .await?
+        .class_hash();
 
         let factory = ContractFactory::new(
             declaration_hash,
@@ -148,17 +94,19 @@ impl RunnableTrait for TestCase {
             }
         };
 
+        let executable_account_address = test_input
+            .random_executable_account
+            .random_accounts()?
+            .address();
+        let amount_to_mint = U256 {
+            low: 0x1234,
+            high: 0,
+        };
+
         let erc20_mint_call = Call {
             to: contract_address_erc20,
             selector: get_selector_from_name("mint")?,
-            calldata: vec![
-                test_input
-                    .random_executable_account
-                    .random_accounts()?
-                    .address(),
-                Felt::from_hex("0x1234")?,
-                Felt::ZERO,
-            ],
+            calldata: cairo_calldata![ContractAddress(executable_account_address), amount_to_mint],
         };
 
         let res = test_input
@@ -173,17 +121,51 @@ impl RunnableTrait for TestCase {
         )
         .await?;
 
+        let mint_receipt = test_input
+            .random_paymaster_account
+            .provider()
+            .get_transaction_receipt(res.transaction_hash)
+            .await?;
+        let mut reader = test_input.random_paymaster_account.random_accounts()?;
+        reader.set_block_id(BlockId::Tag(BlockTag::Pending));
+        let token = TestToken::new(contract_address_erc20, reader.clone());
+        let mint_event = token
+            .events(&mint_receipt)?
+            .into_iter()
+            .find_map(|event| match event {
+                TestTokenEvent::ERC20Event(ERC20ComponentEvent::Transfer(transfer)) => {
+                    Some(transfer)
+                }
+                _ => None,
+            })
+            .ok_or_else(|| {
+                OpenRpcTestGenError::CallError(CallError::EventNotFound(
+                    "mint Transfer".to_string(),
+                ))
+            })?;
+        assert_eq_result!(
+            mint_event,
+            Transfer {
+                from: ContractAddress(Felt::ZERO),
+                to: ContractAddress(executable_account_address),
+                value: amount_to_mint,
+            },
+            "Mint Transfer event mismatch"
+        );
+
         let account_erc20_receiver_address =
             Felt::from_hex("0x78662e7352d062084b0010068b99288486c2d8b914f6e2a55ce945f8792c8b1")?;
-        let amount_to_transfer = vec![Felt::from_hex("0x100")?, Felt::ZERO];
+        let amount_to_transfer = U256 {
+            low: 0x100,
+            high: 0,
+        };
 
         let erc20_transfer_call = Call {
             to: contract_address_erc20,
             selector: get_selector_from_name("transfer")?,
-            calldata: vec![
-                account_erc20_receiver_address,
-                amount_to_transfer[0],
-                amount_to_transfer[1],
+            calldata: cairo_calldata![
+                ContractAddress(account_erc20_receiver_address),
+                amount_to_transfer
             ],
         };
 
@@ -218,39 +200,21 @@ impl RunnableTrait for TestCase {
             calldata: calldata_to_executable_account_call,
         };
 
-        let exec_balance_before_transfer = test_input
-            .random_executable_account
-            .provider()
-            .call(
-                FunctionCall {
-                    calldata: vec![test_input
-                        .random_executable_account
-                        .random_accounts()?
-                        .address()],
-                    contract_address: contract_address_erc20,
-                    entry_point_selector: get_selector_from_name("balance_of")?,
-                },
-                BlockId::Tag(BlockTag::Pending),
-            )
-            .await?;
+        let executable_address = ContractAddress(executable_account_address);
+        let paymaster_address = ContractAddress(
+            test_input
+                .random_paymaster_account
+                .random_accounts()?
+                .address(),
+        );
+        // The fee token exposes the same `balance_of` as the test token.
+        let fee_token = TestToken::new(
+            Felt::from_hex("0x04718f5a0fc34cc1af16a1cdee98ffb20c31f5cd61d6ab07201858f4287c938d")?,
+            reader,
+        );
 
-        let paymaster_balance_before = test_input
-            .random_paymaster_account
-            .provider()
-            .call(
-                FunctionCall {
-                    calldata: vec![test_input
-                        .random_paymaster_account
-                        .random_accounts()?
-                        .address()],
-                    contract_address: Felt::from_hex(
-                        "0x04718f5a0fc34cc1af16a1cdee98ffb20c31f5cd61d6ab07201858f4287c938d",
-                    )?,
-                    entry_point_selector: get_selector_from_name("balance_of")?,
-                },
-                BlockId::Tag(BlockTag::Pending),
-            )
-            .await?;
+        let exec_balance_before_transfer = token.balance_of(&executable_address).await?;
+        let paymaster_balance_before = fee_token.balance_of(&paymaster_address).await?;
 
         let hash = test_input
             .random_paymaster_account
@@ -264,76 +228,35 @@ impl RunnableTrait for TestCase {
         )
         .await?;
 
-        let exec_balance_after_transfer = test_input
-            .random_executable_account
-            .provider()
-            .call(
-                FunctionCall {
-                    calldata: vec![test_input
-                        .random_executable_account
-                        .random_accounts()?
-                        .address()],
-                    contract_address: contract_address_erc20,
-                    entry_point_selector: get_selector_from_name("balance_of")?,
-                },
-                BlockId::Tag(BlockTag::Pending),
-            )
+        let exec_balance_after_transfer = token.balance_of(&executable_address).await?;
+        let paymaster_balance_after = fee_token.balance_of(&paymaster_address).await?;
+        let receiver_balance_after_txn = token
+            .balance_of(&ContractAddress(account_erc20_receiver_address))
             .await?;
 
-        let paymaster_balance_after = test_input
-            .random_paymaster_account
-            .provider()
-            .call(
-                FunctionCall {
-                    calldata: vec![test_input
-                        .random_paymaster_account
-                        .random_accounts()?
-                        .address()],
-                    contract_address: Felt::from_hex(
-                        "0x04718f5a0fc34cc1af16a1cdee98ffb20c31f5cd61d6ab07201858f4287c938d",
-                    )?,
-                    entry_point_selector: get_selector_from_name("balance_of")?,
-                },
-                BlockId::Tag(BlockTag::Pending),
-            )
-            .await?;
-
-        let receiver_balance_after_txn = test_input
-            .random_paymaster_account
-            .provider()
-            .call(
-                FunctionCall {
-                    calldata: vec![account_erc20_receiver_address],
-                    contract_address: contract_address_erc20,
-                    entry_point_selector: get_selector_from_name("balance_of")?,
-                },
-                BlockId::Tag(BlockTag::Pending),
-            )
-            .await?;
-
-        // Prepare assert data
-
-        let receiver_balance_after_txn = felts_slice_to_biguint(receiver_balance_after_txn)?;
-        let amount_to_transfer = felts_slice_to_biguint(amount_to_transfer)?;
-
-        assert_result!(
-            receiver_balance_after_txn == amount_to_transfer,
+        assert_eq_result!(
+            receiver_balance_after_txn,
+            amount_to_transfer,
             "Balances do not match"
         );
 
-        let exec_balance_after_transfer = felts_slice_to_biguint(exec_balance_after_transfer)?;
-        let exec_balance_before_transfer = felts_slice_to_biguint(exec_balance_before_transfer)?;
-
-        assert_result!(
-            exec_balance_before_transfer == exec_balance_after_transfer + amount_to_transfer,
+        let [exec_before, exec_after, transferred, paymaster_before, paymaster_after] = [
+            exec_balance_before_transfer,
+            exec_balance_after_transfer,
+            amount_to_transfer,
+            paymaster_balance_before,
+            paymaster_balance_after,
+        ]
+        .map(|balance| felts_slice_to_biguint([Felt::from(balance.high), Felt::from(balance.low)]));
+
+        assert_eq_result!(
+            exec_before?,
+            exec_after? + transferred?,
             "Token balance on executable account did not decrease by the transfer amount."
         );
 
-        let paymaster_balance_after = felts_slice_to_biguint(paymaster_balance_after)?;
-        let paymaster_balance_before = felts_slice_to_biguint(paymaster_balance_before)?;
-
         assert_result!(
-            paymaster_balance_after < paymaster_balance_before,
+            paymaster_after? < paymaster_before?,
             "Gas balance on paymaster account did not decrease after transaction."
         );
 
@@ -0,0 +1,67 @@
+use crate::{
+    assert_eq_result,
+    utils::v7::{
+        accounts::{account::Account, call::Call, outside_execution::OutsideExecutionV2},
+        contract::factory::ContractFactory,
+        endpoints::{
+            errors::OpenRpcTestGenError,
+            utils::{get_selector_from_name, wait_for_sent_transaction},
+        },
+        signers::{key_pair::SigningKey, local_wallet::LocalWallet},
+    },
+    RandomizableAccountsTrait, RunnableTrait,
+};
+use starknet_types_core::felt::Felt;
+use starknet_types_rpc::TxnExecutionStatus;
+
+#[derive(Clone, Debug)]
+pub struct TestCase {}
+
+impl RunnableTrait for TestCase {
+    type Input = super::TestSuiteOpenRpc;
+
+    async fn run(test_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let paymaster = test_input.random_paymaster_account.random_accounts()?;
+
+        // Account executing the outside execution, it does not need funds as the paymaster pays
+        // for the transaction.
+        let signing_key = SigningKey::from_random();
+        let factory = ContractFactory::new_with_udc(
+            test_input.account_class_hash,
+            paymaster.clone(),
+            test_input.udc_address,
+        );
+        let deployment = factory.deploy_v3(
+            vec![signing_key.verifying_key().scalar()],
+            Felt::from(rand::random::<u64>()),
+            false,
+        );
+        let account_address = deployment.deployed_address();
+        let deployment_result = deployment.send().await?;
+        wait_for_sent_transaction(deployment_result.transaction_hash, &paymaster).await?;
+
+        let outside_execution = OutsideExecutionV2::new(vec![Call {
+            to: Felt::from_hex(
+                "0x04718f5a0fc34cc1af16a1cdee98ffb20c31f5cd61d6ab07201858f4287c938d",
+            )?,
+            selector: get_selector_from_name("transfer")?,
+            calldata: vec![paymaster.address(), Felt::ZERO, Felt::ZERO],
+        }]);
+        let signature = outside_execution
+            .sign(
+                paymaster.chain_id(),
+                account_address,
+                &LocalWallet::from(signing_key),
+            )
+            .await?;
+
+        let result = paymaster
+            .execute_v3(vec![outside_execution.call(account_address, signature)])
+            .send()
+            .await?;
+        let status = wait_for_sent_transaction(result.transaction_hash, &paymaster).await?;
+        assert_eq_result!(status.execution_status, Some(TxnExecutionStatus::Succeeded));
+
+        Ok(Self {})
+    }
+}
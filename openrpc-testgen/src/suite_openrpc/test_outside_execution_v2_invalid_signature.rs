@@ -0,0 +1,92 @@
+use crate::{
+    assert_result,
+    utils::v7::{
+        accounts::{account::Account, call::Call, outside_execution::OutsideExecutionV2},
+        contract::factory::ContractFactory,
+        endpoints::{
+            errors::OpenRpcTestGenError,
+            utils::{get_selector_from_name, wait_for_sent_transaction},
+        },
+        signers::{key_pair::SigningKey, local_wallet::LocalWallet},
+    },
+    RandomizableAccountsTrait, RunnableTrait,
+};
+use starknet_types_core::felt::Felt;
+
+/// Error of the SRC9 component of OpenZeppelin accounts when the outside execution signature is
+/// not valid for the account.
+const INVALID_SIGNATURE: &str = "SRC9: invalid signature";
+
+#[derive(Clone, Debug)]
+pub struct TestCase {}
+
+impl RunnableTrait for TestCase {
+    type Input = super::TestSuiteOpenRpc;
+
+    async fn run(test_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let paymaster = test_input.random_paymaster_account.random_accounts()?;
+
+        // Account executing the outside execution, it does not need funds as the paymaster pays
+        // for the transaction.
+        let signing_key = SigningKey::from_random();
+        let factory = ContractFactory::new_with_udc(
+            test_input.account_class_hash,
+            paymaster.clone(),
+            test_input.udc_address,
+        );
+        let deployment = factory.deploy_v3(
+            vec![signing_key.verifying_key().scalar()],
+            Felt::from(rand::random::<u64>()),
+            false,
+        );
+        let account_address = deployment.deployed_address();
+        let deployment_result = deployment.send().await?;
+        wait_for_sent_transaction(deployment_result.transaction_hash, &paymaster).await?;
+
+        let outside_execution = OutsideExecutionV2::new(vec![Call {
+            to: Felt::from_hex(
+                "0x04718f5a0fc34cc1af16a1cdee98ffb20c31f5cd61d6ab07201858f4287c938d",
+            )?,
+            selector: get_selector_from_name("transfer")?,
+            calldata: vec![paymaster.address(), Felt::ZERO, Felt::ZERO],
+        }]);
+        // Signed with a key the executing account does not know about
+        let signature = outside_execution
+            .sign(
+                paymaster.chain_id(),
+                account_address,
+                &LocalWallet::from(SigningKey::from_random()),
+            )
+            .await?;
+
+        let result = paymaster
+            .execute_v3(vec![outside_execution.call(account_address, signature)])
+            .send()
+            .await;
+
+        // Sending estimates the fee first, so the failed signature check surfaces as the
+        // execution error of the estimation.
+        let error = match result {
+            Ok(result) => {
+                return Err(OpenRpcTestGenError::Other(format!(
+                    "Outside execution with an invalid signature was accepted as {:#x}",
+                    result.transaction_hash
+                )))
+            }
+            Err(error) => format!("{:?}", error),
+        };
+        let message_felt = format!(
+            "{:#x}",
+            Felt::from_bytes_be_slice(INVALID_SIGNATURE.as_bytes())
+        );
+        assert_result!(
+            error.contains(INVALID_SIGNATURE) || error.contains(&message_felt),
+            format!(
+                "Outside execution error {} doesn't mention {:?} ({})",
+                error, INVALID_SIGNATURE, message_felt
+            )
+        );
+
+        Ok(Self {})
+    }
+}
@@ -0,0 +1,76 @@
+use crate::{
+    assert_eq_result,
+    utils::v7::{
+        accounts::{
+            account::{Account, ConnectedAccount},
+            call::Call,
+            single_owner::{ExecutionEncoding, SingleOwnerAccount},
+        },
+        endpoints::{
+            errors::OpenRpcTestGenError,
+            utils::{get_selector_from_name, wait_for_sent_transaction},
+        },
+        signers::{
+            key_pair::SigningKey,
+            remote::{server::serve, RemoteSigner},
+            signer::Signer,
+        },
+    },
+    RandomizableAccountsTrait, RunnableTrait,
+};
+use starknet_types_core::felt::Felt;
+use starknet_types_rpc::{BlockId, BlockTag, TxnExecutionStatus};
+use tokio::net::TcpListener;
+use url::Url;
+
+#[derive(Clone, Debug)]
+pub struct TestCase {}
+
+impl RunnableTrait for TestCase {
+    type Input = super::TestSuiteOpenRpc;
+
+    async fn run(test_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let paymaster = test_input.random_paymaster_account.random_accounts()?;
+
+        let signing_key = SigningKey::from_secret_scalar(test_input.paymaster_private_key);
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let url = Url::parse(&format!("http://{}", listener.local_addr()?))?;
+        let server = tokio::spawn(serve(listener, signing_key));
+
+        let remote_signer = RemoteSigner::new(url);
+        let public_key = remote_signer.get_public_key().await;
+
+        // Same account as the paymaster, with the key kept behind the remote signer.
+        let mut remote_account = SingleOwnerAccount::new(
+            paymaster.provider().clone(),
+            remote_signer,
+            paymaster.address(),
+            paymaster.chain_id(),
+            ExecutionEncoding::New,
+        );
+        remote_account.set_block_id(BlockId::Tag(BlockTag::Pending));
+
+        let result = remote_account
+            .execute_v3(vec![Call {
+                to: Felt::from_hex(
+                    "0x04718f5a0fc34cc1af16a1cdee98ffb20c31f5cd61d6ab07201858f4287c938d",
+                )?,
+                selector: get_selector_from_name("transfer")?,
+                calldata: vec![paymaster.address(), Felt::ZERO, Felt::ZERO],
+            }])
+            .send()
+            .await;
+        server.abort();
+
+        assert_eq_result!(
+            public_key?.scalar(),
+            signing_key.verifying_key().scalar(),
+            "Remote signer public key mismatch"
+        );
+
+        let status = wait_for_sent_transaction(result?.transaction_hash, &paymaster).await?;
+        assert_eq_result!(status.execution_status, Some(TxnExecutionStatus::Succeeded));
+
+        Ok(Self {})
+    }
+}
@@ -0,0 +1,65 @@
+use crate::{
+    assert_result,
+    utils::v7::{
+        accounts::{
+            account::{Account, AccountError, ConnectedAccount},
+            call::Call,
+        },
+        endpoints::{errors::OpenRpcTestGenError, utils::get_selector_from_name},
+        providers::provider::Provider,
+    },
+    RandomizableAccountsTrait, RunnableTrait,
+};
+use starknet_types_core::felt::Felt;
+use starknet_types_rpc::{
+    v0_7_1::{BroadcastedInvokeTxn, BroadcastedTxn},
+    BlockId, BlockTag,
+};
+
+#[derive(Clone, Debug)]
+pub struct TestCase {}
+
+impl RunnableTrait for TestCase {
+    type Input = super::TestSuiteOpenRpc;
+
+    async fn run(test_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let account = test_input.random_paymaster_account.random_accounts()?;
+
+        let transfer = Call {
+            to: Felt::from_hex(
+                "0x04718f5a0fc34cc1af16a1cdee98ffb20c31f5cd61d6ab07201858f4287c938d",
+            )?,
+            selector: get_selector_from_name("transfer")?,
+            calldata: vec![account.address(), Felt::ZERO, Felt::ZERO],
+        };
+
+        let prepared = account
+            .execute_v3(vec![transfer])
+            .gas(100_000)
+            .prepare()
+            .await?;
+        let invoke_request = prepared
+            .get_invoke_request(true, false)
+            .await
+            .map_err(AccountError::Signing)?;
+
+        // Validation is not skipped, so the account has to accept the query version signature.
+        let simulation = account
+            .provider()
+            .simulate_transactions(
+                BlockId::Tag(BlockTag::Pending),
+                vec![BroadcastedTxn::Invoke(BroadcastedInvokeTxn::QueryV3(
+                    invoke_request,
+                ))],
+                vec![],
+            )
+            .await;
+
+        assert_result!(
+            simulation.is_ok(),
+            format!("Simulating a query version failed: {:?}", simulation)
+        );
+
+        Ok(Self {})
+    }
+}
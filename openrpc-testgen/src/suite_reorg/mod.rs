@@ -0,0 +1,241 @@
+use starknet_types_core::felt::Felt;
+use starknet_types_rpc::{BlockId, BlockTag, EventFilterWithPageRequest, FunctionCall, TxnStatus};
+use url::Url;
+
+use crate::{
+    assert_result,
+    utils::v7::{
+        accounts::{
+            account::{Account, ConnectedAccount},
+            creation::helpers::get_chain_id,
+            single_owner::{ExecutionEncoding, SingleOwnerAccount},
+        },
+        contract::declare_and_deploy::{ensure_counter, increase_call},
+        devnet::DevnetClient,
+        endpoints::{
+            errors::OpenRpcTestGenError,
+            utils::{get_selector_from_name, wait_for_sent_transaction},
+        },
+        providers::{
+            jsonrpc::{HttpTransport, JsonRpcClient, StarknetError},
+            provider::{Provider, ProviderError},
+        },
+        signers::{key_pair::SigningKey, local_wallet::LocalWallet},
+    },
+    SetupableTrait,
+};
+
+pub mod test_abort_blocks;
+pub mod test_rebuild_after_abort;
+
+/// Chain reorganization suite, run against the first node only, which must be a Devnet keeping
+/// the full state history (`--state-archive-capacity full`) for `devnet_abortBlocks`.
+#[derive(Clone, Debug)]
+pub struct TestSuiteReorg {
+    pub paymaster_account: SingleOwnerAccount<JsonRpcClient<HttpTransport>, LocalWallet>,
+    pub devnet_client: DevnetClient,
+    /// `Counter` contract increased by the transactions of the built blocks.
+    pub counter_address: Felt,
+}
+
+#[derive(Clone, Debug)]
+pub struct SetupInput {
+    pub urls: Vec<Url>,
+    pub paymaster_account_address: Felt,
+    pub paymaster_private_key: Felt,
+    pub account_class_hash: Felt,
+    pub udc_address: Felt,
+}
+
+impl SetupableTrait for TestSuiteReorg {
+    type Input = SetupInput;
+
+    async fn setup(setup_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let url = setup_input
+            .urls
+            .first()
+            .ok_or_else(|| OpenRpcTestGenError::EmptyUrlList("No L2 node urls.".to_string()))?
+            .clone();
+
+        let provider = JsonRpcClient::new(HttpTransport::new(url.clone()));
+        let chain_id = get_chain_id(&provider).await?;
+
+        let mut paymaster_account = SingleOwnerAccount::new(
+            provider,
+            LocalWallet::from(SigningKey::from_secret_scalar(
+                setup_input.paymaster_private_key,
+            )),
+            setup_input.paymaster_account_address,
+            chain_id,
+            ExecutionEncoding::New,
+        );
+        paymaster_account.set_block_id(BlockId::Tag(BlockTag::Pending));
+
+        let counter = ensure_counter(&paymaster_account, setup_input.udc_address).await?;
+
+        Ok(Self {
+            paymaster_account,
+            devnet_client: DevnetClient::devnet(url),
+            counter_address: counter.address,
+        })
+    }
+}
+
+/// Block built by [build_block], holding a single increase of the counter.
+#[derive(Clone, Copy, Debug)]
+pub struct BuiltBlock {
+    pub block_hash: Felt,
+    pub block_number: u64,
+    pub transaction_hash: Felt,
+    pub amount: Felt,
+}
+
+/// Increases the counter and closes the pending block, so that the node builds a block holding
+/// the transaction whether it mines on every transaction or on demand.
+///
+/// The amount is random, as a transaction rebuilt after an abort reuses the nonce of the aborted
+/// one and must not get its hash.
+pub async fn build_block(test_input: &TestSuiteReorg) -> Result<BuiltBlock, OpenRpcTestGenError> {
+    let account = &test_input.paymaster_account;
+    let amount = Felt::from(rand::random::<u32>());
+    let result = account
+        .execute_v3(vec![increase_call(test_input.counter_address, amount)?])
+        .send()
+        .await?;
+
+    test_input.devnet_client.create_block().await?;
+    wait_for_sent_transaction(result.transaction_hash, account).await?;
+
+    let receipt = account
+        .provider()
+        .get_transaction_receipt_with_block_info(result.transaction_hash)
+        .await?;
+    let (Some(block_hash), Some(block_number)) = (receipt.block_hash, receipt.block_number) else {
+        return Err(OpenRpcTestGenError::Other(format!(
+            "Receipt of transaction {:#x} has no block after block creation",
+            result.transaction_hash
+        )));
+    };
+
+    Ok(BuiltBlock {
+        block_hash,
+        block_number,
+        transaction_hash: result.transaction_hash,
+        amount,
+    })
+}
+
+/// Value of the counter at the given block.
+pub async fn counter(
+    test_input: &TestSuiteReorg,
+    block_id: BlockId<Felt>,
+) -> Result<Felt, OpenRpcTestGenError> {
+    let result = test_input
+        .paymaster_account
+        .provider()
+        .call(
+            FunctionCall {
+                contract_address: test_input.counter_address,
+                entry_point_selector: get_selector_from_name("get_counter")?,
+                calldata: vec![],
+            },
+            block_id,
+        )
+        .await?;
+
+    result.first().copied().ok_or_else(|| {
+        OpenRpcTestGenError::Other(format!(
+            "Empty get_counter result of {:#x}",
+            test_input.counter_address
+        ))
+    })
+}
+
+/// Hashes of the transactions that emitted the events of the counter, in all accepted blocks.
+pub async fn counter_event_transactions(
+    test_input: &TestSuiteReorg,
+) -> Result<Vec<Felt>, OpenRpcTestGenError> {
+    let provider = test_input.paymaster_account.provider();
+    let mut transaction_hashes = vec![];
+    let mut continuation_token = None;
+
+    loop {
+        let chunk = serde_json::to_value(
+            provider
+                .get_events(EventFilterWithPageRequest {
+                    address: Some(test_input.counter_address),
+                    from_block: Some(BlockId::Number(0)),
+                    to_block: Some(BlockId::Tag(BlockTag::Latest)),
+                    keys: None,
+                    chunk_size: 100,
+                    continuation_token,
+                })
+                .await?,
+        )?;
+
+        for event in chunk["events"].as_array().into_iter().flatten() {
+            transaction_hashes.push(serde_json::from_value(event["transaction_hash"].clone())?);
+        }
+
+        continuation_token = chunk["continuation_token"].as_str().map(str::to_string);
+        if continuation_token.is_none() {
+            return Ok(transaction_hashes);
+        }
+    }
+}
+
+/// Checks that the block is no longer served by its hash.
+pub async fn check_block_aborted(
+    test_input: &TestSuiteReorg,
+    block_hash: Felt,
+) -> Result<(), OpenRpcTestGenError> {
+    let result = test_input
+        .paymaster_account
+        .provider()
+        .get_block_with_tx_hashes(BlockId::Hash(block_hash))
+        .await;
+
+    let not_found = matches!(
+        result,
+        Err(ProviderError::StarknetError(StarknetError::BlockNotFound))
+    );
+    assert_result!(
+        not_found,
+        format!(
+            "Aborted block {:#x} returned {:?} instead of BLOCK_NOT_FOUND",
+            block_hash, result
+        )
+    );
+
+    Ok(())
+}
+
+/// Checks that the transaction of an aborted block is either `REJECTED` or unknown.
+pub async fn check_transaction_aborted(
+    test_input: &TestSuiteReorg,
+    transaction_hash: Felt,
+) -> Result<(), OpenRpcTestGenError> {
+    let result = test_input
+        .paymaster_account
+        .provider()
+        .get_transaction_status(transaction_hash)
+        .await;
+
+    let aborted = match &result {
+        Ok(status) => matches!(status.finality_status, TxnStatus::Rejected),
+        Err(ProviderError::StarknetError(StarknetError::TransactionHashNotFound)) => true,
+        Err(_) => false,
+    };
+    assert_result!(
+        aborted,
+        format!(
+            "Transaction {:#x} of an aborted block has status {:?}",
+            transaction_hash, result
+        )
+    );
+
+    Ok(())
+}
+
+#[cfg(not(feature = "rust-analyzer"))]
+include!(concat!(env!("OUT_DIR"), "/generated_tests_suite_reorg.rs"));
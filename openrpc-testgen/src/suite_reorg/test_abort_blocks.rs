@@ -0,0 +1,71 @@
+use starknet_types_rpc::{BlockId, BlockTag};
+
+use crate::{
+    assert_eq_result, assert_result, utils::v7::endpoints::errors::OpenRpcTestGenError,
+    RunnableTrait,
+};
+
+/// Number of blocks built and then aborted.
+const ABORTED_BLOCKS: usize = 2;
+
+#[derive(Clone, Debug)]
+pub struct TestCase {}
+
+impl RunnableTrait for TestCase {
+    type Input = super::TestSuiteReorg;
+
+    async fn run(test_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let counter_before = super::counter(test_input, BlockId::Tag(BlockTag::Latest)).await?;
+
+        let mut blocks = vec![];
+        for _ in 0..ABORTED_BLOCKS {
+            blocks.push(super::build_block(test_input).await?);
+        }
+        let counter_built = super::counter(test_input, BlockId::Tag(BlockTag::Latest)).await?;
+        assert_eq_result!(
+            counter_built,
+            blocks
+                .iter()
+                .fold(counter_before, |counter, block| counter + block.amount),
+            "Counter after building {} blocks",
+            ABORTED_BLOCKS
+        );
+
+        let aborted = test_input
+            .devnet_client
+            .abort_blocks(BlockId::Hash(blocks[0].block_hash))
+            .await?;
+        for block in &blocks {
+            assert_result!(
+                aborted.contains(&block.block_hash),
+                format!(
+                    "Block {:#x} missing from aborted blocks {:?}",
+                    block.block_hash, aborted
+                )
+            );
+            super::check_block_aborted(test_input, block.block_hash).await?;
+            super::check_transaction_aborted(test_input, block.transaction_hash).await?;
+        }
+
+        let counter_after = super::counter(test_input, BlockId::Tag(BlockTag::Latest)).await?;
+        assert_eq_result!(
+            counter_after,
+            counter_before,
+            "Counter at latest block after aborting {} blocks",
+            ABORTED_BLOCKS
+        );
+
+        let event_transactions = super::counter_event_transactions(test_input).await?;
+        for block in &blocks {
+            assert_result!(
+                !event_transactions.contains(&block.transaction_hash),
+                format!(
+                    "getEvents returned the event of transaction {:#x} from aborted block {:#x}",
+                    block.transaction_hash, block.block_hash
+                )
+            );
+        }
+
+        Ok(Self {})
+    }
+}
@@ -0,0 +1,64 @@
+use starknet_types_rpc::{BlockId, BlockTag};
+
+use crate::{
+    assert_eq_result, assert_result, utils::v7::endpoints::errors::OpenRpcTestGenError,
+    RunnableTrait,
+};
+
+#[derive(Clone, Debug)]
+pub struct TestCase {}
+
+impl RunnableTrait for TestCase {
+    type Input = super::TestSuiteReorg;
+
+    async fn run(test_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let counter_before = super::counter(test_input, BlockId::Tag(BlockTag::Latest)).await?;
+
+        let aborted_block = super::build_block(test_input).await?;
+        test_input
+            .devnet_client
+            .abort_blocks(BlockId::Hash(aborted_block.block_hash))
+            .await?;
+
+        let rebuilt_block = super::build_block(test_input).await?;
+        assert_eq_result!(
+            rebuilt_block.block_number,
+            aborted_block.block_number,
+            "Number of the block built in place of aborted block {:#x}",
+            aborted_block.block_hash
+        );
+        assert_result!(
+            rebuilt_block.block_hash != aborted_block.block_hash,
+            format!(
+                "Rebuilt block {} has the hash {:#x} of the aborted block",
+                rebuilt_block.block_number, rebuilt_block.block_hash
+            )
+        );
+        super::check_block_aborted(test_input, aborted_block.block_hash).await?;
+
+        let counter_after = super::counter(test_input, BlockId::Tag(BlockTag::Latest)).await?;
+        assert_eq_result!(
+            counter_after,
+            counter_before + rebuilt_block.amount,
+            "Counter after aborting one increase and rebuilding another"
+        );
+
+        let event_transactions = super::counter_event_transactions(test_input).await?;
+        assert_result!(
+            event_transactions.contains(&rebuilt_block.transaction_hash),
+            format!(
+                "getEvents is missing the event of transaction {:#x} from rebuilt block {:#x}",
+                rebuilt_block.transaction_hash, rebuilt_block.block_hash
+            )
+        );
+        assert_result!(
+            !event_transactions.contains(&aborted_block.transaction_hash),
+            format!(
+                "getEvents returned the event of transaction {:#x} from aborted block {:#x}",
+                aborted_block.transaction_hash, aborted_block.block_hash
+            )
+        );
+
+        Ok(Self {})
+    }
+}
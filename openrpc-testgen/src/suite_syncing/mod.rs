@@ -0,0 +1,134 @@
+use std::time::Duration;
+
+use serde::Deserialize;
+use starknet_types_core::felt::Felt;
+use starknet_types_rpc::{BlockId, MaybePendingBlockWithTxHashes};
+use url::Url;
+
+use crate::{
+    assert_eq_result,
+    utils::v7::{
+        endpoints::errors::OpenRpcTestGenError,
+        providers::{
+            jsonrpc::{HttpTransport, JsonRpcClient},
+            provider::Provider,
+        },
+    },
+    SetupableTrait,
+};
+
+pub mod test_sync_progress;
+pub mod test_syncing_status;
+
+/// Interval between the polls of the syncing status of a syncing node.
+pub const SYNC_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Conformance of `starknet_syncing` with the blocks served by the nodes, and the progress of
+/// syncing nodes over time.
+#[derive(Clone, Debug)]
+pub struct TestSuiteSyncing {
+    pub providers: Vec<JsonRpcClient<HttpTransport>>,
+    /// Time a syncing node is given to catch up before the progress test fails, `None` to skip
+    /// the polling so the suite stays short.
+    pub sync_progress_timeout: Option<Duration>,
+}
+
+#[derive(Clone, Debug)]
+pub struct SetupInput {
+    pub urls: Vec<Url>,
+    pub sync_progress_timeout: Option<Duration>,
+}
+
+impl SetupableTrait for TestSuiteSyncing {
+    type Input = SetupInput;
+
+    async fn setup(setup_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        if setup_input.urls.is_empty() {
+            return Err(OpenRpcTestGenError::EmptyUrlList(
+                "No L2 node urls.".to_string(),
+            ));
+        }
+
+        let providers = setup_input
+            .urls
+            .iter()
+            .map(|url| JsonRpcClient::new(HttpTransport::new(url.clone())))
+            .collect();
+
+        Ok(Self {
+            providers,
+            sync_progress_timeout: setup_input.sync_progress_timeout,
+        })
+    }
+}
+
+/// Sync status of a syncing node, read by the field names of the specification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub struct SyncProgress {
+    pub starting_block_hash: Felt,
+    pub starting_block_num: u64,
+    pub current_block_hash: Felt,
+    pub current_block_num: u64,
+    pub highest_block_hash: Felt,
+    pub highest_block_num: u64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum SpecSyncingStatus {
+    Syncing(SyncProgress),
+    NotSyncing(bool),
+}
+
+/// Sync status reported by `starknet_syncing`, `None` for a node that is not syncing.
+pub async fn sync_progress<P: Provider>(
+    provider: &P,
+) -> Result<Option<SyncProgress>, OpenRpcTestGenError> {
+    let status = serde_json::to_value(provider.syncing().await?)?;
+
+    match serde_json::from_value(status.clone())? {
+        SpecSyncingStatus::Syncing(progress) => Ok(Some(progress)),
+        SpecSyncingStatus::NotSyncing(false) => Ok(None),
+        SpecSyncingStatus::NotSyncing(true) => Err(OpenRpcTestGenError::Other(format!(
+            "starknet_syncing returned {} instead of a sync status or false",
+            status
+        ))),
+    }
+}
+
+/// Checks that `getBlockWithTxHashes` serves the block of the given number with the given hash.
+pub async fn check_block<P: Provider>(
+    provider: &P,
+    block_number: u64,
+    block_hash: Felt,
+    block: &str,
+) -> Result<(), OpenRpcTestGenError> {
+    let served_hash = match provider
+        .get_block_with_tx_hashes(BlockId::Number(block_number))
+        .await?
+    {
+        MaybePendingBlockWithTxHashes::Block(block) => block.block_header.block_hash,
+        MaybePendingBlockWithTxHashes::Pending(_) => {
+            return Err(OpenRpcTestGenError::UnexpectedBlockResponseType(format!(
+                "Pending block returned for block {}",
+                block_number
+            )));
+        }
+    };
+
+    assert_eq_result!(
+        served_hash,
+        block_hash,
+        "Hash of the {} {} served by getBlockWithTxHashes",
+        block,
+        block_number
+    );
+
+    Ok(())
+}
+
+#[cfg(not(feature = "rust-analyzer"))]
+include!(concat!(
+    env!("OUT_DIR"),
+    "/generated_tests_suite_syncing.rs"
+));
@@ -0,0 +1,82 @@
+use std::time::Instant;
+
+use tracing::info;
+
+use crate::{
+    assert_eq_result, assert_result,
+    utils::v7::{endpoints::errors::OpenRpcTestGenError, providers::provider::Provider},
+    RunnableTrait,
+};
+
+#[derive(Clone, Debug)]
+pub struct TestCase {}
+
+impl RunnableTrait for TestCase {
+    type Input = super::TestSuiteSyncing;
+
+    async fn run(test_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        let Some(timeout) = test_input.sync_progress_timeout else {
+            info!("Skipping sync progress, no sync progress timeout given");
+            return Ok(Self {});
+        };
+
+        for (node, provider) in test_input.providers.iter().enumerate() {
+            let Some(mut previous) = super::sync_progress(provider).await? else {
+                info!("Skipping sync progress of node {}, it is not syncing", node);
+                continue;
+            };
+
+            let start = Instant::now();
+            loop {
+                tokio::time::sleep(super::SYNC_POLL_INTERVAL).await;
+
+                let Some(progress) = super::sync_progress(provider).await? else {
+                    // Once caught up, the node serves every block it saw while syncing.
+                    let latest_block = provider.block_number().await?;
+                    assert_result!(
+                        latest_block >= previous.highest_block_num,
+                        format!(
+                            "Node {} stopped syncing at block {} before the highest block {}",
+                            node, latest_block, previous.highest_block_num
+                        )
+                    );
+                    break;
+                };
+
+                assert_eq_result!(
+                    (progress.starting_block_num, progress.starting_block_hash),
+                    (previous.starting_block_num, previous.starting_block_hash),
+                    "Starting sync block of node {}",
+                    node
+                );
+                assert_result!(
+                    progress.current_block_num >= previous.current_block_num,
+                    format!(
+                        "Current sync block of node {} went back from {} to {}",
+                        node, previous.current_block_num, progress.current_block_num
+                    )
+                );
+                assert_result!(
+                    progress.highest_block_num >= previous.highest_block_num,
+                    format!(
+                        "Highest sync block of node {} went back from {} to {}",
+                        node, previous.highest_block_num, progress.highest_block_num
+                    )
+                );
+
+                if start.elapsed() > timeout {
+                    return Err(OpenRpcTestGenError::Timeout(format!(
+                        "Node {} still syncing after {} seconds, at block {} of {}",
+                        node,
+                        timeout.as_secs(),
+                        progress.current_block_num,
+                        progress.highest_block_num
+                    )));
+                }
+                previous = progress;
+            }
+        }
+
+        Ok(Self {})
+    }
+}
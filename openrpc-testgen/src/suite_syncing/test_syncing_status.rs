@@ -0,0 +1,76 @@
+use crate::{
+    assert_result,
+    utils::v7::{endpoints::errors::OpenRpcTestGenError, providers::provider::Provider},
+    RunnableTrait,
+};
+
+#[derive(Clone, Debug)]
+pub struct TestCase {}
+
+impl RunnableTrait for TestCase {
+    type Input = super::TestSuiteSyncing;
+
+    async fn run(test_input: &Self::Input) -> Result<Self, OpenRpcTestGenError> {
+        for provider in &test_input.providers {
+            let progress = super::sync_progress(provider).await?;
+            // Queried after the sync status, so the latest block can only be further.
+            let latest = provider.block_hash_and_number().await?;
+
+            let Some(progress) = progress else {
+                super::check_block(
+                    provider,
+                    latest.block_number,
+                    latest.block_hash,
+                    "latest block",
+                )
+                .await?;
+                continue;
+            };
+
+            assert_result!(
+                progress.starting_block_num <= progress.current_block_num
+                    && progress.current_block_num <= progress.highest_block_num,
+                format!(
+                    "Sync status blocks out of order: starting {}, current {}, highest {}",
+                    progress.starting_block_num,
+                    progress.current_block_num,
+                    progress.highest_block_num
+                )
+            );
+            assert_result!(
+                latest.block_number >= progress.current_block_num,
+                format!(
+                    "Latest block {} behind the current sync block {}",
+                    latest.block_number, progress.current_block_num
+                )
+            );
+
+            super::check_block(
+                provider,
+                progress.starting_block_num,
+                progress.starting_block_hash,
+                "starting sync block",
+            )
+            .await?;
+            super::check_block(
+                provider,
+                progress.current_block_num,
+                progress.current_block_hash,
+                "current sync block",
+            )
+            .await?;
+            // The highest block is only known to the node once synced up to it.
+            if progress.highest_block_num <= latest.block_number {
+                super::check_block(
+                    provider,
+                    progress.highest_block_num,
+                    progress.highest_block_hash,
+                    "highest sync block",
+                )
+                .await?;
+            }
+        }
+
+        Ok(Self {})
+    }
+}
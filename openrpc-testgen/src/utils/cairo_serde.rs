@@ -0,0 +1,182 @@
+//! Decoding of call results and event data with the `cainome_cairo_serde` types, the counterpart
+//! of [cairo_calldata](crate::cairo_calldata), and felt252 short strings.
+
+use cainome_cairo_serde::CairoSerde;
+use starknet_types_core::felt::Felt;
+
+/// Longest string fitting in a felt252.
+const MAX_SHORT_STRING_LEN: usize = 31;
+
+#[derive(Debug, thiserror::Error)]
+pub enum CairoSerdeError {
+    #[error("Failed to decode cairo value: {0:?}")]
+    Cairo(#[from] cainome_cairo_serde::Error),
+    #[error("{0} felts left after decoding")]
+    TrailingFelts(usize),
+    #[error("Invalid short string: {0}")]
+    InvalidShortString(String),
+}
+
+/// Decodes a value spanning all of `felts`, e.g. the result of a call.
+pub fn decode<T: CairoSerde>(felts: &[Felt]) -> Result<T::RustType, CairoSerdeError> {
+    let mut offset = 0;
+    let value = decode_at::<T>(felts, &mut offset)?;
+    if offset != felts.len() {
+        return Err(CairoSerdeError::TrailingFelts(felts.len() - offset));
+    }
+
+    Ok(value)
+}
+
+/// Decodes a value from `felts` at `offset` and moves `offset` past it, e.g. a member of the
+/// keys or data of an event.
+pub fn decode_at<T: CairoSerde>(
+    felts: &[Felt],
+    offset: &mut usize,
+) -> Result<T::RustType, CairoSerdeError> {
+    let value = T::cairo_deserialize(felts, *offset)?;
+    *offset += T::cairo_serialized_size(&value);
+
+    Ok(value)
+}
+
+/// Felt252 holding the ASCII `string`, e.g. `'ANY_CALLER'`.
+pub fn short_string_to_felt(string: &str) -> Result<Felt, CairoSerdeError> {
+    if !string.is_ascii() || string.len() > MAX_SHORT_STRING_LEN {
+        return Err(CairoSerdeError::InvalidShortString(string.to_string()));
+    }
+
+    Ok(Felt::from_bytes_be_slice(string.as_bytes()))
+}
+
+/// ASCII string held by a felt252, without its leading zero bytes.
+pub fn felt_to_short_string(felt: Felt) -> Result<String, CairoSerdeError> {
+    let bytes = felt.to_bytes_be();
+    let start = bytes
+        .iter()
+        .position(|byte| *byte != 0)
+        .unwrap_or(bytes.len());
+
+    if start == 0 || !bytes[start..].is_ascii() {
+        return Err(CairoSerdeError::InvalidShortString(format!("{:#x}", felt)));
+    }
+
+    Ok(String::from_utf8_lossy(&bytes[start..]).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use cainome_cairo_serde::{ByteArray, ContractAddress, U256};
+    use cainome_cairo_serde_derive::CairoSerde;
+
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq, CairoSerde)]
+    enum Action {
+        Stop,
+        Burn(ContractAddress),
+        Transfer(U256),
+    }
+
+    fn round_trip<T>(value: T::RustType) -> Vec<Felt>
+    where
+        T: CairoSerde,
+        T::RustType: PartialEq + std::fmt::Debug,
+    {
+        let felts = T::cairo_serialize(&value);
+        assert_eq!(decode::<T>(&felts).unwrap(), value);
+        felts
+    }
+
+    #[test]
+    fn test_round_trip_byte_array() {
+        let value = ByteArray::from_string("Cairo strings longer than thirty-one bytes").unwrap();
+        let felts = round_trip::<ByteArray>(value);
+
+        // One full 31 bytes word, then the pending word of the remaining 11 bytes.
+        assert_eq!(felts.len(), 4);
+        assert_eq!(felts[0], Felt::ONE);
+        assert_eq!(felts[3], Felt::from(11));
+        assert_eq!(
+            decode::<ByteArray>(&felts).unwrap().to_string().unwrap(),
+            "Cairo strings longer than thirty-one bytes"
+        );
+    }
+
+    #[test]
+    fn test_round_trip_option() {
+        assert_eq!(
+            round_trip::<Option<Felt>>(Some(Felt::from(7))),
+            vec![Felt::ZERO, Felt::from(7)]
+        );
+        assert_eq!(round_trip::<Option<Felt>>(None), vec![Felt::ONE]);
+    }
+
+    #[test]
+    fn test_round_trip_tuple() {
+        let felts = round_trip::<(Felt, U256, Vec<Felt>)>((
+            Felt::from(1),
+            U256 { low: 2, high: 3 },
+            vec![Felt::from(4)],
+        ));
+
+        assert_eq!(
+            felts,
+            vec![
+                Felt::from(1),
+                Felt::from(2),
+                Felt::from(3),
+                Felt::ONE,
+                Felt::from(4)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_round_trip_enum() {
+        assert_eq!(round_trip::<Action>(Action::Stop), vec![Felt::ZERO]);
+        assert_eq!(
+            round_trip::<Action>(Action::Burn(ContractAddress(Felt::from(0x123)))),
+            vec![Felt::ONE, Felt::from(0x123)]
+        );
+        assert_eq!(
+            round_trip::<Action>(Action::Transfer(U256 { low: 5, high: 0 })),
+            vec![Felt::TWO, Felt::from(5), Felt::ZERO]
+        );
+    }
+
+    #[test]
+    fn test_decode_event_members() {
+        // `Transfer` event of an ERC20: `from` and `to` in the keys, `value` in the data.
+        let keys = [Felt::from(0x1), Felt::from(0x2)];
+        let data = [Felt::from(100), Felt::ZERO];
+
+        let mut keys_offset = 0;
+        let from = decode_at::<ContractAddress>(&keys, &mut keys_offset).unwrap();
+        let to = decode_at::<ContractAddress>(&keys, &mut keys_offset).unwrap();
+
+        assert_eq!(from, ContractAddress(Felt::from(0x1)));
+        assert_eq!(to, ContractAddress(Felt::from(0x2)));
+        assert_eq!(keys_offset, keys.len());
+        assert_eq!(decode::<U256>(&data).unwrap(), U256 { low: 100, high: 0 });
+    }
+
+    #[test]
+    fn test_decode_trailing_felts() {
+        assert!(matches!(
+            decode::<Felt>(&[Felt::ONE, Felt::TWO]),
+            Err(CairoSerdeError::TrailingFelts(1))
+        ));
+    }
+
+    #[test]
+    fn test_short_string() {
+        let felt = short_string_to_felt("ANY_CALLER").unwrap();
+
+        assert_eq!(felt, Felt::from_hex("0x414e595f43414c4c4552").unwrap());
+        assert_eq!(felt_to_short_string(felt).unwrap(), "ANY_CALLER");
+        assert!(short_string_to_felt("a string of more than thirty-one chars").is_err());
+        assert!(short_string_to_felt("é").is_err());
+        assert!(felt_to_short_string(Felt::MAX).is_err());
+    }
+}
@@ -1,7 +1,10 @@
+use starknet_types_core::felt::Felt;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
 pub enum ConversionsError {
     #[error("Conversion failed: {0}")]
     FeltVecToBigUintError(String),
+    #[error("Value {0:#x} does not fit in u128")]
+    FeltToU128Error(Felt),
 }
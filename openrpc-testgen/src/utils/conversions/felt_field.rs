@@ -0,0 +1,29 @@
+use serde_json::Value;
+use starknet_types_core::felt::Felt;
+
+/// Felt held as a hex string by `field` of a JSON object, e.g. an event, a message or a call of
+/// a trace read as [Value]. `None` when the field is missing or not a felt.
+pub fn felt_field(value: &Value, field: &str) -> Option<Felt> {
+    value[field]
+        .as_str()
+        .and_then(|value| Felt::from_hex(value).ok())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_felt_field() {
+        let value = serde_json::json!({
+            "from_address": "0x1a2b",
+            "payload": ["0x1"],
+            "to_address": "not a felt"
+        });
+
+        assert_eq!(felt_field(&value, "from_address"), Some(Felt::from(0x1a2b)));
+        assert_eq!(felt_field(&value, "payload"), None);
+        assert_eq!(felt_field(&value, "to_address"), None);
+        assert_eq!(felt_field(&value, "class_hash"), None);
+    }
+}
@@ -0,0 +1,38 @@
+use starknet_types_core::felt::Felt;
+
+use super::errors::ConversionsError;
+
+/// Converts a Felt holding an amount, a price or a fee to u128, failing on larger values.
+pub fn felt_to_u128(value: Felt) -> Result<u128, ConversionsError> {
+    let bytes = value.to_bytes_be();
+    if bytes[..16].iter().any(|&byte| byte != 0) {
+        return Err(ConversionsError::FeltToU128Error(value));
+    }
+
+    let mut low = [0u8; 16];
+    low.copy_from_slice(&bytes[16..]);
+    Ok(u128::from_be_bytes(low))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_felt_to_u128() {
+        assert_eq!(felt_to_u128(Felt::ZERO).unwrap(), 0);
+        assert_eq!(felt_to_u128(Felt::from(u128::MAX)).unwrap(), u128::MAX);
+    }
+
+    #[test]
+    fn test_felt_to_u128_overflow() {
+        assert!(matches!(
+            felt_to_u128(Felt::from(u128::MAX) + Felt::ONE),
+            Err(ConversionsError::FeltToU128Error(_))
+        ));
+        assert!(matches!(
+            felt_to_u128(Felt::MAX),
+            Err(ConversionsError::FeltToU128Error(_))
+        ));
+    }
+}
@@ -1,2 +1,4 @@
 pub mod errors;
+pub mod felt_field;
+pub mod felt_to_u128;
 pub mod felts_to_biguint;
@@ -1,3 +1,4 @@
+pub mod cairo_serde;
 pub mod conversions;
 pub mod random_single_owner_account;
 pub mod v7;
@@ -81,12 +81,12 @@ impl Account for RandomSingleOwnerAccount {
     async fn sign_execution_v3(
         &self,
         execution: &crate::utils::v7::accounts::account::RawExecutionV3,
-        _query_only: bool,
+        query_only: bool,
     ) -> Result<Vec<Felt>, Self::SignError> {
         let random_account = self.random_accounts().unwrap();
 
         random_account
-            .sign_execution_v3(execution, _query_only)
+            .sign_execution_v3(execution, query_only)
             .await
     }
 
@@ -114,6 +114,18 @@ impl Account for RandomSingleOwnerAccount {
             .await
     }
 
+    async fn sign_legacy_declaration(
+        &self,
+        legacy_declaration: &crate::utils::v7::accounts::account::RawLegacyDeclaration,
+        query_only: bool,
+    ) -> Result<Vec<Felt>, Self::SignError> {
+        let random_account = self.random_accounts().unwrap();
+
+        random_account
+            .sign_legacy_declaration(legacy_declaration, query_only)
+            .await
+    }
+
     fn is_signer_interactive(&self) -> bool {
         let random_account = self.random_accounts().unwrap();
 
@@ -200,7 +200,7 @@ where
         self.prepare().await?.send().await
     }
 
-    async fn prepare(&self) -> Result<PreparedExecutionV1<'a, A>, AccountError<A::SignError>> {
+    pub async fn prepare(&self) -> Result<PreparedExecutionV1<'a, A>, AccountError<A::SignError>> {
         // Resolves nonce
         let nonce = match self.nonce {
             Some(value) => value,
@@ -266,7 +266,7 @@ where
         self.account
             .provider()
             .estimate_fee_single(
-                BroadcastedTxn::Invoke(BroadcastedInvokeTxn::V1(invoke)),
+                BroadcastedTxn::Invoke(BroadcastedInvokeTxn::QueryV1(invoke)),
                 vec![],
                 self.account.block_id(),
             )
@@ -305,10 +305,10 @@ where
 
         let mut flags = vec![];
 
-        if !skip_validate {
+        if skip_validate {
             flags.push(SimulationFlag::Validate);
         }
-        if !skip_fee_charge {
+        if skip_fee_charge {
             flags.push(SimulationFlag::FeeCharge);
         }
 
@@ -316,7 +316,7 @@ where
             .provider()
             .simulate_transaction(
                 self.account.block_id(),
-                BroadcastedTxn::Invoke(BroadcastedInvokeTxn::V1(invoke)),
+                BroadcastedTxn::Invoke(BroadcastedInvokeTxn::QueryV1(invoke)),
                 flags,
             )
             .await
@@ -367,7 +367,7 @@ where
         self.prepare().await?.send().await
     }
 
-    async fn prepare(&self) -> Result<PreparedExecutionV3<'a, A>, AccountError<A::SignError>> {
+    pub async fn prepare(&self) -> Result<PreparedExecutionV3<'a, A>, AccountError<A::SignError>> {
         // Resolves nonce
         let nonce = match self.nonce {
             Some(value) => value,
@@ -492,7 +492,7 @@ where
         self.account
             .provider()
             .estimate_fee_single(
-                BroadcastedTxn::Invoke(BroadcastedInvokeTxn::V3(invoke)),
+                BroadcastedTxn::Invoke(BroadcastedInvokeTxn::QueryV3(invoke)),
                 if skip_signature {
                     // Validation would fail since real signature was not requested
                     vec![]
@@ -551,7 +551,7 @@ where
             .provider()
             .simulate_transaction(
                 self.account.block_id(),
-                BroadcastedTxn::Invoke(BroadcastedInvokeTxn::V3(invoke)),
+                BroadcastedTxn::Invoke(BroadcastedInvokeTxn::QueryV3(invoke)),
                 flags,
             )
             .await
@@ -733,7 +733,7 @@ where
     // The `simulate` function is temporarily removed until it's supported in [Provider]
     // TODO: add `simulate` back once transaction simulation in supported
 
-    async fn get_invoke_request(
+    pub async fn get_invoke_request(
         &self,
         query_only: bool,
         skip_signature: bool,
@@ -775,7 +775,7 @@ where
     // The `simulate` function is temporarily removed until it's supported in [Provider]
     // TODO: add `simulate` back once transaction simulation in supported
 
-    async fn get_invoke_request(
+    pub async fn get_invoke_request(
         &self,
         query_only: bool,
         skip_signature: bool,
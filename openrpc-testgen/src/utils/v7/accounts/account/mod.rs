@@ -1,3 +1,4 @@
+use crate::utils::v7::contract::legacy::LegacyContractClass;
 use crate::utils::v7::providers::provider::{Provider, ProviderError};
 
 use auto_impl::auto_impl;
@@ -6,9 +7,7 @@ use sha3::{Digest, Keccak256};
 
 use starknet_types_core::felt::{Felt, NonZeroFelt};
 use starknet_types_core::hash::{Poseidon, StarkHash};
-use starknet_types_rpc::v0_7_1::{
-    BlockId, BlockTag, ContractClass, DeprecatedContractClass, SierraEntryPoint,
-};
+use starknet_types_rpc::v0_7_1::{BlockId, BlockTag, ContractClass, SierraEntryPoint};
 use std::{error::Error, sync::Arc};
 
 use super::{
@@ -87,11 +86,11 @@ pub trait Account: ExecutionEncoder + Sized {
         query_only: bool,
     ) -> impl std::future::Future<Output = Result<Vec<Felt>, Self::SignError>>;
 
-    // async fn sign_legacy_declaration(
-    //     &self,
-    //     legacy_declaration: &RawLegacyDeclaration,
-    //     query_only: bool,
-    // ) -> Result<Vec<Felt>, Self::SignError>;
+    fn sign_legacy_declaration(
+        &self,
+        legacy_declaration: &RawLegacyDeclaration,
+        query_only: bool,
+    ) -> impl std::future::Future<Output = Result<Vec<Felt>, Self::SignError>> + Send;
 
     /// Whether the underlying signer implementation is interactive, such as a hardware wallet.
     /// Implementations should return `true` if the signing operation is very expensive, even if not
@@ -144,12 +143,9 @@ pub trait Account: ExecutionEncoder + Sized {
     //     self.declare_v2(contract_class, compiled_class_hash)
     // }
 
-    // fn declare_legacy(
-    //     &self,
-    //     contract_class: Arc<DeprecatedContractClass>,
-    // ) -> LegacyDeclaration<Self> {
-    //     LegacyDeclaration::new(contract_class, self)
-    // }
+    fn declare_legacy(&self, contract_class: Arc<LegacyContractClass>) -> LegacyDeclaration<Self> {
+        LegacyDeclaration::new(contract_class, self)
+    }
 }
 
 #[auto_impl(&, Box, Arc)]
@@ -254,7 +250,7 @@ pub struct DeclarationV3<'a, A> {
 
 pub struct LegacyDeclaration<'a, A> {
     account: &'a A,
-    contract_class: Arc<DeprecatedContractClass<Felt>>,
+    contract_class: Arc<LegacyContractClass>,
     nonce: Option<Felt>,
     max_fee: Option<Felt>,
     fee_estimate_multiplier: f64,
@@ -353,7 +349,7 @@ pub struct RawDeclarationV3 {
 #[allow(dead_code)]
 
 pub struct RawLegacyDeclaration {
-    contract_class: Arc<DeprecatedContractClass<Felt>>,
+    contract_class: Arc<LegacyContractClass>,
     nonce: Felt,
     max_fee: Felt,
 }
@@ -456,15 +452,15 @@ where
         (*self).sign_declaration_v3(declaration, query_only).await
     }
 
-    // async fn sign_legacy_declaration(
-    //     &self,
-    //     legacy_declaration: &RawLegacyDeclaration,
-    //     query_only: bool,
-    // ) -> Result<Vec<Felt>, Self::SignError> {
-    //     (*self)
-    //         .sign_legacy_declaration(legacy_declaration, query_only)
-    //         .await
-    // }
+    async fn sign_legacy_declaration(
+        &self,
+        legacy_declaration: &RawLegacyDeclaration,
+        query_only: bool,
+    ) -> Result<Vec<Felt>, Self::SignError> {
+        (*self)
+            .sign_legacy_declaration(legacy_declaration, query_only)
+            .await
+    }
 
     fn is_signer_interactive(&self) -> bool {
         (*self).is_signer_interactive()
@@ -521,15 +517,15 @@ where
             .await
     }
 
-    // async fn sign_legacy_declaration(
-    //     &self,
-    //     legacy_declaration: &RawLegacyDeclaration,
-    //     query_only: bool,
-    // ) -> Result<Vec<Felt>, Self::SignError> {
-    //     self.as_ref()
-    //         .sign_legacy_declaration(legacy_declaration, query_only)
-    //         .await
-    // }
+    async fn sign_legacy_declaration(
+        &self,
+        legacy_declaration: &RawLegacyDeclaration,
+        query_only: bool,
+    ) -> Result<Vec<Felt>, Self::SignError> {
+        self.as_ref()
+            .sign_legacy_declaration(legacy_declaration, query_only)
+            .await
+    }
 
     fn is_signer_interactive(&self) -> bool {
         self.as_ref().is_signer_interactive()
@@ -586,15 +582,15 @@ where
             .await
     }
 
-    // async fn sign_legacy_declaration(
-    //     &self,
-    //     legacy_declaration: &RawLegacyDeclaration,
-    //     query_only: bool,
-    // ) -> Result<Vec<Felt>, Self::SignError> {
-    //     self.as_ref()
-    //         .sign_legacy_declaration(legacy_declaration, query_only)
-    //         .await
-    // }
+    async fn sign_legacy_declaration(
+        &self,
+        legacy_declaration: &RawLegacyDeclaration,
+        query_only: bool,
+    ) -> Result<Vec<Felt>, Self::SignError> {
+        self.as_ref()
+            .sign_legacy_declaration(legacy_declaration, query_only)
+            .await
+    }
 
     fn is_signer_interactive(&self) -> bool {
         self.as_ref().is_signer_interactive()
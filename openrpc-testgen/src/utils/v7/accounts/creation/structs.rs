@@ -3,7 +3,6 @@ use serde::Serialize;
 use serde::Serializer;
 
 use starknet_types_core::felt::Felt;
-use starknet_types_rpc::v0_7_1::PriceUnit;
 
 use crate::utils::v7::signers::key_pair::SigningKey;
 
@@ -48,24 +47,3 @@ pub struct MintRequest {
     pub amount: u128,
     pub address: Felt,
 }
-
-#[derive(Serialize, Deserialize, Debug)]
-pub struct MintRequest2 {
-    pub amount: u128,
-    pub address: Felt,
-    pub unit: PriceUnit,
-}
-
-#[derive(Serialize, Deserialize, Debug)]
-pub struct MintResponse {
-    new_balance: String,
-    unit: PriceUnit,
-    tx_hash: Felt,
-}
-
-// #[derive(Serialize, Deserialize, Debug)]
-// pub struct MintResponse {
-//     new_balance: U256,
-//     unit: PriceUnit,
-//     tx_hash: Felt,
-// }
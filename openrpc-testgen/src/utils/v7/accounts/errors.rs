@@ -1,51 +1,16 @@
 use starknet_types_core::felt::Felt;
 use thiserror::Error;
 
+pub use crate::utils::v7::contract::{
+    BytecodeSegmentLengthMismatchError, CompressProgramError, ComputeClassHashError,
+    InvalidBytecodeSegmentError, JsonError, PcOutOfRangeError,
+};
 use crate::utils::v7::providers::provider::ProviderError;
 
 #[derive(Debug, thiserror::Error)]
 #[error("Not all fields are prepared")]
 pub struct NotPreparedError;
 #[allow(dead_code)]
-#[derive(Debug)]
-pub enum ComputeClassHashError {
-    InvalidBuiltinName,
-    BytecodeSegmentLengthMismatch(BytecodeSegmentLengthMismatchError),
-    InvalidBytecodeSegment(InvalidBytecodeSegmentError),
-    PcOutOfRange(PcOutOfRangeError),
-    Json(JsonError),
-}
-#[allow(dead_code)]
-#[derive(Debug)]
-pub enum CompressProgramError {
-    Json(JsonError),
-    Io(std::io::Error),
-}
-
-#[derive(Debug)]
-#[allow(dead_code)]
-
-pub struct JsonError {
-    pub(crate) message: String,
-}
-#[allow(dead_code)]
-#[derive(Debug)]
-pub struct BytecodeSegmentLengthMismatchError {
-    pub segment_length: usize,
-    pub bytecode_length: usize,
-}
-#[allow(dead_code)]
-#[derive(Debug)]
-pub struct InvalidBytecodeSegmentError {
-    pub visited_pc: u64,
-    pub segment_start: u64,
-}
-#[allow(dead_code)]
-#[derive(Debug)]
-pub struct PcOutOfRangeError {
-    pub pc: u64,
-}
-#[allow(dead_code)]
 #[derive(Error, Debug)]
 pub enum CreationError {
     #[error("Class with hash {0:#x} is not declared, try using --class-hash with a hash of the declared class")]
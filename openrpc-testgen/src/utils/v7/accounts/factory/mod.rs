@@ -39,8 +39,6 @@ const PREFIX_DEPLOY_ACCOUNT: Felt = Felt::from_raw([
     3350261884043292318,
 ]);
 
-#[allow(dead_code)]
-
 /// 2 ^ 128 + 1
 const QUERY_VERSION_ONE: Felt = Felt::from_raw([
     576460752142433776,
@@ -49,8 +47,6 @@ const QUERY_VERSION_ONE: Felt = Felt::from_raw([
     18446744073700081633,
 ]);
 
-#[allow(dead_code)]
-
 /// 2 ^ 128 + 3
 const QUERY_VERSION_THREE: Felt = Felt::from_raw([
     576460752142432688,
@@ -390,7 +386,7 @@ where
         self.prepare().await?.send().await
     }
 
-    async fn prepare(
+    pub async fn prepare(
         &self,
     ) -> Result<PreparedAccountDeploymentV1<'f, F>, AccountFactoryError<F::SignError>> {
         // Resolves nonce
@@ -453,14 +449,14 @@ where
         };
 
         let deploy = prepared
-            .get_deploy_request(false, skip_signature)
+            .get_deploy_request(true, skip_signature)
             .await
             .map_err(AccountFactoryError::Signing)?;
 
         self.factory
             .provider()
             .estimate_fee_single(
-                BroadcastedTxn::DeployAccount(BroadcastedDeployAccountTxn::V1(deploy)),
+                BroadcastedTxn::DeployAccount(BroadcastedDeployAccountTxn::QueryV1(deploy)),
                 vec![],
                 self.factory.block_id(),
             )
@@ -499,10 +495,10 @@ where
 
         let mut flags = vec![];
 
-        if !skip_validate {
+        if skip_validate {
             flags.push(SimulationFlag::Validate);
         }
-        if !skip_fee_charge {
+        if skip_fee_charge {
             flags.push(SimulationFlag::FeeCharge);
         }
 
@@ -510,7 +506,7 @@ where
             .provider()
             .simulate_transaction(
                 self.factory.block_id(),
-                BroadcastedTxn::DeployAccount(BroadcastedDeployAccountTxn::V1(deploy)),
+                BroadcastedTxn::DeployAccount(BroadcastedDeployAccountTxn::QueryV1(deploy)),
                 flags,
             )
             .await
@@ -583,7 +579,7 @@ where
         self.prepare().await?.send().await
     }
 
-    async fn prepare(
+    pub async fn prepare(
         &self,
     ) -> Result<PreparedAccountDeploymentV3<'f, F>, AccountFactoryError<F::SignError>> {
         // Resolves nonce
@@ -705,14 +701,14 @@ where
             },
         };
         let deploy = prepared
-            .get_deploy_request(false, skip_signature)
+            .get_deploy_request(true, skip_signature)
             .await
             .map_err(AccountFactoryError::Signing)?;
 
         self.factory
             .provider()
             .estimate_fee_single(
-                BroadcastedTxn::DeployAccount(BroadcastedDeployAccountTxn::V3(deploy)),
+                BroadcastedTxn::DeployAccount(BroadcastedDeployAccountTxn::QueryV3(deploy)),
                 if skip_signature {
                     // Validation would fail since real signature was not requested
                     vec![]
@@ -752,7 +748,7 @@ where
             },
         };
         let deploy = prepared
-            .get_deploy_request(false, skip_signature)
+            .get_deploy_request(true, skip_signature)
             .await
             .map_err(AccountFactoryError::Signing)?;
 
@@ -769,7 +765,7 @@ where
             .provider()
             .simulate_transaction(
                 self.factory.block_id(),
-                BroadcastedTxn::DeployAccount(BroadcastedDeployAccountTxn::V3(deploy)),
+                BroadcastedTxn::DeployAccount(BroadcastedDeployAccountTxn::QueryV3(deploy)),
                 flags,
             )
             .await
@@ -840,13 +836,17 @@ where
         )
     }
 
-    pub fn transaction_hash(&self, _query_only: bool) -> Felt {
+    pub fn transaction_hash(&self, query_only: bool) -> Felt {
         let mut calldata_to_hash = vec![self.factory.class_hash(), self.inner.salt];
         calldata_to_hash.append(&mut self.factory.calldata());
 
         compute_hash_on_elements(&[
             PREFIX_DEPLOY_ACCOUNT,
-            Felt::ONE,
+            if query_only {
+                QUERY_VERSION_ONE
+            } else {
+                Felt::ONE
+            }, // version
             self.address(),
             Felt::ZERO, // entry_point_selector
             compute_hash_on_elements(&calldata_to_hash),
@@ -873,7 +873,7 @@ where
             .map_err(AccountFactoryError::Provider)
     }
 
-    async fn get_deploy_request(
+    pub async fn get_deploy_request(
         &self,
         query_only: bool,
         skip_signature: bool,
@@ -912,9 +912,17 @@ where
         )
     }
 
-    pub fn transaction_hash(&self, _query_only: bool) -> Felt {
+    pub fn transaction_hash(&self, query_only: bool) -> Felt {
         // Main data vector to collect all elements for hashing
-        let mut data = vec![PREFIX_DEPLOY_ACCOUNT, Felt::THREE, self.address()];
+        let mut data = vec![
+            PREFIX_DEPLOY_ACCOUNT,
+            if query_only {
+                QUERY_VERSION_THREE
+            } else {
+                Felt::THREE
+            },
+            self.address(),
+        ];
 
         // Fee data collection
         let mut fee_data = vec![Felt::ZERO]; // Hard-coded fee market
@@ -974,16 +982,18 @@ where
             .map_err(AccountFactoryError::Provider)
     }
 
-    async fn get_deploy_request(
+    pub async fn get_deploy_request(
         &self,
-        _query_only: bool,
+        query_only: bool,
         skip_signature: bool,
     ) -> Result<DeployAccountTxnV3<Felt>, F::SignError> {
         Ok(DeployAccountTxnV3 {
             signature: if skip_signature {
                 vec![]
             } else {
-                self.factory.sign_deployment_v3(&self.inner, false).await?
+                self.factory
+                    .sign_deployment_v3(&self.inner, query_only)
+                    .await?
             },
             nonce: self.inner.nonce,
             contract_address_salt: self.inner.salt,
@@ -1011,7 +1021,6 @@ where
             // Hard-coded L1 DA mode for nonce and fee
             nonce_data_availability_mode: DaMode::L1,
             fee_data_availability_mode: DaMode::L1,
-            // is_query: query_only,
         })
     }
 }
@@ -92,10 +92,10 @@ where
     async fn sign_deployment_v3(
         &self,
         deployment: &RawAccountDeploymentV3,
-        _query_only: bool,
+        query_only: bool,
     ) -> Result<Vec<Felt>, Self::SignError> {
-        let tx_hash =
-            PreparedAccountDeploymentV3::from_raw(deployment.clone(), self).transaction_hash(false);
+        let tx_hash = PreparedAccountDeploymentV3::from_raw(deployment.clone(), self)
+            .transaction_hash(query_only);
         let signature = self.signer.sign_hash(&tx_hash).await?;
 
         Ok(vec![signature.r, signature.s])
@@ -4,5 +4,5 @@ pub mod creation;
 pub mod deployment;
 pub mod errors;
 pub mod factory;
+pub mod outside_execution;
 pub mod single_owner;
-pub mod utils;
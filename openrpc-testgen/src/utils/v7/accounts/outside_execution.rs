@@ -0,0 +1,379 @@
+use std::collections::HashMap;
+
+use serde_json::{json, Value};
+use starknet_types_core::felt::Felt;
+
+use super::{account::starknet_keccak, call::Call};
+use crate::utils::v7::{
+    signers::signer::Signer,
+    typed_data::{Domain, SignTypedDataError, TypeMember, TypedData},
+};
+
+/// `'ANY_CALLER'` short string, allows any account to submit the outside execution.
+pub const ANY_CALLER: Felt = Felt::from_raw([
+    401125202220711261,
+    18446744073709551615,
+    18446744073709016628,
+    15282850808162428286,
+]);
+
+const DOMAIN_NAME: &str = "Account.execute_from_outside";
+
+/// SNIP-9 outside execution, version 1. Signed as revision 0 typed data and submitted through
+/// `execute_from_outside`.
+#[derive(Debug, Clone)]
+pub struct OutsideExecutionV1 {
+    caller: Felt,
+    nonce: Felt,
+    execute_after: u64,
+    execute_before: u64,
+    calls: Vec<Call>,
+}
+
+/// SNIP-9 outside execution, version 2. Signed as revision 1 typed data and submitted through
+/// `execute_from_outside_v2`.
+#[derive(Debug, Clone)]
+pub struct OutsideExecutionV2 {
+    caller: Felt,
+    nonce: Felt,
+    execute_after: u64,
+    execute_before: u64,
+    calls: Vec<Call>,
+}
+
+impl OutsideExecutionV1 {
+    /// Creates an outside execution any caller can submit at any time, with a random nonce.
+    pub fn new(calls: Vec<Call>) -> Self {
+        Self {
+            caller: ANY_CALLER,
+            nonce: Felt::from(rand::random::<u128>()),
+            execute_after: 0,
+            execute_before: u64::MAX,
+            calls,
+        }
+    }
+
+    pub fn caller(self, caller: Felt) -> Self {
+        Self { caller, ..self }
+    }
+
+    pub fn nonce(self, nonce: Felt) -> Self {
+        Self { nonce, ..self }
+    }
+
+    pub fn execute_after(self, execute_after: u64) -> Self {
+        Self {
+            execute_after,
+            ..self
+        }
+    }
+
+    pub fn execute_before(self, execute_before: u64) -> Self {
+        Self {
+            execute_before,
+            ..self
+        }
+    }
+
+    pub fn typed_data(&self, chain_id: Felt) -> TypedData {
+        let calls: Vec<Value> = self
+            .calls
+            .iter()
+            .map(|call| {
+                json!({
+                    "to": call.to.to_hex_string(),
+                    "selector": call.selector.to_hex_string(),
+                    "calldata_len": call.calldata.len(),
+                    "calldata": felts_to_hex(&call.calldata),
+                })
+            })
+            .collect();
+
+        TypedData {
+            types: HashMap::from([
+                (
+                    "StarkNetDomain".to_string(),
+                    vec![
+                        TypeMember::new("name", "felt"),
+                        TypeMember::new("version", "felt"),
+                        TypeMember::new("chainId", "felt"),
+                    ],
+                ),
+                (
+                    "OutsideExecution".to_string(),
+                    vec![
+                        TypeMember::new("caller", "felt"),
+                        TypeMember::new("nonce", "felt"),
+                        TypeMember::new("execute_after", "felt"),
+                        TypeMember::new("execute_before", "felt"),
+                        TypeMember::new("calls_len", "felt"),
+                        TypeMember::new("calls", "OutsideCall*"),
+                    ],
+                ),
+                (
+                    "OutsideCall".to_string(),
+                    vec![
+                        TypeMember::new("to", "felt"),
+                        TypeMember::new("selector", "felt"),
+                        TypeMember::new("calldata_len", "felt"),
+                        TypeMember::new("calldata", "felt*"),
+                    ],
+                ),
+            ]),
+            primary_type: "OutsideExecution".to_string(),
+            domain: Domain {
+                name: json!(DOMAIN_NAME),
+                version: json!(1),
+                chain_id: json!(chain_id.to_hex_string()),
+                revision: None,
+            },
+            message: json!({
+                "caller": self.caller.to_hex_string(),
+                "nonce": self.nonce.to_hex_string(),
+                "execute_after": self.execute_after,
+                "execute_before": self.execute_before,
+                "calls_len": self.calls.len(),
+                "calls": calls,
+            }),
+        }
+    }
+
+    /// Signature of `account` over the outside execution, as expected by `execute_from_outside`.
+    pub async fn sign<S>(
+        &self,
+        chain_id: Felt,
+        account: Felt,
+        signer: &S,
+    ) -> Result<Vec<Felt>, SignTypedDataError<S::SignError>>
+    where
+        S: Signer + Sync,
+    {
+        let signature = self.typed_data(chain_id).sign(account, signer).await?;
+        Ok(vec![signature.r, signature.s])
+    }
+
+    /// Call to `execute_from_outside` on `account`. The signature is not checked against the
+    /// outside execution, so mismatching ones can be submitted on purpose.
+    pub fn call(&self, account: Felt, signature: Vec<Felt>) -> Call {
+        Call {
+            to: account,
+            selector: starknet_keccak(b"execute_from_outside"),
+            calldata: execute_from_outside_calldata(
+                self.caller,
+                self.nonce,
+                self.execute_after,
+                self.execute_before,
+                &self.calls,
+                signature,
+            ),
+        }
+    }
+}
+
+impl OutsideExecutionV2 {
+    /// Creates an outside execution any caller can submit at any time, with a random nonce.
+    pub fn new(calls: Vec<Call>) -> Self {
+        Self {
+            caller: ANY_CALLER,
+            nonce: Felt::from(rand::random::<u128>()),
+            execute_after: 0,
+            execute_before: u64::MAX,
+            calls,
+        }
+    }
+
+    pub fn caller(self, caller: Felt) -> Self {
+        Self { caller, ..self }
+    }
+
+    pub fn nonce(self, nonce: Felt) -> Self {
+        Self { nonce, ..self }
+    }
+
+    pub fn execute_after(self, execute_after: u64) -> Self {
+        Self {
+            execute_after,
+            ..self
+        }
+    }
+
+    pub fn execute_before(self, execute_before: u64) -> Self {
+        Self {
+            execute_before,
+            ..self
+        }
+    }
+
+    pub fn typed_data(&self, chain_id: Felt) -> TypedData {
+        let calls: Vec<Value> = self
+            .calls
+            .iter()
+            .map(|call| {
+                json!({
+                    "To": call.to.to_hex_string(),
+                    "Selector": call.selector.to_hex_string(),
+                    "Calldata": felts_to_hex(&call.calldata),
+                })
+            })
+            .collect();
+
+        TypedData {
+            types: HashMap::from([
+                (
+                    "StarknetDomain".to_string(),
+                    vec![
+                        TypeMember::new("name", "shortstring"),
+                        TypeMember::new("version", "shortstring"),
+                        TypeMember::new("chainId", "shortstring"),
+                        TypeMember::new("revision", "shortstring"),
+                    ],
+                ),
+                (
+                    "OutsideExecution".to_string(),
+                    vec![
+                        TypeMember::new("Caller", "ContractAddress"),
+                        TypeMember::new("Nonce", "felt"),
+                        TypeMember::new("Execute After", "u128"),
+                        TypeMember::new("Execute Before", "u128"),
+                        TypeMember::new("Calls", "Call*"),
+                    ],
+                ),
+                (
+                    "Call".to_string(),
+                    vec![
+                        TypeMember::new("To", "ContractAddress"),
+                        TypeMember::new("Selector", "selector"),
+                        TypeMember::new("Calldata", "felt*"),
+                    ],
+                ),
+            ]),
+            primary_type: "OutsideExecution".to_string(),
+            domain: Domain {
+                name: json!(DOMAIN_NAME),
+                version: json!(2),
+                chain_id: json!(chain_id.to_hex_string()),
+                revision: Some(json!(1)),
+            },
+            message: json!({
+                "Caller": self.caller.to_hex_string(),
+                "Nonce": self.nonce.to_hex_string(),
+                "Execute After": self.execute_after,
+                "Execute Before": self.execute_before,
+                "Calls": calls,
+            }),
+        }
+    }
+
+    /// Signature of `account` over the outside execution, as expected by
+    /// `execute_from_outside_v2`.
+    pub async fn sign<S>(
+        &self,
+        chain_id: Felt,
+        account: Felt,
+        signer: &S,
+    ) -> Result<Vec<Felt>, SignTypedDataError<S::SignError>>
+    where
+        S: Signer + Sync,
+    {
+        let signature = self.typed_data(chain_id).sign(account, signer).await?;
+        Ok(vec![signature.r, signature.s])
+    }
+
+    /// Call to `execute_from_outside_v2` on `account`. The signature is not checked against the
+    /// outside execution, so mismatching ones can be submitted on purpose.
+    pub fn call(&self, account: Felt, signature: Vec<Felt>) -> Call {
+        Call {
+            to: account,
+            selector: starknet_keccak(b"execute_from_outside_v2"),
+            calldata: execute_from_outside_calldata(
+                self.caller,
+                self.nonce,
+                self.execute_after,
+                self.execute_before,
+                &self.calls,
+                signature,
+            ),
+        }
+    }
+}
+
+fn felts_to_hex(felts: &[Felt]) -> Vec<String> {
+    felts.iter().map(Felt::to_hex_string).collect()
+}
+
+/// Cairo serialization of the `OutsideExecution` struct followed by the signature, shared by both
+/// versions.
+fn execute_from_outside_calldata(
+    caller: Felt,
+    nonce: Felt,
+    execute_after: u64,
+    execute_before: u64,
+    calls: &[Call],
+    signature: Vec<Felt>,
+) -> Vec<Felt> {
+    let mut calldata = vec![
+        caller,
+        nonce,
+        Felt::from(execute_after),
+        Felt::from(execute_before),
+        Felt::from(calls.len()),
+    ];
+    for call in calls {
+        calldata.push(call.to);
+        calldata.push(call.selector);
+        calldata.push(Felt::from(call.calldata.len()));
+        calldata.extend_from_slice(&call.calldata);
+    }
+    calldata.push(Felt::from(signature.len()));
+    calldata.extend(signature);
+
+    calldata
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_any_caller() {
+        assert_eq!(ANY_CALLER, Felt::from_bytes_be_slice(b"ANY_CALLER"));
+    }
+
+    #[test]
+    fn test_outside_execution_v2_type_hashes() {
+        let typed_data = OutsideExecutionV2::new(vec![]).typed_data(Felt::ONE);
+
+        // Constants from the OpenZeppelin SRC9 component
+        assert_eq!(
+            typed_data.type_hash("OutsideExecution").unwrap(),
+            Felt::from_hex_unchecked(
+                "0x312b56c05a7965066ddbda31c016d8d05afc305071c0ca3cdc2192c3c2f1f0f"
+            )
+        );
+        assert_eq!(
+            typed_data.type_hash("Call").unwrap(),
+            Felt::from_hex_unchecked(
+                "0x3635c7f2a7ba93844c0d064e18e487f35ab90f7c39d00f186a781fc3f0c2ca9"
+            )
+        );
+    }
+
+    #[test]
+    fn test_execute_from_outside_calldata() {
+        let outside_execution = OutsideExecutionV1::new(vec![Call {
+            to: Felt::from(10),
+            selector: Felt::from(11),
+            calldata: vec![Felt::from(12)],
+        }])
+        .nonce(Felt::from(5))
+        .execute_before(100);
+
+        let call = outside_execution.call(Felt::from(20), vec![Felt::from(30), Felt::from(31)]);
+
+        assert_eq!(call.to, Felt::from(20));
+        assert_eq!(call.selector, starknet_keccak(b"execute_from_outside"));
+        let mut expected = vec![ANY_CALLER];
+        expected.extend([5_u64, 0, 100, 1, 10, 11, 1, 12, 2, 30, 31].map(Felt::from));
+        assert_eq!(call.calldata, expected);
+    }
+}
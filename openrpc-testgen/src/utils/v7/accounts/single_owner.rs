@@ -7,7 +7,7 @@ use starknet_types_rpc::v0_7_1::{BlockId, BlockTag};
 use super::{
     account::{
         Account, ConnectedAccount, ExecutionEncoder, RawDeclarationV2, RawDeclarationV3,
-        RawExecutionV1, RawExecutionV3,
+        RawExecutionV1, RawExecutionV3, RawLegacyDeclaration,
     },
     call::Call,
     errors::ComputeClassHashError,
@@ -101,9 +101,9 @@ where
     async fn sign_execution_v1(
         &self,
         execution: &RawExecutionV1,
-        _query_only: bool,
+        query_only: bool,
     ) -> Result<Vec<Felt>, Self::SignError> {
-        let tx_hash = execution.transaction_hash(self.chain_id, self.address, false, self);
+        let tx_hash = execution.transaction_hash(self.chain_id, self.address, query_only, self);
         let signature = self
             .signer
             .sign_hash(&tx_hash)
@@ -116,9 +116,9 @@ where
     async fn sign_execution_v3(
         &self,
         execution: &RawExecutionV3,
-        _query_only: bool,
+        query_only: bool,
     ) -> Result<Vec<Felt>, Self::SignError> {
-        let tx_hash = execution.transaction_hash(self.chain_id, self.address, false, self);
+        let tx_hash = execution.transaction_hash(self.chain_id, self.address, query_only, self);
         let signature = self
             .signer
             .sign_hash(&tx_hash)
@@ -158,22 +158,22 @@ where
         Ok(vec![signature.r, signature.s])
     }
 
-    // async fn sign_legacy_declaration(
-    //     &self,
-    //     legacy_declaration: &RawLegacyDeclaration,
-    //     query_only: bool,
-    // ) -> Result<Vec<Felt>, Self::SignError> {
-    //     let tx_hash = legacy_declaration
-    //         .transaction_hash(self.chain_id, self.address, query_only)
-    //         .map_err(SignError::ClassHash)?;
-    //     let signature = self
-    //         .signer
-    //         .sign_hash(&tx_hash)
-    //         .await
-    //         .map_err(SignError::Signer)?;
-
-    //     Ok(vec![signature.r, signature.s])
-    // }
+    async fn sign_legacy_declaration(
+        &self,
+        legacy_declaration: &RawLegacyDeclaration,
+        query_only: bool,
+    ) -> Result<Vec<Felt>, Self::SignError> {
+        let tx_hash = legacy_declaration
+            .transaction_hash(self.chain_id, self.address, query_only)
+            .map_err(SignError::ClassHash)?;
+        let signature = self
+            .signer
+            .sign_hash(&tx_hash)
+            .await
+            .map_err(SignError::Signer)?;
+
+        Ok(vec![signature.r, signature.s])
+    }
 
     fn is_signer_interactive(&self) -> bool {
         self.signer.is_interactive()
@@ -0,0 +1,531 @@
+//! Local recomputation of the block hash and header commitments of blocks served by a node, using
+//! the Starknet v0.13.2 block hash calculation of `production-nodes-types`.
+//!
+//! Headers of the v0.7 specification only expose the block hash and the state root, so the
+//! transaction, event, receipt and state diff commitments are verified through the block hash.
+//! Receipts don't report the gas consumed either, which is derived from the fee, so blocks whose
+//! fees don't pin the gas down exactly are not verified.
+
+use std::cmp::Ordering;
+
+use production_nodes_types::pathfinder_types::types::{
+    block::BlockHeaderData,
+    block_hash::{
+        calculate_event_commitment, calculate_receipt_commitment, calculate_transaction_commitment,
+        compute_final_hash,
+    },
+    event::Event,
+    header::L1DataAvailabilityMode,
+    receipt::{ExecutionResources, ExecutionStatus, L1Gas, L2ToL1Message, Receipt},
+    state_update::{state_diff_commitment, StateUpdate as CommonStateUpdate},
+};
+use serde::Deserialize;
+use starknet_types_core::felt::Felt;
+use starknet_types_rpc::v0_7_1::{
+    BlockWithReceipts, PriceUnit, StateUpdate, Txn, TxnExecutionStatus, TxnWithHash,
+};
+
+use crate::utils::conversions::{errors::ConversionsError, felt_to_u128::felt_to_u128};
+
+/// First Starknet version hashing blocks with Poseidon over the header commitments.
+const MIN_STARKNET_VERSION: [u64; 3] = [0, 13, 2];
+
+#[derive(Debug, thiserror::Error)]
+pub enum BlockHashError {
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+    #[error("Failed to calculate commitment: {0}")]
+    Commitment(#[from] anyhow::Error),
+    #[error("Block {block_number}: {field} reported by the node is {reported:#x}, computed {computed:#x}")]
+    Mismatch {
+        block_number: u64,
+        field: &'static str,
+        reported: Felt,
+        computed: Felt,
+    },
+    #[error("Block hash calculation of Starknet version {0} is not supported")]
+    UnsupportedVersion(String),
+    #[error("Block {block_number}: block_hash reported by the node is {reported:#x}, computed {computed:#x} from {}", format_commitments(.commitments))]
+    BlockHashMismatch {
+        block_number: u64,
+        reported: Felt,
+        computed: Felt,
+        commitments: Vec<(&'static str, Felt)>,
+    },
+    #[error("Gas consumed by transaction {0:#x} cannot be derived exactly from its fee")]
+    GasConsumed(Felt),
+    #[error(transparent)]
+    Conversions(#[from] ConversionsError),
+}
+
+/// Block with receipts, read by the field names of the specification.
+#[derive(Debug, Deserialize)]
+struct SpecBlock {
+    parent_hash: Felt,
+    block_number: u64,
+    new_root: Felt,
+    timestamp: u64,
+    sequencer_address: Felt,
+    l1_gas_price: SpecResourcePrice,
+    l1_data_gas_price: SpecResourcePrice,
+    l1_da_mode: L1DataAvailabilityMode,
+    starknet_version: String,
+    transactions: Vec<SpecTransactionAndReceipt>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SpecResourcePrice {
+    price_in_fri: Felt,
+    price_in_wei: Felt,
+}
+
+#[derive(Debug, Deserialize)]
+struct SpecTransactionAndReceipt {
+    transaction: Txn<Felt>,
+    receipt: SpecReceipt,
+}
+
+#[derive(Debug, Deserialize)]
+struct SpecReceipt {
+    transaction_hash: Felt,
+    actual_fee: SpecFeePayment,
+    messages_sent: Vec<L2ToL1Message>,
+    events: Vec<Event>,
+    execution_status: TxnExecutionStatus,
+    #[serde(default)]
+    revert_reason: Option<String>,
+    execution_resources: SpecExecutionResources,
+}
+
+#[derive(Debug, Deserialize)]
+struct SpecFeePayment {
+    amount: Felt,
+    unit: PriceUnit,
+}
+
+#[derive(Debug, Deserialize)]
+struct SpecExecutionResources {
+    data_availability: L1Gas,
+}
+
+#[derive(Debug, Deserialize)]
+struct SpecStateUpdate {
+    block_hash: Felt,
+    new_root: Felt,
+    state_diff: SpecStateDiff,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct SpecStateDiff {
+    storage_diffs: Vec<SpecContractStorageDiff>,
+    deprecated_declared_classes: Vec<Felt>,
+    declared_classes: Vec<SpecDeclaredClass>,
+    deployed_contracts: Vec<SpecDeployedContract>,
+    replaced_classes: Vec<SpecReplacedClass>,
+    nonces: Vec<SpecNonceUpdate>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SpecContractStorageDiff {
+    address: Felt,
+    storage_entries: Vec<SpecStorageEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SpecStorageEntry {
+    key: Felt,
+    value: Felt,
+}
+
+#[derive(Debug, Deserialize)]
+struct SpecDeclaredClass {
+    class_hash: Felt,
+    compiled_class_hash: Felt,
+}
+
+#[derive(Debug, Deserialize)]
+struct SpecDeployedContract {
+    address: Felt,
+    class_hash: Felt,
+}
+
+#[derive(Debug, Deserialize)]
+struct SpecReplacedClass {
+    contract_address: Felt,
+    class_hash: Felt,
+}
+
+#[derive(Debug, Deserialize)]
+struct SpecNonceUpdate {
+    contract_address: Felt,
+    nonce: Felt,
+}
+
+/// Recomputes the header of a block from its transactions, receipts and state update, with the
+/// block hash computed over the recomputed commitments.
+pub fn compute_block_header(
+    block: &BlockWithReceipts<Felt>,
+    state_update: &StateUpdate<Felt>,
+) -> Result<BlockHeaderData, BlockHashError> {
+    let block: SpecBlock = serde_json::from_value(serde_json::to_value(block)?)?;
+    let state_update: SpecStateUpdate =
+        serde_json::from_value(serde_json::to_value(state_update)?)?;
+
+    if compare_versions(&block.starknet_version, &MIN_STARKNET_VERSION) == Ordering::Less {
+        return Err(BlockHashError::UnsupportedVersion(block.starknet_version));
+    }
+
+    let eth_l1_gas_price = felt_to_u128(block.l1_gas_price.price_in_wei)?;
+    let strk_l1_gas_price = felt_to_u128(block.l1_gas_price.price_in_fri)?;
+    let eth_l1_data_gas_price = felt_to_u128(block.l1_data_gas_price.price_in_wei)?;
+    let strk_l1_data_gas_price = felt_to_u128(block.l1_data_gas_price.price_in_fri)?;
+
+    let mut transactions = Vec::with_capacity(block.transactions.len());
+    let mut receipts = Vec::with_capacity(block.transactions.len());
+    let mut transaction_events = Vec::with_capacity(block.transactions.len());
+
+    for (
+        transaction_index,
+        SpecTransactionAndReceipt {
+            transaction,
+            receipt,
+        },
+    ) in block.transactions.into_iter().enumerate()
+    {
+        let (gas_price, data_gas_price) = match receipt.actual_fee.unit {
+            PriceUnit::Wei => (eth_l1_gas_price, eth_l1_data_gas_price),
+            PriceUnit::Fri => (strk_l1_gas_price, strk_l1_data_gas_price),
+        };
+        let total_gas_consumed = total_gas_consumed(&receipt, gas_price, data_gas_price)?;
+
+        transactions.push(TxnWithHash {
+            transaction,
+            transaction_hash: receipt.transaction_hash,
+        });
+        transaction_events.push((receipt.transaction_hash, receipt.events));
+        receipts.push(Receipt {
+            actual_fee: receipt.actual_fee.amount,
+            execution_resources: ExecutionResources {
+                total_gas_consumed,
+                ..Default::default()
+            },
+            l2_to_l1_messages: receipt.messages_sent,
+            execution_status: match receipt.execution_status {
+                TxnExecutionStatus::Succeeded => ExecutionStatus::Succeeded,
+                TxnExecutionStatus::Reverted => ExecutionStatus::Reverted {
+                    reason: receipt.revert_reason.unwrap_or_default(),
+                },
+            },
+            transaction_hash: receipt.transaction_hash,
+            transaction_index: transaction_index as u64,
+        });
+    }
+
+    let state_update = common_state_update(state_update);
+
+    let mut header = BlockHeaderData {
+        hash: Felt::ZERO,
+        parent_hash: block.parent_hash,
+        number: block.block_number,
+        timestamp: block.timestamp,
+        sequencer_address: block.sequencer_address,
+        state_commitment: block.new_root,
+        state_diff_commitment: state_diff_commitment::compute(
+            &state_update.contract_updates,
+            &state_update.system_contract_updates,
+            &state_update.declared_cairo_classes,
+            &state_update.declared_sierra_classes,
+        ),
+        transaction_commitment: calculate_transaction_commitment(&transactions)?,
+        transaction_count: transactions.len() as u32,
+        event_commitment: calculate_event_commitment(&transaction_events)?,
+        event_count: transaction_events
+            .iter()
+            .map(|(_, events)| events.len() as u32)
+            .sum(),
+        state_diff_length: state_update.state_diff_length(),
+        starknet_version: block.starknet_version,
+        eth_l1_gas_price,
+        strk_l1_gas_price,
+        eth_l1_data_gas_price,
+        strk_l1_data_gas_price,
+        receipt_commitment: calculate_receipt_commitment(&receipts)?,
+        l1_da_mode: block.l1_da_mode,
+    };
+    header.hash = compute_final_hash(&header).map_err(anyhow::Error::from)?;
+
+    Ok(header)
+}
+
+/// Verifies the block hash reported by the node, and by its state update, against the recomputed
+/// header, returning the recomputed header.
+pub fn verify_block_header(
+    block: &BlockWithReceipts<Felt>,
+    state_update: &StateUpdate<Felt>,
+) -> Result<BlockHeaderData, BlockHashError> {
+    let header = compute_block_header(block, state_update)?;
+
+    if block.block_header.block_hash != header.hash {
+        return Err(BlockHashError::BlockHashMismatch {
+            block_number: header.number,
+            reported: block.block_header.block_hash,
+            computed: header.hash,
+            commitments: header_commitments(&header),
+        });
+    }
+
+    let checks = [
+        (
+            "state update block_hash",
+            state_update.block_hash,
+            header.hash,
+        ),
+        (
+            "state update new_root",
+            state_update.new_root,
+            header.state_commitment,
+        ),
+    ];
+    for (field, reported, computed) in checks {
+        if reported != computed {
+            return Err(BlockHashError::Mismatch {
+                block_number: header.number,
+                field,
+                reported,
+                computed,
+            });
+        }
+    }
+
+    Ok(header)
+}
+
+/// Commitments the block hash is computed from, by name, since a mismatching hash can't tell
+/// which of them the node calculated differently.
+pub fn header_commitments(header: &BlockHeaderData) -> Vec<(&'static str, Felt)> {
+    vec![
+        ("transaction_commitment", header.transaction_commitment),
+        ("event_commitment", header.event_commitment),
+        ("state_diff_commitment", header.state_diff_commitment),
+        ("receipt_commitment", header.receipt_commitment),
+    ]
+}
+
+fn format_commitments(commitments: &[(&'static str, Felt)]) -> String {
+    commitments
+        .iter()
+        .map(|(name, commitment)| format!("{} {:#x}", name, commitment))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Gas consumed by a transaction, derived from its fee since v0.7 receipts only report the gas of
+/// data availability: all of the L1 data gas is spent on data availability, and the L1 gas is
+/// what remains of the fee.
+///
+/// The derivation only holds when the fee is the full price of the gas, so it is refused for
+/// reverted transactions, whose fee may be capped by their max fee or resource bounds, and for
+/// fees that are not a whole number of gas units.
+fn total_gas_consumed(
+    receipt: &SpecReceipt,
+    gas_price: u128,
+    data_gas_price: u128,
+) -> Result<L1Gas, BlockHashError> {
+    let inexact = BlockHashError::GasConsumed(receipt.transaction_hash);
+    if receipt.execution_status == TxnExecutionStatus::Reverted {
+        return Err(inexact);
+    }
+
+    let fee = felt_to_u128(receipt.actual_fee.amount)?;
+    let l1_data_gas = receipt.execution_resources.data_availability.l1_data_gas;
+
+    let l1_gas_fee = match l1_data_gas
+        .checked_mul(data_gas_price)
+        .and_then(|data_gas_fee| fee.checked_sub(data_gas_fee))
+    {
+        Some(l1_gas_fee) if gas_price != 0 && l1_gas_fee % gas_price == 0 => l1_gas_fee,
+        _ => return Err(inexact),
+    };
+    let l1_gas = l1_gas_fee / gas_price;
+
+    Ok(L1Gas {
+        l1_gas,
+        l1_data_gas,
+    })
+}
+
+fn common_state_update(state_update: SpecStateUpdate) -> CommonStateUpdate {
+    let mut common = CommonStateUpdate::default()
+        .with_block_hash(state_update.block_hash)
+        .with_state_commitment(state_update.new_root);
+
+    let state_diff = state_update.state_diff;
+    for storage_diff in state_diff.storage_diffs {
+        for entry in storage_diff.storage_entries {
+            // Storage of the system contract at 0x1 is committed separately.
+            common = if storage_diff.address == Felt::ONE {
+                common.with_system_storage_update(storage_diff.address, entry.key, entry.value)
+            } else {
+                common.with_storage_update(storage_diff.address, entry.key, entry.value)
+            };
+        }
+    }
+    for deployed in state_diff.deployed_contracts {
+        common = common.with_deployed_contract(deployed.address, deployed.class_hash);
+    }
+    for nonce_update in state_diff.nonces {
+        common = common.with_contract_nonce(nonce_update.contract_address, nonce_update.nonce);
+    }
+    for replaced in state_diff.replaced_classes {
+        common = common.with_replaced_class(replaced.contract_address, replaced.class_hash);
+    }
+    for declared in state_diff.declared_classes {
+        common =
+            common.with_declared_sierra_class(declared.class_hash, declared.compiled_class_hash);
+    }
+    for class_hash in state_diff.deprecated_declared_classes {
+        common = common.with_declared_cairo_class(class_hash);
+    }
+
+    common
+}
+
+/// Compares a dotted Starknet version, like `0.13.2.1`, with a version given by its numbers.
+fn compare_versions(version: &str, other: &[u64]) -> Ordering {
+    let numbers: Vec<u64> = version
+        .split('.')
+        .map(|number| number.parse().unwrap_or_default())
+        .collect();
+
+    numbers.as_slice().cmp(other)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_compare_versions() {
+        assert_eq!(
+            compare_versions("0.13.2", &MIN_STARKNET_VERSION),
+            Ordering::Equal
+        );
+        assert_eq!(
+            compare_versions("0.13.2.1", &MIN_STARKNET_VERSION),
+            Ordering::Greater
+        );
+        assert_eq!(
+            compare_versions("0.13.10", &MIN_STARKNET_VERSION),
+            Ordering::Greater
+        );
+        assert_eq!(
+            compare_versions("0.13.1.1", &MIN_STARKNET_VERSION),
+            Ordering::Less
+        );
+    }
+
+    #[test]
+    fn test_total_gas_consumed() {
+        let receipt: SpecReceipt = serde_json::from_value(serde_json::json!({
+            "transaction_hash": "0x1",
+            "actual_fee": { "amount": "0x274", "unit": "FRI" },
+            "messages_sent": [],
+            "events": [],
+            "execution_status": "SUCCEEDED",
+            "execution_resources": {
+                "data_availability": { "l1_gas": 0, "l1_data_gas": 128 }
+            }
+        }))
+        .unwrap();
+
+        // 628 = 5 * 100 + 128 * 1
+        let gas = total_gas_consumed(&receipt, 100, 1).unwrap();
+        assert_eq!(gas.l1_gas, 5);
+        assert_eq!(gas.l1_data_gas, 128);
+
+        // 628 - 128 is not a whole number of gas units at a price of 3.
+        assert!(matches!(
+            total_gas_consumed(&receipt, 3, 1),
+            Err(BlockHashError::GasConsumed(_))
+        ));
+        assert!(matches!(
+            total_gas_consumed(&receipt, 0, 1),
+            Err(BlockHashError::GasConsumed(_))
+        ));
+    }
+
+    #[test]
+    fn test_total_gas_consumed_reverted() {
+        let receipt: SpecReceipt = serde_json::from_value(serde_json::json!({
+            "transaction_hash": "0x1",
+            "actual_fee": { "amount": "0x274", "unit": "FRI" },
+            "messages_sent": [],
+            "events": [],
+            "execution_status": "REVERTED",
+            "revert_reason": "Insufficient max fee",
+            "execution_resources": {
+                "data_availability": { "l1_gas": 0, "l1_data_gas": 128 }
+            }
+        }))
+        .unwrap();
+
+        assert!(matches!(
+            total_gas_consumed(&receipt, 100, 1),
+            Err(BlockHashError::GasConsumed(_))
+        ));
+    }
+
+    /// Block of Starknet version 0.13.2 built for the test: an invoke v3 paid in FRI sending a
+    /// message to L1, an invoke v1 paid in WEI, and a state diff with every kind of update,
+    /// including storage of the system contract at 0x1. Its commitments and block hash were
+    /// calculated apart from this module, following the v0.13.2 block hash specification.
+    #[test]
+    fn test_compute_block_header() {
+        let block: BlockWithReceipts<Felt> =
+            serde_json::from_str(include_str!("test_data/block_with_receipts.json")).unwrap();
+        let state_update: StateUpdate<Felt> =
+            serde_json::from_str(include_str!("test_data/state_update.json")).unwrap();
+
+        let header = verify_block_header(&block, &state_update).unwrap();
+
+        assert_eq!(
+            header.hash,
+            Felt::from_hex_unchecked(
+                "0x269ae0aff208d28abb36c1c1107606cf7e58e0f2c70048dfa08502aac4c72a0"
+            )
+        );
+        assert_eq!(
+            header_commitments(&header),
+            vec![
+                (
+                    "transaction_commitment",
+                    Felt::from_hex_unchecked(
+                        "0x32d5b7a2e70845f65287a7451710e0cd0b7b6cc6b1b1779fb659ca05bf3a638"
+                    )
+                ),
+                (
+                    "event_commitment",
+                    Felt::from_hex_unchecked(
+                        "0x3f4378f6bc47503cbc1eca191178d36a37c718809ec5630ac85be2093c08035"
+                    )
+                ),
+                (
+                    "state_diff_commitment",
+                    Felt::from_hex_unchecked(
+                        "0x59bdb767d329af2175308030cdac5c038ab08a85e303b138df4636d589ca21f"
+                    )
+                ),
+                (
+                    "receipt_commitment",
+                    Felt::from_hex_unchecked(
+                        "0x2967cbb5f6ed606324f8622d8be1f9a50cebc4080450912323e04b5e45f4fb2"
+                    )
+                ),
+            ]
+        );
+        assert_eq!(header.transaction_count, 2);
+        assert_eq!(header.event_count, 3);
+        assert_eq!(header.state_diff_length, 9);
+    }
+}
@@ -0,0 +1,836 @@
+//! Generator of typed Rust bindings from the ABI of a Sierra class.
+//!
+//! The generated module contains one wrapper struct per contract, with a method per ABI function:
+//! external functions return an [ExecutionV3](crate::utils::v7::accounts::account::ExecutionV3)
+//! of the wrapped account, views go through `Provider::call`. Cairo structs and enums become
+//! `CairoSerde` types, and events get a `decode` from their keys and data. Runtime support for
+//! the generated code lives in [bindings](super::bindings).
+
+use std::collections::{BTreeSet, HashMap};
+
+use super::{AbiEntry, AbiEnum, AbiEvent, AbiFunction, AbiNamedMember, AbiStruct, EventFieldKind};
+use super::{EventField, StateMutability, TypedAbiEvent};
+
+#[derive(Debug, thiserror::Error)]
+pub enum BindgenError {
+    #[error("Unsupported Cairo type {0}")]
+    UnsupportedType(String),
+    #[error("Unsupported {kind} member {member} in event {event}")]
+    UnsupportedEventMember {
+        event: String,
+        member: String,
+        kind: String,
+    },
+}
+
+const RUST_KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "if", "impl", "in",
+    "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "static", "struct", "super", "trait", "true", "try", "type", "typeof", "unsafe",
+    "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/// Bindings of a single contract.
+pub struct Bindgen<'a> {
+    contract_name: String,
+    artifact_name: String,
+    abi: &'a [AbiEntry],
+}
+
+/// ABI entries relevant to code generation, with interfaces flattened.
+#[derive(Default)]
+struct Items<'a> {
+    constructor: Option<&'a [AbiNamedMember]>,
+    functions: Vec<&'a AbiFunction>,
+    structs: Vec<&'a AbiStruct>,
+    enums: Vec<&'a AbiEnum>,
+    event_structs: Vec<(&'a str, &'a [EventField])>,
+    event_enums: Vec<(&'a str, &'a [EventField])>,
+}
+
+impl<'a> Bindgen<'a> {
+    /// `contract_name` is the name of the generated struct, `artifact_name` the registry name of
+    /// the contract the ABI comes from, only used in the module documentation.
+    pub fn new(contract_name: &str, artifact_name: &str, abi: &'a [AbiEntry]) -> Self {
+        Self {
+            contract_name: contract_name.to_string(),
+            artifact_name: artifact_name.to_string(),
+            abi,
+        }
+    }
+
+    pub fn generate(&self) -> Result<String, BindgenError> {
+        let mut items = Items::default();
+        collect_items(self.abi, &mut items);
+
+        let names = type_names(&items);
+        let mut resolver = TypeResolver {
+            names: &names,
+            imports: BTreeSet::new(),
+        };
+
+        let mut types = String::new();
+        for abi_struct in &items.structs {
+            types.push_str(&generate_struct(abi_struct, &names, &mut resolver)?);
+        }
+        for abi_enum in &items.enums {
+            types.push_str(&generate_enum(abi_enum, &names, &mut resolver)?);
+        }
+
+        let mut events = String::new();
+        for (name, members) in &items.event_structs {
+            events.push_str(&generate_event_struct(
+                name,
+                members,
+                &names,
+                &mut resolver,
+            )?);
+        }
+        for (name, variants) in &items.event_enums {
+            events.push_str(&generate_event_enum(name, variants, &names)?);
+        }
+
+        let contract_event = root_event_enum(&items).map(|name| names[name].clone());
+        let contract = self.generate_contract(&items, contract_event.as_deref(), &mut resolver)?;
+
+        let mut out = format!(
+            "//! Bindings of `{}`, generated by `contract-bindgen` from its Sierra ABI. Regenerate\n\
+             //! instead of editing by hand.\n\
+             #![allow(non_snake_case, clippy::ptr_arg, clippy::too_many_arguments)]\n\n",
+            self.artifact_name
+        );
+
+        let mut cainome_imports: Vec<&str> = resolver.imports.iter().copied().collect();
+        cainome_imports.push("CairoSerde");
+        out.push_str(&format!(
+            "use cainome_cairo_serde::{{{}}};\n",
+            cainome_imports.join(", ")
+        ));
+        if !items.structs.is_empty() || !items.enums.is_empty() {
+            out.push_str("use cainome_cairo_serde_derive::CairoSerde;\n");
+        }
+        out.push_str("use starknet_types_core::felt::Felt;\n");
+
+        let has_external = items.functions.iter().any(|f| is_external(f));
+        let has_view = items.functions.iter().any(|f| !is_external(f));
+        let has_events = !items.event_structs.is_empty() || !items.event_enums.is_empty();
+        let rpc_imports: Vec<&str> = [
+            (has_view, "FunctionCall"),
+            (contract_event.is_some(), "TxnReceipt"),
+        ]
+        .into_iter()
+        .filter_map(|(used, import)| used.then_some(import))
+        .collect();
+        match rpc_imports.as_slice() {
+            [] => {}
+            [import] => out.push_str(&format!("use starknet_types_rpc::{import};\n")),
+            imports => out.push_str(&format!(
+                "use starknet_types_rpc::{{{}}};\n",
+                imports.join(", ")
+            )),
+        }
+
+        let mut crate_imports = vec![];
+        if has_external || has_events {
+            crate_imports.push("accounts::account::starknet_keccak");
+        }
+        if has_external {
+            crate_imports.push("accounts::account::ExecutionV3");
+            crate_imports.push("accounts::call::Call");
+        }
+        crate_imports.push("accounts::account::ConnectedAccount");
+        if has_view || has_events {
+            crate_imports.push("contract::bindings::ContractBindingError");
+        }
+        if !items.event_structs.is_empty() {
+            crate_imports.push("contract::bindings::decode_member");
+        }
+        if contract_event.is_some() {
+            crate_imports.push("contract::bindings::receipt_events");
+        }
+        if has_view {
+            crate_imports.push("accounts::account::starknet_keccak");
+            crate_imports.push("providers::provider::Provider");
+        }
+        crate_imports.sort();
+        crate_imports.dedup();
+        for import in crate_imports {
+            out.push_str(&format!("use crate::utils::v7::{import};\n"));
+        }
+
+        out.push_str(&contract);
+        out.push_str(&types);
+        out.push_str(&events);
+
+        Ok(out)
+    }
+
+    fn generate_contract(
+        &self,
+        items: &Items,
+        contract_event: Option<&str>,
+        resolver: &mut TypeResolver,
+    ) -> Result<String, BindgenError> {
+        let name = &self.contract_name;
+        let mut out = format!(
+            "\n#[derive(Debug, Clone)]\n\
+             pub struct {name}<A> {{\n\
+             \x20   pub address: Felt,\n\
+             \x20   pub account: A,\n\
+             }}\n\n\
+             impl<A> {name}<A>\n\
+             where\n\
+             \x20   A: ConnectedAccount + Sync,\n\
+             {{\n\
+             \x20   pub fn new(address: Felt, account: A) -> Self {{\n\
+             \x20       Self {{ address, account }}\n\
+             \x20   }}\n",
+        );
+
+        for function in &items.functions {
+            let (params, calldata) = serialize_inputs(&function.inputs, resolver)?;
+            let method = escape_keyword(&function.name);
+            let args = function
+                .inputs
+                .iter()
+                .map(|input| escape_keyword(&input.name))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let params = if params.is_empty() {
+                "&self".to_string()
+            } else {
+                format!("&self, {params}")
+            };
+            let selector = format!("starknet_keccak(b\"{}\")", function.name);
+
+            match function.state_mutability {
+                StateMutability::External => {
+                    out.push_str(&format!(
+                        "\n    pub fn {}_call({params}) -> Call {{\n\
+                         {calldata}\
+                         \x20       Call {{\n\
+                         \x20           to: self.address,\n\
+                         \x20           selector: {selector},\n\
+                         \x20           calldata,\n\
+                         \x20       }}\n\
+                         \x20   }}\n\n\
+                         \x20   pub fn {method}({params}) -> ExecutionV3<'_, A> {{\n\
+                         \x20       self.account\n\
+                         \x20           .execute_v3(vec![self.{}_call({args})])\n\
+                         \x20   }}\n",
+                        function.name, function.name,
+                    ));
+                }
+                StateMutability::View => {
+                    let output = match function.outputs.first() {
+                        Some(output) => resolver.resolve(&output.r#type)?,
+                        None => "()".to_string(),
+                    };
+                    let (binding, result) = if output == "()" {
+                        ("", "        Ok(())\n".to_string())
+                    } else {
+                        (
+                            "let result = ",
+                            format!("        Ok(<{output}>::cairo_deserialize(&result, 0)?)\n"),
+                        )
+                    };
+                    out.push_str(&format!(
+                        "\n    pub async fn {method}({params}) -> Result<{output}, ContractBindingError> {{\n\
+                         {calldata}\
+                         \x20       {binding}self\n\
+                         \x20           .account\n\
+                         \x20           .provider()\n\
+                         \x20           .call(\n\
+                         \x20               FunctionCall {{\n\
+                         \x20                   contract_address: self.address,\n\
+                         \x20                   entry_point_selector: {selector},\n\
+                         \x20                   calldata,\n\
+                         \x20               }},\n\
+                         \x20               self.account.block_id(),\n\
+                         \x20           )\n\
+                         \x20           .await?;\n\
+                         {result}\
+                         \x20   }}\n"
+                    ));
+                }
+            }
+        }
+
+        if let Some(event) = contract_event {
+            out.push_str(&format!(
+                "\n    /// Events emitted by this contract in `receipt`.\n\
+                 \x20   pub fn events(&self, receipt: &TxnReceipt<Felt>) -> Result<Vec<{event}>, ContractBindingError> {{\n\
+                 \x20       receipt_events(receipt)\n\
+                 \x20           .iter()\n\
+                 \x20           .filter(|event| event.from_address == self.address)\n\
+                 \x20           .map(|event| {event}::decode(&event.keys, &event.data))\n\
+                 \x20           .collect()\n\
+                 \x20   }}\n"
+            ));
+        }
+
+        out.push_str("}\n");
+
+        if let Some(inputs) = items.constructor {
+            let (params, calldata) = serialize_inputs(inputs, resolver)?;
+            let body = if inputs.is_empty() {
+                "    vec![]\n".to_string()
+            } else {
+                // Statements are indented for a method body, one level deeper than needed here.
+                let statements: String = calldata
+                    .lines()
+                    .map(|line| format!("{}\n", &line[4..]))
+                    .collect();
+                format!("{statements}    calldata\n")
+            };
+            out.push_str(&format!(
+                "\n/// Calldata of the constructor of [{name}], to be passed to the deployment.\n\
+                 pub fn constructor_calldata({params}) -> Vec<Felt> {{\n\
+                 {body}\
+                 }}\n"
+            ));
+        }
+
+        Ok(out)
+    }
+}
+
+fn collect_items<'a>(entries: &'a [AbiEntry], items: &mut Items<'a>) {
+    for entry in entries {
+        match entry {
+            AbiEntry::Function(function) => {
+                if !items.functions.iter().any(|f| f.name == function.name) {
+                    items.functions.push(function);
+                }
+            }
+            AbiEntry::Interface(interface) => collect_items(&interface.items, items),
+            AbiEntry::Constructor(constructor) => items.constructor = Some(&constructor.inputs),
+            AbiEntry::Struct(abi_struct) if !is_core(&abi_struct.name) => {
+                items.structs.push(abi_struct)
+            }
+            AbiEntry::Enum(abi_enum) if !is_core(&abi_enum.name) => items.enums.push(abi_enum),
+            AbiEntry::Event(AbiEvent::Typed(TypedAbiEvent::Struct(event))) => {
+                items.event_structs.push((&event.name, &event.members))
+            }
+            AbiEntry::Event(AbiEvent::Typed(TypedAbiEvent::Enum(event))) => {
+                items.event_enums.push((&event.name, &event.variants))
+            }
+            // L1 handlers can't be called by accounts and Cairo 1.0 events carry no type
+            // information worth generating.
+            _ => {}
+        }
+    }
+}
+
+fn is_external(function: &AbiFunction) -> bool {
+    matches!(function.state_mutability, StateMutability::External)
+}
+
+fn is_core(path: &str) -> bool {
+    path.starts_with("core::")
+}
+
+/// Rust names of all generated types, by Cairo path. The last path segment is used, prefixed
+/// with the enclosing module when it's ambiguous, e.g. the `Event` of a contract and of its
+/// components.
+fn type_names(items: &Items) -> HashMap<String, String> {
+    let paths: Vec<&str> = items
+        .structs
+        .iter()
+        .map(|s| s.name.as_str())
+        .chain(items.enums.iter().map(|e| e.name.as_str()))
+        .chain(items.event_structs.iter().map(|(name, _)| *name))
+        .chain(items.event_enums.iter().map(|(name, _)| *name))
+        .collect();
+
+    let mut last_segment_count: HashMap<&str, usize> = HashMap::new();
+    for path in &paths {
+        *last_segment_count.entry(last_segment(path)).or_default() += 1;
+    }
+
+    paths
+        .iter()
+        .map(|path| {
+            let name = last_segment(path);
+            let name = if last_segment_count[name] > 1 {
+                let module = path.rsplit("::").nth(1).unwrap_or_default();
+                format!("{}{}", to_pascal_case(module), name)
+            } else {
+                name.to_string()
+            };
+            (path.to_string(), name)
+        })
+        .collect()
+}
+
+/// Event enum of the contract itself, the one not nested in any other event.
+fn root_event_enum<'a>(items: &Items<'a>) -> Option<&'a str> {
+    items
+        .event_enums
+        .iter()
+        .map(|(name, _)| *name)
+        .find(|name| {
+            !items
+                .event_enums
+                .iter()
+                .any(|(_, variants)| variants.iter().any(|v| v.r#type == *name))
+        })
+}
+
+fn last_segment(path: &str) -> &str {
+    path.rsplit("::").next().unwrap_or(path)
+}
+
+fn to_pascal_case(name: &str) -> String {
+    name.split('_')
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+fn escape_keyword(name: &str) -> String {
+    if RUST_KEYWORDS.contains(&name) {
+        format!("r#{name}")
+    } else {
+        name.to_string()
+    }
+}
+
+/// Function parameters and the statements serializing them into `calldata`.
+fn serialize_inputs(
+    inputs: &[AbiNamedMember],
+    resolver: &mut TypeResolver,
+) -> Result<(String, String), BindgenError> {
+    let mut params = vec![];
+    let mut calldata = if inputs.is_empty() {
+        "        let calldata = vec![];\n".to_string()
+    } else {
+        "        let mut calldata = vec![];\n".to_string()
+    };
+
+    for input in inputs {
+        let name = escape_keyword(&input.name);
+        let rust_type = resolver.resolve(&input.r#type)?;
+        params.push(format!("{name}: &{rust_type}"));
+        calldata.push_str(&format!(
+            "        calldata.extend(<{rust_type}>::cairo_serialize({name}));\n"
+        ));
+    }
+
+    Ok((params.join(", "), calldata))
+}
+
+fn generate_struct(
+    abi_struct: &AbiStruct,
+    names: &HashMap<String, String>,
+    resolver: &mut TypeResolver,
+) -> Result<String, BindgenError> {
+    let mut out = format!(
+        "\n#[derive(Debug, Clone, PartialEq, CairoSerde)]\npub struct {} {{\n",
+        names[&abi_struct.name]
+    );
+    for member in &abi_struct.members {
+        out.push_str(&format!(
+            "    pub {}: {},\n",
+            escape_keyword(&member.name),
+            resolver.resolve(&member.r#type)?
+        ));
+    }
+    out.push_str("}\n");
+    Ok(out)
+}
+
+fn generate_enum(
+    abi_enum: &AbiEnum,
+    names: &HashMap<String, String>,
+    resolver: &mut TypeResolver,
+) -> Result<String, BindgenError> {
+    let mut out = format!(
+        "\n#[derive(Debug, Clone, PartialEq, CairoSerde)]\npub enum {} {{\n",
+        names[&abi_enum.name]
+    );
+    for variant in &abi_enum.variants {
+        match resolver.resolve(&variant.r#type)?.as_str() {
+            "()" => out.push_str(&format!("    {},\n", variant.name)),
+            rust_type => out.push_str(&format!("    {}({rust_type}),\n", variant.name)),
+        }
+    }
+    out.push_str("}\n");
+    Ok(out)
+}
+
+fn generate_event_struct(
+    name: &str,
+    members: &[EventField],
+    names: &HashMap<String, String>,
+    resolver: &mut TypeResolver,
+) -> Result<String, BindgenError> {
+    let rust_name = &names[name];
+    let mut fields = String::new();
+    let mut decoded = String::new();
+    let (mut uses_keys, mut uses_data) = (false, false);
+
+    for member in members {
+        let (source, offset) = match member.kind {
+            EventFieldKind::Key => {
+                uses_keys = true;
+                ("keys", "keys_offset")
+            }
+            EventFieldKind::Data => {
+                uses_data = true;
+                ("data", "data_offset")
+            }
+            EventFieldKind::Nested | EventFieldKind::Flat => {
+                return Err(BindgenError::UnsupportedEventMember {
+                    event: name.to_string(),
+                    member: member.name.clone(),
+                    kind: format!("{:?}", member.kind),
+                })
+            }
+        };
+        let field = escape_keyword(&member.name);
+        let rust_type = resolver.resolve(&member.r#type)?;
+        fields.push_str(&format!("    pub {field}: {rust_type},\n"));
+        decoded.push_str(&format!(
+            "            {field}: decode_member::<{rust_type}>({source}, &mut {offset})?,\n"
+        ));
+    }
+
+    let mut offsets = String::new();
+    if uses_keys {
+        offsets.push_str("        let mut keys_offset = 0;\n");
+    }
+    if uses_data {
+        offsets.push_str("        let mut data_offset = 0;\n");
+    }
+    let keys = if uses_keys { "keys" } else { "_keys" };
+    let data = if uses_data { "data" } else { "_data" };
+
+    Ok(format!(
+        "\n#[derive(Debug, Clone, PartialEq)]\n\
+         pub struct {rust_name} {{\n\
+         {fields}\
+         }}\n\n\
+         impl {rust_name} {{\n\
+         \x20   /// Decodes the event from its keys, without the selector, and data.\n\
+         \x20   pub fn decode({keys}: &[Felt], {data}: &[Felt]) -> Result<Self, ContractBindingError> {{\n\
+         {offsets}\
+         \x20       Ok(Self {{\n\
+         {decoded}\
+         \x20       }})\n\
+         \x20   }}\n\
+         }}\n"
+    ))
+}
+
+fn generate_event_enum(
+    name: &str,
+    variants: &[EventField],
+    names: &HashMap<String, String>,
+) -> Result<String, BindgenError> {
+    let rust_name = &names[name];
+    let variant_type = |variant: &EventField| {
+        names
+            .get(&variant.r#type)
+            .ok_or_else(|| BindgenError::UnsupportedEventMember {
+                event: name.to_string(),
+                member: variant.name.clone(),
+                kind: format!("{:?}", variant.kind),
+            })
+    };
+
+    let mut out = format!("\n#[derive(Debug, Clone, PartialEq)]\npub enum {rust_name} {{\n");
+    for variant in variants {
+        out.push_str(&format!(
+            "    {}({}),\n",
+            variant.name,
+            variant_type(variant)?
+        ));
+    }
+    out.push_str(&format!(
+        "}}\n\n\
+         impl {rust_name} {{\n\
+         \x20   /// Decodes the event from its keys, starting with the variant selector, and data.\n\
+         \x20   pub fn decode(keys: &[Felt], data: &[Felt]) -> Result<Self, ContractBindingError> {{\n\
+         \x20       let selector = *keys.first().ok_or(ContractBindingError::MissingEventSelector)?;\n"
+    ));
+
+    // Nested variants are identified by their selector, flat ones are tried in order with the
+    // same keys.
+    for variant in variants {
+        if let EventFieldKind::Nested = variant.kind {
+            out.push_str(&format!(
+                "        if selector == starknet_keccak(b\"{0}\") {{\n\
+                 \x20           return Ok(Self::{0}({1}::decode(&keys[1..], data)?));\n\
+                 \x20       }}\n",
+                variant.name,
+                variant_type(variant)?
+            ));
+        }
+    }
+    for variant in variants {
+        if let EventFieldKind::Flat = variant.kind {
+            out.push_str(&format!(
+                "        if let Ok(event) = {1}::decode(keys, data) {{\n\
+                 \x20           return Ok(Self::{0}(event));\n\
+                 \x20       }}\n",
+                variant.name,
+                variant_type(variant)?
+            ));
+        }
+    }
+    out.push_str("        Err(ContractBindingError::UnknownEvent(selector))\n    }\n}\n");
+
+    Ok(out)
+}
+
+struct TypeResolver<'a> {
+    names: &'a HashMap<String, String>,
+    /// `cainome_cairo_serde` types used by the generated code.
+    imports: BTreeSet<&'static str>,
+}
+
+impl TypeResolver<'_> {
+    /// Rust type for a Cairo type path. The same type is used for `CairoSerde` and as its
+    /// `RustType`.
+    fn resolve(&mut self, cairo_type: &str) -> Result<String, BindgenError> {
+        let cairo_type = cairo_type.trim();
+        let unsupported = || BindgenError::UnsupportedType(cairo_type.to_string());
+
+        if let Some(elements) = cairo_type
+            .strip_prefix('(')
+            .and_then(|t| t.strip_suffix(')'))
+        {
+            let elements = split_top_level(elements)
+                .into_iter()
+                .filter(|element| !element.is_empty())
+                .map(|element| self.resolve(element))
+                .collect::<Result<Vec<_>, _>>()?;
+            return Ok(match elements.len() {
+                1 => format!("({},)", elements[0]),
+                _ => format!("({})", elements.join(", ")),
+            });
+        }
+
+        if let Some((base, args)) = cairo_type.split_once("::<") {
+            let args = args.strip_suffix('>').ok_or_else(unsupported)?;
+            let args = split_top_level(args)
+                .into_iter()
+                .map(|arg| self.resolve(arg))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            return match (base, args.as_slice()) {
+                ("core::array::Array" | "core::array::Span", [element]) => {
+                    Ok(format!("Vec<{element}>"))
+                }
+                ("core::option::Option", [value]) => Ok(format!("Option<{value}>")),
+                ("core::result::Result", [ok, err]) => Ok(format!("Result<{ok}, {err}>")),
+                // Serialized as the wrapped value.
+                ("core::zeroable::NonZero", [value]) => Ok(value.clone()),
+                _ => Err(unsupported()),
+            };
+        }
+
+        let (rust_type, import) = match cairo_type {
+            "core::felt252" => ("Felt", None),
+            "core::bool" => ("bool", None),
+            "core::integer::u8" => ("u8", None),
+            "core::integer::u16" => ("u16", None),
+            "core::integer::u32" => ("u32", None),
+            "core::integer::u64" | "core::integer::usize" => ("u64", None),
+            "core::integer::u128" => ("u128", None),
+            "core::integer::i8" => ("i8", None),
+            "core::integer::i16" => ("i16", None),
+            "core::integer::i32" => ("i32", None),
+            "core::integer::i64" => ("i64", None),
+            "core::integer::i128" => ("i128", None),
+            "core::integer::u256" => ("U256", Some("U256")),
+            "core::byte_array::ByteArray" => ("ByteArray", Some("ByteArray")),
+            "core::starknet::contract_address::ContractAddress" => {
+                ("ContractAddress", Some("ContractAddress"))
+            }
+            "core::starknet::class_hash::ClassHash" => ("ClassHash", Some("ClassHash")),
+            "core::starknet::eth_address::EthAddress" => ("EthAddress", Some("EthAddress")),
+            _ => {
+                return self.names.get(cairo_type).cloned().ok_or_else(unsupported);
+            }
+        };
+        if let Some(import) = import {
+            self.imports.insert(import);
+        }
+
+        Ok(rust_type.to_string())
+    }
+}
+
+/// Splits a comma separated list of types, ignoring commas nested in generics or tuples.
+fn split_top_level(list: &str) -> Vec<&str> {
+    let mut parts = vec![];
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in list.char_indices() {
+        match c {
+            '<' | '(' => depth += 1,
+            '>' | ')' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(list[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(list[start..].trim());
+    parts
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Write;
+    use std::process::{Command, Stdio};
+
+    use super::*;
+
+    fn resolve(cairo_type: &str) -> Result<String, BindgenError> {
+        let names = HashMap::from([("contracts::Point".to_string(), "Point".to_string())]);
+        TypeResolver {
+            names: &names,
+            imports: BTreeSet::new(),
+        }
+        .resolve(cairo_type)
+    }
+
+    #[test]
+    fn test_resolve_types() {
+        assert_eq!(resolve("core::felt252").unwrap(), "Felt");
+        assert_eq!(
+            resolve("core::array::Span::<core::integer::u256>").unwrap(),
+            "Vec<U256>"
+        );
+        assert_eq!(
+            resolve("(core::felt252, core::option::Option::<contracts::Point>)").unwrap(),
+            "(Felt, Option<Point>)"
+        );
+        assert_eq!(
+            resolve("core::result::Result::<core::bool, core::array::Array::<core::felt252>>")
+                .unwrap(),
+            "Result<bool, Vec<Felt>>"
+        );
+        assert_eq!(resolve("()").unwrap(), "()");
+        assert!(matches!(
+            resolve("contracts::Unknown"),
+            Err(BindgenError::UnsupportedType(_))
+        ));
+    }
+
+    #[test]
+    fn test_type_names() {
+        let abi: Vec<AbiEntry> = serde_json::from_value(serde_json::json!([
+            {
+                "type": "event",
+                "name": "openzeppelin_token::erc20::erc20::ERC20Component::Event",
+                "kind": "enum",
+                "variants": []
+            },
+            {
+                "type": "event",
+                "name": "contracts::paymaster::erc20::TestToken::Event",
+                "kind": "enum",
+                "variants": [{
+                    "name": "ERC20Event",
+                    "type": "openzeppelin_token::erc20::erc20::ERC20Component::Event",
+                    "kind": "flat"
+                }]
+            },
+            {
+                "type": "struct",
+                "name": "contracts::Point",
+                "members": [{ "name": "x", "type": "core::felt252" }]
+            }
+        ]))
+        .unwrap();
+        let mut items = Items::default();
+        collect_items(&abi, &mut items);
+        let names = type_names(&items);
+
+        assert_eq!(names["contracts::Point"], "Point");
+        assert_eq!(
+            names["contracts::paymaster::erc20::TestToken::Event"],
+            "TestTokenEvent"
+        );
+        assert_eq!(
+            names["openzeppelin_token::erc20::erc20::ERC20Component::Event"],
+            "ERC20ComponentEvent"
+        );
+        assert_eq!(
+            root_event_enum(&items),
+            Some("contracts::paymaster::erc20::TestToken::Event")
+        );
+    }
+
+    /// Formats `code` the way `contract-bindgen` does before writing it.
+    fn rustfmt(code: &str) -> String {
+        let mut child = Command::new("rustfmt")
+            .args(["--edition", "2021"])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .expect("Failed to run rustfmt");
+        child
+            .stdin
+            .take()
+            .unwrap()
+            .write_all(code.as_bytes())
+            .unwrap();
+        let output = child.wait_with_output().unwrap();
+        assert!(output.status.success(), "rustfmt failed");
+
+        String::from_utf8(output.stdout).unwrap()
+    }
+
+    /// Regenerates the bindings of a contract from the ABI snapshot `contract-bindgen` writes next
+    /// to them, and compares them with the checked-in ones.
+    fn assert_bindings_up_to_date(
+        contract_name: &str,
+        artifact_name: &str,
+        abi: &str,
+        bindings: &str,
+    ) {
+        let abi: Vec<AbiEntry> = serde_json::from_str(abi).unwrap();
+        let generated = Bindgen::new(contract_name, artifact_name, &abi)
+            .generate()
+            .unwrap();
+
+        assert_eq!(
+            rustfmt(&generated),
+            bindings,
+            "Bindings of {} differ from the generator output, regenerate them with contract-bindgen",
+            artifact_name
+        );
+    }
+
+    #[test]
+    fn test_hello_starknet_bindings() {
+        assert_bindings_up_to_date(
+            "HelloStarknet",
+            "contracts_sample_contract_3_HelloStarknet",
+            include_str!("bindings/abi/hello_starknet.json"),
+            include_str!("bindings/hello_starknet.rs"),
+        );
+    }
+
+    #[test]
+    fn test_test_token_bindings() {
+        assert_bindings_up_to_date(
+            "TestToken",
+            "TestToken",
+            include_str!("bindings/abi/test_token.json"),
+            include_str!("bindings/test_token.rs"),
+        );
+    }
+}
@@ -0,0 +1,141 @@
+//! Bindings of `contracts_sample_contract_3_HelloStarknet`, generated by `contract-bindgen` from its Sierra ABI. Regenerate
+//! instead of editing by hand.
+#![allow(non_snake_case, clippy::ptr_arg, clippy::too_many_arguments)]
+
+use crate::utils::v7::accounts::account::starknet_keccak;
+use crate::utils::v7::accounts::account::ConnectedAccount;
+use crate::utils::v7::accounts::account::ExecutionV3;
+use crate::utils::v7::accounts::call::Call;
+use crate::utils::v7::contract::bindings::decode_member;
+use crate::utils::v7::contract::bindings::receipt_events;
+use crate::utils::v7::contract::bindings::ContractBindingError;
+use crate::utils::v7::providers::provider::Provider;
+use cainome_cairo_serde::CairoSerde;
+use starknet_types_core::felt::Felt;
+use starknet_types_rpc::{FunctionCall, TxnReceipt};
+
+#[derive(Debug, Clone)]
+pub struct HelloStarknet<A> {
+    pub address: Felt,
+    pub account: A,
+}
+
+impl<A> HelloStarknet<A>
+where
+    A: ConnectedAccount + Sync,
+{
+    pub fn new(address: Felt, account: A) -> Self {
+        Self { address, account }
+    }
+
+    pub fn increase_balance_call(&self, amount: &Felt) -> Call {
+        let mut calldata = vec![];
+        calldata.extend(<Felt>::cairo_serialize(amount));
+        Call {
+            to: self.address,
+            selector: starknet_keccak(b"increase_balance"),
+            calldata,
+        }
+    }
+
+    pub fn increase_balance(&self, amount: &Felt) -> ExecutionV3<'_, A> {
+        self.account
+            .execute_v3(vec![self.increase_balance_call(amount)])
+    }
+
+    pub fn decrease_balance_call(&self, amount: &Felt) -> Call {
+        let mut calldata = vec![];
+        calldata.extend(<Felt>::cairo_serialize(amount));
+        Call {
+            to: self.address,
+            selector: starknet_keccak(b"decrease_balance"),
+            calldata,
+        }
+    }
+
+    pub fn decrease_balance(&self, amount: &Felt) -> ExecutionV3<'_, A> {
+        self.account
+            .execute_v3(vec![self.decrease_balance_call(amount)])
+    }
+
+    pub async fn get_balance(&self) -> Result<Felt, ContractBindingError> {
+        let calldata = vec![];
+        let result = self
+            .account
+            .provider()
+            .call(
+                FunctionCall {
+                    contract_address: self.address,
+                    entry_point_selector: starknet_keccak(b"get_balance"),
+                    calldata,
+                },
+                self.account.block_id(),
+            )
+            .await?;
+        Ok(<Felt>::cairo_deserialize(&result, 0)?)
+    }
+
+    pub async fn get_extra_data(&self) -> Result<Felt, ContractBindingError> {
+        let calldata = vec![];
+        let result = self
+            .account
+            .provider()
+            .call(
+                FunctionCall {
+                    contract_address: self.address,
+                    entry_point_selector: starknet_keccak(b"get_extra_data"),
+                    calldata,
+                },
+                self.account.block_id(),
+            )
+            .await?;
+        Ok(<Felt>::cairo_deserialize(&result, 0)?)
+    }
+
+    /// Events emitted by this contract in `receipt`.
+    pub fn events(&self, receipt: &TxnReceipt<Felt>) -> Result<Vec<Event>, ContractBindingError> {
+        receipt_events(receipt)
+            .iter()
+            .filter(|event| event.from_address == self.address)
+            .map(|event| Event::decode(&event.keys, &event.data))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DepositFromL1 {
+    pub user: Felt,
+    pub amount: Felt,
+}
+
+impl DepositFromL1 {
+    /// Decodes the event from its keys, without the selector, and data.
+    pub fn decode(keys: &[Felt], _data: &[Felt]) -> Result<Self, ContractBindingError> {
+        let mut keys_offset = 0;
+        Ok(Self {
+            user: decode_member::<Felt>(keys, &mut keys_offset)?,
+            amount: decode_member::<Felt>(keys, &mut keys_offset)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    DepositFromL1(DepositFromL1),
+}
+
+impl Event {
+    /// Decodes the event from its keys, starting with the variant selector, and data.
+    pub fn decode(keys: &[Felt], data: &[Felt]) -> Result<Self, ContractBindingError> {
+        let selector = *keys
+            .first()
+            .ok_or(ContractBindingError::MissingEventSelector)?;
+        if selector == starknet_keccak(b"DepositFromL1") {
+            return Ok(Self::DepositFromL1(DepositFromL1::decode(
+                &keys[1..],
+                data,
+            )?));
+        }
+        Err(ContractBindingError::UnknownEvent(selector))
+    }
+}
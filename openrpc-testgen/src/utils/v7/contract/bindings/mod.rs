@@ -0,0 +1,44 @@
+//! Typed bindings of the contracts in `contracts/`, generated by `contract-bindgen` with
+//! [Bindgen](super::bindgen::Bindgen), and the runtime support they share.
+
+pub mod hello_starknet;
+pub mod test_token;
+
+use cainome_cairo_serde::CairoSerde;
+use starknet_types_core::felt::Felt;
+use starknet_types_rpc::{Event, TxnReceipt};
+
+use crate::utils::v7::providers::provider::ProviderError;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ContractBindingError {
+    #[error(transparent)]
+    Provider(#[from] ProviderError),
+    #[error("Failed to decode cairo value: {0:?}")]
+    CairoSerde(#[from] cainome_cairo_serde::Error),
+    #[error("Event has no selector key")]
+    MissingEventSelector,
+    #[error("Unknown event selector {0:#x}")]
+    UnknownEvent(Felt),
+}
+
+/// Decodes a `T` from `felts` at `offset` and moves `offset` past it.
+pub fn decode_member<T: CairoSerde>(
+    felts: &[Felt],
+    offset: &mut usize,
+) -> Result<T::RustType, ContractBindingError> {
+    let value = T::cairo_deserialize(felts, *offset)?;
+    *offset += T::cairo_serialized_size(&value);
+    Ok(value)
+}
+
+/// Events of a receipt, whatever the transaction type.
+pub fn receipt_events(receipt: &TxnReceipt<Felt>) -> &[Event<Felt>] {
+    match receipt {
+        TxnReceipt::Declare(receipt) => &receipt.common_receipt_properties.events,
+        TxnReceipt::Deploy(receipt) => &receipt.common_receipt_properties.events,
+        TxnReceipt::DeployAccount(receipt) => &receipt.common_receipt_properties.events,
+        TxnReceipt::Invoke(receipt) => &receipt.common_receipt_properties.events,
+        TxnReceipt::L1Handler(receipt) => &receipt.common_receipt_properties.events,
+    }
+}
@@ -0,0 +1,392 @@
+//! Bindings of `TestToken`, generated by `contract-bindgen` from its Sierra ABI. Regenerate
+//! instead of editing by hand.
+#![allow(non_snake_case, clippy::ptr_arg, clippy::too_many_arguments)]
+
+use crate::utils::v7::accounts::account::starknet_keccak;
+use crate::utils::v7::accounts::account::ConnectedAccount;
+use crate::utils::v7::accounts::account::ExecutionV3;
+use crate::utils::v7::accounts::call::Call;
+use crate::utils::v7::contract::bindings::decode_member;
+use crate::utils::v7::contract::bindings::receipt_events;
+use crate::utils::v7::contract::bindings::ContractBindingError;
+use crate::utils::v7::providers::provider::Provider;
+use cainome_cairo_serde::{ByteArray, CairoSerde, ContractAddress, U256};
+use starknet_types_core::felt::Felt;
+use starknet_types_rpc::{FunctionCall, TxnReceipt};
+
+#[derive(Debug, Clone)]
+pub struct TestToken<A> {
+    pub address: Felt,
+    pub account: A,
+}
+
+impl<A> TestToken<A>
+where
+    A: ConnectedAccount + Sync,
+{
+    pub fn new(address: Felt, account: A) -> Self {
+        Self { address, account }
+    }
+
+    pub async fn total_supply(&self) -> Result<U256, ContractBindingError> {
+        let calldata = vec![];
+        let result = self
+            .account
+            .provider()
+            .call(
+                FunctionCall {
+                    contract_address: self.address,
+                    entry_point_selector: starknet_keccak(b"total_supply"),
+                    calldata,
+                },
+                self.account.block_id(),
+            )
+            .await?;
+        Ok(<U256>::cairo_deserialize(&result, 0)?)
+    }
+
+    pub async fn balance_of(
+        &self,
+        account: &ContractAddress,
+    ) -> Result<U256, ContractBindingError> {
+        let mut calldata = vec![];
+        calldata.extend(<ContractAddress>::cairo_serialize(account));
+        let result = self
+            .account
+            .provider()
+            .call(
+                FunctionCall {
+                    contract_address: self.address,
+                    entry_point_selector: starknet_keccak(b"balance_of"),
+                    calldata,
+                },
+                self.account.block_id(),
+            )
+            .await?;
+        Ok(<U256>::cairo_deserialize(&result, 0)?)
+    }
+
+    pub async fn allowance(
+        &self,
+        owner: &ContractAddress,
+        spender: &ContractAddress,
+    ) -> Result<U256, ContractBindingError> {
+        let mut calldata = vec![];
+        calldata.extend(<ContractAddress>::cairo_serialize(owner));
+        calldata.extend(<ContractAddress>::cairo_serialize(spender));
+        let result = self
+            .account
+            .provider()
+            .call(
+                FunctionCall {
+                    contract_address: self.address,
+                    entry_point_selector: starknet_keccak(b"allowance"),
+                    calldata,
+                },
+                self.account.block_id(),
+            )
+            .await?;
+        Ok(<U256>::cairo_deserialize(&result, 0)?)
+    }
+
+    pub fn transfer_call(&self, recipient: &ContractAddress, amount: &U256) -> Call {
+        let mut calldata = vec![];
+        calldata.extend(<ContractAddress>::cairo_serialize(recipient));
+        calldata.extend(<U256>::cairo_serialize(amount));
+        Call {
+            to: self.address,
+            selector: starknet_keccak(b"transfer"),
+            calldata,
+        }
+    }
+
+    pub fn transfer(&self, recipient: &ContractAddress, amount: &U256) -> ExecutionV3<'_, A> {
+        self.account
+            .execute_v3(vec![self.transfer_call(recipient, amount)])
+    }
+
+    pub fn transfer_from_call(
+        &self,
+        sender: &ContractAddress,
+        recipient: &ContractAddress,
+        amount: &U256,
+    ) -> Call {
+        let mut calldata = vec![];
+        calldata.extend(<ContractAddress>::cairo_serialize(sender));
+        calldata.extend(<ContractAddress>::cairo_serialize(recipient));
+        calldata.extend(<U256>::cairo_serialize(amount));
+        Call {
+            to: self.address,
+            selector: starknet_keccak(b"transfer_from"),
+            calldata,
+        }
+    }
+
+    pub fn transfer_from(
+        &self,
+        sender: &ContractAddress,
+        recipient: &ContractAddress,
+        amount: &U256,
+    ) -> ExecutionV3<'_, A> {
+        self.account
+            .execute_v3(vec![self.transfer_from_call(sender, recipient, amount)])
+    }
+
+    pub fn approve_call(&self, spender: &ContractAddress, amount: &U256) -> Call {
+        let mut calldata = vec![];
+        calldata.extend(<ContractAddress>::cairo_serialize(spender));
+        calldata.extend(<U256>::cairo_serialize(amount));
+        Call {
+            to: self.address,
+            selector: starknet_keccak(b"approve"),
+            calldata,
+        }
+    }
+
+    pub fn approve(&self, spender: &ContractAddress, amount: &U256) -> ExecutionV3<'_, A> {
+        self.account
+            .execute_v3(vec![self.approve_call(spender, amount)])
+    }
+
+    pub async fn name(&self) -> Result<ByteArray, ContractBindingError> {
+        let calldata = vec![];
+        let result = self
+            .account
+            .provider()
+            .call(
+                FunctionCall {
+                    contract_address: self.address,
+                    entry_point_selector: starknet_keccak(b"name"),
+                    calldata,
+                },
+                self.account.block_id(),
+            )
+            .await?;
+        Ok(<ByteArray>::cairo_deserialize(&result, 0)?)
+    }
+
+    pub async fn symbol(&self) -> Result<ByteArray, ContractBindingError> {
+        let calldata = vec![];
+        let result = self
+            .account
+            .provider()
+            .call(
+                FunctionCall {
+                    contract_address: self.address,
+                    entry_point_selector: starknet_keccak(b"symbol"),
+                    calldata,
+                },
+                self.account.block_id(),
+            )
+            .await?;
+        Ok(<ByteArray>::cairo_deserialize(&result, 0)?)
+    }
+
+    pub async fn decimals(&self) -> Result<u8, ContractBindingError> {
+        let calldata = vec![];
+        let result = self
+            .account
+            .provider()
+            .call(
+                FunctionCall {
+                    contract_address: self.address,
+                    entry_point_selector: starknet_keccak(b"decimals"),
+                    calldata,
+                },
+                self.account.block_id(),
+            )
+            .await?;
+        Ok(<u8>::cairo_deserialize(&result, 0)?)
+    }
+
+    pub async fn totalSupply(&self) -> Result<U256, ContractBindingError> {
+        let calldata = vec![];
+        let result = self
+            .account
+            .provider()
+            .call(
+                FunctionCall {
+                    contract_address: self.address,
+                    entry_point_selector: starknet_keccak(b"totalSupply"),
+                    calldata,
+                },
+                self.account.block_id(),
+            )
+            .await?;
+        Ok(<U256>::cairo_deserialize(&result, 0)?)
+    }
+
+    pub async fn balanceOf(&self, account: &ContractAddress) -> Result<U256, ContractBindingError> {
+        let mut calldata = vec![];
+        calldata.extend(<ContractAddress>::cairo_serialize(account));
+        let result = self
+            .account
+            .provider()
+            .call(
+                FunctionCall {
+                    contract_address: self.address,
+                    entry_point_selector: starknet_keccak(b"balanceOf"),
+                    calldata,
+                },
+                self.account.block_id(),
+            )
+            .await?;
+        Ok(<U256>::cairo_deserialize(&result, 0)?)
+    }
+
+    pub fn transferFrom_call(
+        &self,
+        sender: &ContractAddress,
+        recipient: &ContractAddress,
+        amount: &U256,
+    ) -> Call {
+        let mut calldata = vec![];
+        calldata.extend(<ContractAddress>::cairo_serialize(sender));
+        calldata.extend(<ContractAddress>::cairo_serialize(recipient));
+        calldata.extend(<U256>::cairo_serialize(amount));
+        Call {
+            to: self.address,
+            selector: starknet_keccak(b"transferFrom"),
+            calldata,
+        }
+    }
+
+    pub fn transferFrom(
+        &self,
+        sender: &ContractAddress,
+        recipient: &ContractAddress,
+        amount: &U256,
+    ) -> ExecutionV3<'_, A> {
+        self.account
+            .execute_v3(vec![self.transferFrom_call(sender, recipient, amount)])
+    }
+
+    pub fn burn_call(&self, account: &ContractAddress, amount: &U256) -> Call {
+        let mut calldata = vec![];
+        calldata.extend(<ContractAddress>::cairo_serialize(account));
+        calldata.extend(<U256>::cairo_serialize(amount));
+        Call {
+            to: self.address,
+            selector: starknet_keccak(b"burn"),
+            calldata,
+        }
+    }
+
+    pub fn burn(&self, account: &ContractAddress, amount: &U256) -> ExecutionV3<'_, A> {
+        self.account
+            .execute_v3(vec![self.burn_call(account, amount)])
+    }
+
+    pub fn mint_call(&self, recipient: &ContractAddress, amount: &U256) -> Call {
+        let mut calldata = vec![];
+        calldata.extend(<ContractAddress>::cairo_serialize(recipient));
+        calldata.extend(<U256>::cairo_serialize(amount));
+        Call {
+            to: self.address,
+            selector: starknet_keccak(b"mint"),
+            calldata,
+        }
+    }
+
+    pub fn mint(&self, recipient: &ContractAddress, amount: &U256) -> ExecutionV3<'_, A> {
+        self.account
+            .execute_v3(vec![self.mint_call(recipient, amount)])
+    }
+
+    /// Events emitted by this contract in `receipt`.
+    pub fn events(
+        &self,
+        receipt: &TxnReceipt<Felt>,
+    ) -> Result<Vec<TestTokenEvent>, ContractBindingError> {
+        receipt_events(receipt)
+            .iter()
+            .filter(|event| event.from_address == self.address)
+            .map(|event| TestTokenEvent::decode(&event.keys, &event.data))
+            .collect()
+    }
+}
+
+/// Calldata of the constructor of [TestToken], to be passed to the deployment.
+pub fn constructor_calldata() -> Vec<Felt> {
+    vec![]
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Transfer {
+    pub from: ContractAddress,
+    pub to: ContractAddress,
+    pub value: U256,
+}
+
+impl Transfer {
+    /// Decodes the event from its keys, without the selector, and data.
+    pub fn decode(keys: &[Felt], data: &[Felt]) -> Result<Self, ContractBindingError> {
+        let mut keys_offset = 0;
+        let mut data_offset = 0;
+        Ok(Self {
+            from: decode_member::<ContractAddress>(keys, &mut keys_offset)?,
+            to: decode_member::<ContractAddress>(keys, &mut keys_offset)?,
+            value: decode_member::<U256>(data, &mut data_offset)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Approval {
+    pub owner: ContractAddress,
+    pub spender: ContractAddress,
+    pub value: U256,
+}
+
+impl Approval {
+    /// Decodes the event from its keys, without the selector, and data.
+    pub fn decode(keys: &[Felt], data: &[Felt]) -> Result<Self, ContractBindingError> {
+        let mut keys_offset = 0;
+        let mut data_offset = 0;
+        Ok(Self {
+            owner: decode_member::<ContractAddress>(keys, &mut keys_offset)?,
+            spender: decode_member::<ContractAddress>(keys, &mut keys_offset)?,
+            value: decode_member::<U256>(data, &mut data_offset)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ERC20ComponentEvent {
+    Transfer(Transfer),
+    Approval(Approval),
+}
+
+impl ERC20ComponentEvent {
+    /// Decodes the event from its keys, starting with the variant selector, and data.
+    pub fn decode(keys: &[Felt], data: &[Felt]) -> Result<Self, ContractBindingError> {
+        let selector = *keys
+            .first()
+            .ok_or(ContractBindingError::MissingEventSelector)?;
+        if selector == starknet_keccak(b"Transfer") {
+            return Ok(Self::Transfer(Transfer::decode(&keys[1..], data)?));
+        }
+        if selector == starknet_keccak(b"Approval") {
+            return Ok(Self::Approval(Approval::decode(&keys[1..], data)?));
+        }
+        Err(ContractBindingError::UnknownEvent(selector))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TestTokenEvent {
+    ERC20Event(ERC20ComponentEvent),
+}
+
+impl TestTokenEvent {
+    /// Decodes the event from its keys, starting with the variant selector, and data.
+    pub fn decode(keys: &[Felt], data: &[Felt]) -> Result<Self, ContractBindingError> {
+        let selector = *keys
+            .first()
+            .ok_or(ContractBindingError::MissingEventSelector)?;
+        if let Ok(event) = ERC20ComponentEvent::decode(keys, data) {
+            return Ok(Self::ERC20Event(event));
+        }
+        Err(ContractBindingError::UnknownEvent(selector))
+    }
+}
@@ -1,5 +1,6 @@
 use std::path::PathBuf;
 
+use starknet_types_core::felt::Felt;
 use starknet_types_rpc::{
     v0_7_1::{BlockId, BlockTag},
     PriceUnit,
@@ -9,22 +10,231 @@ use url::Url;
 
 use crate::utils::v7::{
     accounts::{
+        account::{Account, ConnectedAccount},
+        call::Call,
         creation::{
             create::{create_account, AccountType},
             helpers::get_chain_id,
-            structs::MintRequest2,
         },
         deployment::{
             deploy::{deploy_account, DeployAccountVersion},
             structs::{ValidatedWaitParams, WaitForTx},
         },
         single_owner::{ExecutionEncoding, SingleOwnerAccount},
-        utils::mint::mint,
     },
-    endpoints::{declare_contract::declare_contract, deploy_contract::deploy_contract},
-    providers::jsonrpc::{HttpTransport, JsonRpcClient},
+    contract::{
+        factory::ContractFactory,
+        helpers::{get_udc_deployed_address, UdcUniqueness},
+        registry::ContractRegistry,
+        HashAndFlatten,
+    },
+    devnet::DevnetClient,
+    endpoints::{
+        declare_contract::declare_contract,
+        deploy_contract::deploy_contract,
+        errors::OpenRpcTestGenError,
+        utils::{get_selector_from_name, wait_for_sent_transaction},
+    },
+    providers::{
+        jsonrpc::{HttpTransport, JsonRpcClient, StarknetError},
+        provider::{Provider, ProviderError},
+    },
     signers::local_wallet::LocalWallet,
 };
+
+/// Outcome of [ensure_declared].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Declaration {
+    /// The class was declared by the given transaction.
+    Declared {
+        class_hash: Felt,
+        transaction_hash: Felt,
+    },
+    /// The class was already declared on the node.
+    AlreadyDeclared { class_hash: Felt },
+}
+
+impl Declaration {
+    pub fn class_hash(&self) -> Felt {
+        match self {
+            Self::Declared { class_hash, .. } | Self::AlreadyDeclared { class_hash } => *class_hash,
+        }
+    }
+
+    /// Hash of the declare transaction, `None` if the class was already declared.
+    pub fn transaction_hash(&self) -> Option<Felt> {
+        match self {
+            Self::Declared {
+                transaction_hash, ..
+            } => Some(*transaction_hash),
+            Self::AlreadyDeclared { .. } => None,
+        }
+    }
+}
+
+/// Outcome of [ensure_deployed].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Deployment {
+    /// The contract was deployed by the given transaction.
+    Deployed {
+        contract_address: Felt,
+        transaction_hash: Felt,
+    },
+    /// A contract was already deployed at the address.
+    AlreadyDeployed { contract_address: Felt },
+}
+
+impl Deployment {
+    pub fn contract_address(&self) -> Felt {
+        match self {
+            Self::Deployed {
+                contract_address, ..
+            }
+            | Self::AlreadyDeployed { contract_address } => *contract_address,
+        }
+    }
+
+    /// Hash of the deployment transaction, `None` if the contract was already deployed.
+    pub fn transaction_hash(&self) -> Option<Felt> {
+        match self {
+            Self::Deployed {
+                transaction_hash, ..
+            } => Some(*transaction_hash),
+            Self::AlreadyDeployed { .. } => None,
+        }
+    }
+}
+
+/// Whether `getClass` serves the class hash of the contract from the [ContractRegistry]. A class
+/// can only be declared once, so tests of declare transactions skip the classes declared by
+/// earlier runs against the same node.
+pub async fn is_declared(
+    account: &SingleOwnerAccount<JsonRpcClient<HttpTransport>, LocalWallet>,
+    artifact: &str,
+) -> Result<bool, OpenRpcTestGenError> {
+    let class_hash = ContractRegistry::global()
+        .sierra_class(artifact)?
+        .class_hash()?;
+
+    match account
+        .provider()
+        .get_class(BlockId::Tag(BlockTag::Pending), class_hash)
+        .await
+    {
+        Ok(_) => Ok(true),
+        Err(ProviderError::StarknetError(StarknetError::ClassHashNotFound)) => Ok(false),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Declares the contract from the [ContractRegistry], e.g. `ExecutableAccount`, unless it
+/// [is_declared] already.
+pub async fn ensure_declared(
+    account: &SingleOwnerAccount<JsonRpcClient<HttpTransport>, LocalWallet>,
+    artifact: &str,
+) -> Result<Declaration, OpenRpcTestGenError> {
+    let registry = ContractRegistry::global();
+
+    if is_declared(account, artifact).await? {
+        let class_hash = registry.sierra_class(artifact)?.class_hash()?;
+        return Ok(Declaration::AlreadyDeclared { class_hash });
+    }
+
+    let (flattened_sierra_class, compiled_class_hash) = registry.get(artifact)?;
+    let result = account
+        .declare_v3(flattened_sierra_class, compiled_class_hash)
+        .send()
+        .await?;
+    wait_for_sent_transaction(result.transaction_hash, account).await?;
+
+    Ok(Declaration::Declared {
+        class_hash: result.class_hash,
+        transaction_hash: result.transaction_hash,
+    })
+}
+
+/// Deploys the class through the UDC without uniqueness, so that the address only depends on
+/// the arguments, unless a contract of the class is already deployed there. A contract of another
+/// class at the address is an error.
+pub async fn ensure_deployed(
+    account: &SingleOwnerAccount<JsonRpcClient<HttpTransport>, LocalWallet>,
+    udc_address: Felt,
+    class_hash: Felt,
+    salt: Felt,
+    constructor_calldata: Vec<Felt>,
+) -> Result<Deployment, OpenRpcTestGenError> {
+    let contract_address = get_udc_deployed_address(
+        salt,
+        class_hash,
+        &UdcUniqueness::NotUnique,
+        &constructor_calldata,
+    );
+
+    match account
+        .provider()
+        .get_class_hash_at(BlockId::Tag(BlockTag::Pending), contract_address)
+        .await
+    {
+        Ok(deployed_class_hash) if deployed_class_hash == class_hash => {
+            return Ok(Deployment::AlreadyDeployed { contract_address })
+        }
+        Ok(deployed_class_hash) => {
+            return Err(OpenRpcTestGenError::ClassHashMismatch {
+                contract_address,
+                expected: class_hash,
+                actual: deployed_class_hash,
+            })
+        }
+        Err(ProviderError::StarknetError(StarknetError::ContractNotFound)) => {}
+        Err(e) => return Err(e.into()),
+    }
+
+    let factory = ContractFactory::new_with_udc(class_hash, account.clone(), udc_address);
+    let result = factory
+        .deploy_v3(constructor_calldata, salt, false)
+        .send()
+        .await?;
+    wait_for_sent_transaction(result.transaction_hash, account).await?;
+
+    Ok(Deployment::Deployed {
+        contract_address,
+        transaction_hash: result.transaction_hash,
+    })
+}
+
+/// `Counter` contract shared by the suites, see [ensure_counter].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Counter {
+    pub class_hash: Felt,
+    pub address: Felt,
+}
+
+/// Declares the `Counter` contract and deploys it from salt zero, unless that was done already,
+/// so that the suites increase the same contract.
+pub async fn ensure_counter(
+    account: &SingleOwnerAccount<JsonRpcClient<HttpTransport>, LocalWallet>,
+    udc_address: Felt,
+) -> Result<Counter, OpenRpcTestGenError> {
+    let class_hash = ensure_declared(account, "Counter").await?.class_hash();
+    let address = ensure_deployed(account, udc_address, class_hash, Felt::ZERO, vec![])
+        .await?
+        .contract_address();
+
+    Ok(Counter {
+        class_hash,
+        address,
+    })
+}
+
+/// Call increasing the `Counter` at `counter_address` by `amount`.
+pub fn increase_call(counter_address: Felt, amount: Felt) -> Result<Call, OpenRpcTestGenError> {
+    Ok(Call {
+        to: counter_address,
+        selector: get_selector_from_name("increase")?,
+        calldata: vec![amount],
+    })
+}
+
 #[allow(dead_code)]
 pub async fn decalare_and_deploy(
     url: Url,
@@ -40,15 +250,9 @@ pub async fn decalare_and_deploy(
             }
         };
 
-    match mint(
-        url.clone(),
-        &MintRequest2 {
-            amount: u128::MAX,
-            address: create_acc_data.address,
-            unit: PriceUnit::Fri,
-        },
-    )
-    .await
+    match DevnetClient::devnet(url.clone())
+        .mint(create_acc_data.address, u128::MAX, PriceUnit::Fri)
+        .await
     {
         Ok(_) => {}
         Err(e) => {
@@ -8,7 +8,7 @@ use crate::utils::v7::accounts::{
     call::Call,
 };
 
-use super::helpers::{get_udc_deployed_address, UdcUniqueSettings, UdcUniqueness};
+use super::helpers::{get_udc_deployed_address, UdcUniqueSettings, UdcUniqueness, UdcVersion};
 
 // use crate::{
 //     account::{call::Call, Account, AccountError, ConnectedAccount, ExecutionV1, ExecutionV3},
@@ -24,17 +24,18 @@ const UDC_ADDRESS: Felt = Felt::from_raw([
     15685625669053253235,
     15144800532519055890,
 ]);
-/// Selector for entrypoint `deployContract`.
-const SELECTOR_DEPLOYCONTRACT: Felt = Felt::from_raw([
-    469988280392664069,
-    1439621915307882061,
-    1265649739554438882,
-    18249998464715511309,
+/// The Cairo 1 UDC address: 0x02ceed65a4bd731034c01113685c831b01c15d7d432f71afb1cf1634b53a2125.
+pub const UDC_V1_ADDRESS: Felt = Felt::from_raw([
+    505287751652144584,
+    6849092491656713429,
+    14735209673864872887,
+    4208494925911946768,
 ]);
 
 pub struct ContractFactory<A> {
     class_hash: Felt,
     udc_address: Felt,
+    udc_version: UdcVersion,
     account: A,
 }
 
@@ -79,9 +80,23 @@ impl<A> ContractFactory<A> {
         Self {
             class_hash,
             udc_address,
+            udc_version: UdcVersion::V0,
             account,
         }
     }
+
+    /// Deploys through the Cairo 1 UDC at its default address.
+    pub fn new_v1(class_hash: Felt, account: A) -> Self {
+        Self::new_with_udc(class_hash, account, UDC_V1_ADDRESS).udc_version(UdcVersion::V1)
+    }
+
+    /// Sets the version of the UDC at the factory address, [UdcVersion::V0] by default.
+    pub fn udc_version(self, udc_version: UdcVersion) -> Self {
+        Self {
+            udc_version,
+            ..self
+        }
+    }
 }
 #[allow(dead_code)]
 impl<A> ContractFactory<A>
@@ -208,6 +223,7 @@ where
                 UdcUniqueness::Unique(UdcUniqueSettings {
                     deployer_address: self.factory.account.address(),
                     udc_contract_address: self.factory.udc_address,
+                    udc_version: self.factory.udc_version,
                 })
             } else {
                 UdcUniqueness::NotUnique
@@ -230,6 +246,7 @@ where
                 UdcUniqueness::Unique(UdcUniqueSettings {
                     deployer_address: self.factory.account.address(),
                     udc_contract_address: self.factory.udc_address,
+                    udc_version: self.factory.udc_version,
                 })
             } else {
                 UdcUniqueness::NotUnique
@@ -298,7 +315,7 @@ impl<'f, A> From<&DeploymentV1<'f, A>> for ExecutionV1<'f, A> {
         let mut calldata = vec![
             value.factory.class_hash,
             value.salt,
-            if value.unique { Felt::ONE } else { Felt::ZERO },
+            value.factory.udc_version.uniqueness_flag(value.unique),
             value.constructor_calldata.len().into(),
         ];
         calldata.extend_from_slice(&value.constructor_calldata);
@@ -306,7 +323,7 @@ impl<'f, A> From<&DeploymentV1<'f, A>> for ExecutionV1<'f, A> {
         let execution = Self::new(
             vec![Call {
                 to: value.factory.udc_address,
-                selector: SELECTOR_DEPLOYCONTRACT,
+                selector: value.factory.udc_version.deploy_selector(),
                 calldata,
             }],
             &value.factory.account,
@@ -333,7 +350,7 @@ impl<'f, A> From<&DeploymentV3<'f, A>> for ExecutionV3<'f, A> {
         let mut calldata = vec![
             value.factory.class_hash,
             value.salt,
-            if value.unique { Felt::ONE } else { Felt::ZERO },
+            value.factory.udc_version.uniqueness_flag(value.unique),
             value.constructor_calldata.len().into(),
         ];
         calldata.extend_from_slice(&value.constructor_calldata);
@@ -341,7 +358,7 @@ impl<'f, A> From<&DeploymentV3<'f, A>> for ExecutionV3<'f, A> {
         let execution = Self::new(
             vec![Call {
                 to: value.factory.udc_address,
-                selector: SELECTOR_DEPLOYCONTRACT,
+                selector: value.factory.udc_version.deploy_selector(),
                 calldata,
             }],
             &value.factory.account,
@@ -1,8 +1,24 @@
 use starknet_types_core::felt::Felt;
-use starknet_types_core::hash::{Pedersen, StarkHash};
+use starknet_types_core::hash::{Pedersen, Poseidon, StarkHash};
 
 use crate::utils::v7::accounts::deployment::helpers::get_contract_address;
 
+/// Selector for entrypoint `deployContract` of the Cairo 0 UDC.
+const SELECTOR_DEPLOYCONTRACT: Felt = Felt::from_raw([
+    469988280392664069,
+    1439621915307882061,
+    1265649739554438882,
+    18249998464715511309,
+]);
+
+/// Selector for entrypoint `deploy_contract` of the Cairo 1 UDC.
+const SELECTOR_DEPLOY_CONTRACT: Felt = Felt::from_raw([
+    469491241087273759,
+    6932863530730442843,
+    15026782010309712029,
+    10702208904235627055,
+]);
+
 #[allow(dead_code)]
 /// Computes the target contract address for deployments through the Universal Deploy Contract.
 pub fn get_udc_deployed_address(
@@ -16,7 +32,9 @@ pub fn get_udc_deployed_address(
             get_contract_address(salt, class_hash, constructor_calldata, Felt::ZERO)
         }
         UdcUniqueness::Unique(settings) => {
-            let unique_salt = Pedersen::hash(&settings.deployer_address, &salt);
+            let unique_salt = settings
+                .udc_version
+                .unique_salt(settings.deployer_address, salt);
             get_contract_address(
                 unique_salt,
                 class_hash,
@@ -39,4 +57,103 @@ pub enum UdcUniqueness {
 pub struct UdcUniqueSettings {
     pub deployer_address: Felt,
     pub udc_contract_address: Felt,
+    pub udc_version: UdcVersion,
+}
+
+/// Version of the Universal Deployer Contract.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum UdcVersion {
+    /// Cairo 0 UDC, deploying with `deployContract(classHash, salt, unique, calldata)`.
+    #[default]
+    V0,
+    /// Cairo 1 UDC of OpenZeppelin, deploying with
+    /// `deploy_contract(class_hash, salt, not_from_zero, calldata)`.
+    V1,
+}
+
+impl UdcVersion {
+    pub fn deploy_selector(&self) -> Felt {
+        match self {
+            Self::V0 => SELECTOR_DEPLOYCONTRACT,
+            Self::V1 => SELECTOR_DEPLOY_CONTRACT,
+        }
+    }
+
+    /// Calldata flag of the deployment, also emitted in the `ContractDeployed` event: `unique`
+    /// for [UdcVersion::V0] and `not_from_zero` for [UdcVersion::V1], set for unique deployments
+    /// by both versions.
+    pub fn uniqueness_flag(&self, unique: bool) -> Felt {
+        Felt::from(unique)
+    }
+
+    /// Salt passed to `deploy_syscall` by the UDC for unique deployments, mixing in the address
+    /// of the account calling the UDC.
+    pub fn unique_salt(&self, deployer_address: Felt, salt: Felt) -> Felt {
+        match self {
+            Self::V0 => Pedersen::hash(&deployer_address, &salt),
+            Self::V1 => Poseidon::hash_array(&[deployer_address, salt]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::utils::v7::endpoints::utils::get_selector_from_name;
+
+    #[test]
+    fn test_deploy_selectors() {
+        assert_eq!(
+            UdcVersion::V0.deploy_selector(),
+            get_selector_from_name("deployContract").unwrap()
+        );
+        assert_eq!(
+            UdcVersion::V1.deploy_selector(),
+            get_selector_from_name("deploy_contract").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_uniqueness_flag_set_for_unique_deployments() {
+        for udc_version in [UdcVersion::V0, UdcVersion::V1] {
+            assert_eq!(udc_version.uniqueness_flag(true), Felt::ONE);
+            assert_eq!(udc_version.uniqueness_flag(false), Felt::ZERO);
+        }
+    }
+
+    #[test]
+    fn test_not_unique_address_ignores_version() {
+        let calldata = [Felt::from(7_u8)];
+        let address = get_udc_deployed_address(
+            Felt::from(42_u8),
+            Felt::from(0x1234_u64),
+            &UdcUniqueness::NotUnique,
+            &calldata,
+        );
+
+        assert_eq!(
+            address,
+            get_contract_address(
+                Felt::from(42_u8),
+                Felt::from(0x1234_u64),
+                &calldata,
+                Felt::ZERO
+            )
+        );
+    }
+
+    #[test]
+    fn test_unique_salt_depends_on_version() {
+        let deployer_address = Felt::from(0xabc_u64);
+        let salt = Felt::from(42_u8);
+
+        assert_eq!(
+            UdcVersion::V0.unique_salt(deployer_address, salt),
+            Pedersen::hash(&deployer_address, &salt)
+        );
+        assert_ne!(
+            UdcVersion::V0.unique_salt(deployer_address, salt),
+            UdcVersion::V1.unique_salt(deployer_address, salt)
+        );
+    }
 }
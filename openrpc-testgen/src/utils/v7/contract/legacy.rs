@@ -0,0 +1,83 @@
+//! Cairo 0 (legacy) contract classes, as written by `starknet-compile-deprecated`. The class
+//! hash and the program serialization are shared with `t9n` in `crypto_utils`.
+
+use std::io::Write as _;
+
+use crypto_utils::legacy_class_hash::{
+    compute_legacy_class_hash, legacy_hinted_class_hash, legacy_program_json, LegacyClassHashError,
+};
+pub use crypto_utils::legacy_class_hash::{LegacyEntryPoint, LegacyEntryPoints};
+use flate2::{write::GzEncoder, Compression};
+use serde::{Deserialize, Serialize};
+use serde_json::value::RawValue;
+use starknet_types_core::felt::Felt;
+use starknet_types_rpc::v0_7_1::DeprecatedContractClass;
+
+use super::{CompressProgramError, ComputeClassHashError, JsonError};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LegacyContractClass {
+    #[serde(default)]
+    pub abi: Option<Vec<serde_json::Value>>,
+    pub entry_points_by_type: LegacyEntryPoints,
+    pub program: Box<RawValue>,
+}
+
+impl LegacyContractClass {
+    pub fn class_hash(&self) -> Result<Felt, ComputeClassHashError> {
+        compute_legacy_class_hash(
+            &self.entry_points_by_type,
+            &self.abi_json()?,
+            self.program.get(),
+        )
+        .map_err(class_hash_error)
+    }
+
+    pub fn hinted_class_hash(&self) -> Result<Felt, ComputeClassHashError> {
+        legacy_hinted_class_hash(&self.abi_json()?, self.program.get()).map_err(class_hash_error)
+    }
+
+    /// The class in the form sent in `DECLARE` v1 transactions, with the program gzipped and
+    /// base64 encoded.
+    pub fn compress(&self) -> Result<DeprecatedContractClass<Felt>, CompressProgramError> {
+        let program = legacy_program_json(self.program.get()).map_err(|e| {
+            CompressProgramError::Json(JsonError {
+                message: e.to_string(),
+            })
+        })?;
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::best());
+        encoder
+            .write_all(program.as_bytes())
+            .map_err(CompressProgramError::Io)?;
+        let compressed_program = encoder.finish().map_err(CompressProgramError::Io)?;
+
+        serde_json::from_value(serde_json::json!({
+            "program": base64::encode(compressed_program),
+            "entry_points_by_type": self.entry_points_by_type,
+            "abi": self.abi,
+        }))
+        .map_err(|e| {
+            CompressProgramError::Json(JsonError {
+                message: e.to_string(),
+            })
+        })
+    }
+
+    fn abi_json(&self) -> Result<String, ComputeClassHashError> {
+        serde_json::to_string(&self.abi).map_err(|e| {
+            ComputeClassHashError::Json(JsonError {
+                message: e.to_string(),
+            })
+        })
+    }
+}
+
+fn class_hash_error(err: LegacyClassHashError) -> ComputeClassHashError {
+    match err {
+        LegacyClassHashError::InvalidBuiltinName(_) => ComputeClassHashError::InvalidBuiltinName,
+        e => ComputeClassHashError::Json(JsonError {
+            message: e.to_string(),
+        }),
+    }
+}
@@ -1,6 +1,10 @@
+pub mod bindgen;
+pub mod bindings;
 pub mod declare_and_deploy;
 pub mod factory;
 pub mod helpers;
+pub mod legacy;
+pub mod registry;
 pub mod unsigned_felt;
 use serde::{de::Visitor, ser::SerializeSeq, Deserialize, Deserializer, Serialize, Serializer};
 use serde_json_pythonic::to_string_pythonic;
@@ -301,7 +305,6 @@ mod errors {
         Json(JsonError),
     }
     #[derive(Debug)]
-    #[allow(dead_code)]
     pub enum CompressProgramError {
         Json(JsonError),
         Io(std::io::Error),
@@ -342,6 +345,17 @@ mod errors {
         }
     }
 
+    impl std::error::Error for CompressProgramError {}
+
+    impl Display for CompressProgramError {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+            match self {
+                Self::Json(inner) => write!(f, "json serialization error: {}", inner),
+                Self::Io(inner) => write!(f, "compression io error: {}", inner),
+            }
+        }
+    }
+
     impl std::error::Error for JsonError {}
 
     impl Display for JsonError {
@@ -384,8 +398,8 @@ mod errors {
     }
 }
 pub use errors::{
-    BytecodeSegmentLengthMismatchError, ComputeClassHashError, InvalidBytecodeSegmentError,
-    JsonError, PcOutOfRangeError,
+    BytecodeSegmentLengthMismatchError, CompressProgramError, ComputeClassHashError,
+    InvalidBytecodeSegmentError, JsonError, PcOutOfRangeError,
 };
 
 use starknet_types_rpc::v0_7_1::{EntryPointsByType, SierraEntryPoint};
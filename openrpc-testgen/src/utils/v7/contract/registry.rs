@@ -0,0 +1,265 @@
+//! Lookup of the compiled contracts from `contracts/` by name.
+//!
+//! Scarb writes the artifacts of every contract to `target/dev` of the repository as
+//! `<package>_<name>.contract_class.json` (Sierra) and
+//! `<package>_<name>.compiled_contract_class.json` (CASM). The registry indexes that directory,
+//! or the one set in [ARTIFACTS_DIR_ENV], and caches the flattened class and compiled class
+//! hash of each contract on first use.
+
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::{Mutex, OnceLock},
+};
+
+use starknet_types_core::felt::Felt;
+use starknet_types_rpc::v0_7_1::ContractClass;
+
+use super::{CompiledClass, ComputeClassHashError, HashAndFlatten, JsonError, SierraClass};
+
+/// Environment variable overriding the artifacts directory of [ContractRegistry::global].
+pub const ARTIFACTS_DIR_ENV: &str = "CONTRACT_ARTIFACTS_DIR";
+/// Artifacts directory of the Scarb workspace at the root of the repository, independent of the
+/// directory the tests are run from.
+pub const DEFAULT_ARTIFACTS_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../target/dev");
+pub const DEFAULT_PACKAGE: &str = "contracts";
+
+const SIERRA_SUFFIX: &str = ".contract_class.json";
+const CASM_SUFFIX: &str = ".compiled_contract_class.json";
+
+#[derive(Debug, thiserror::Error)]
+pub enum ContractRegistryError {
+    #[error(
+        "{kind} artifact of contract {name} not found at {}, please execute scarb build command \
+        or set CONTRACT_ARTIFACTS_DIR",
+        path.display()
+    )]
+    MissingArtifact {
+        name: String,
+        kind: &'static str,
+        path: PathBuf,
+    },
+    #[error("Failed to read {}: {source}", path.display())]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("Failed to parse {}: {source}", path.display())]
+    Json {
+        path: PathBuf,
+        source: serde_json::Error,
+    },
+    #[error("Failed to flatten contract {name}: {source}")]
+    Flatten { name: String, source: JsonError },
+    #[error("Failed to compute the compiled class hash of contract {name}: {source}")]
+    CompiledClassHash {
+        name: String,
+        source: ComputeClassHashError,
+    },
+}
+
+#[derive(Debug)]
+pub struct ContractRegistry {
+    artifacts_dir: PathBuf,
+    package: String,
+    cache: Mutex<HashMap<String, (ContractClass<Felt>, Felt)>>,
+}
+
+impl ContractRegistry {
+    pub fn new(artifacts_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            artifacts_dir: artifacts_dir.into(),
+            package: DEFAULT_PACKAGE.to_string(),
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Registry over the directory set in [ARTIFACTS_DIR_ENV], [DEFAULT_ARTIFACTS_DIR] otherwise.
+    pub fn from_env() -> Self {
+        Self::new(
+            std::env::var_os(ARTIFACTS_DIR_ENV)
+                .map(PathBuf::from)
+                .unwrap_or_else(|| PathBuf::from(DEFAULT_ARTIFACTS_DIR)),
+        )
+    }
+
+    /// Registry shared by the test suites, created with [ContractRegistry::from_env] on first use.
+    pub fn global() -> &'static Self {
+        static REGISTRY: OnceLock<ContractRegistry> = OnceLock::new();
+        REGISTRY.get_or_init(Self::from_env)
+    }
+
+    /// Scarb package the artifacts belong to, [DEFAULT_PACKAGE] by default.
+    pub fn package(self, package: &str) -> Self {
+        Self {
+            package: package.to_string(),
+            ..self
+        }
+    }
+
+    pub fn artifacts_dir(&self) -> &Path {
+        &self.artifacts_dir
+    }
+
+    pub fn sierra_path(&self, name: &str) -> PathBuf {
+        self.artifact_path(name, SIERRA_SUFFIX)
+    }
+
+    pub fn casm_path(&self, name: &str) -> PathBuf {
+        self.artifact_path(name, CASM_SUFFIX)
+    }
+
+    /// Names of all contracts with a Sierra artifact in the artifacts directory, sorted.
+    pub fn names(&self) -> Result<Vec<String>, ContractRegistryError> {
+        let prefix = format!("{}_", self.package);
+        let entries =
+            std::fs::read_dir(&self.artifacts_dir).map_err(|source| ContractRegistryError::Io {
+                path: self.artifacts_dir.clone(),
+                source,
+            })?;
+
+        let mut names = vec![];
+        for entry in entries {
+            let entry = entry.map_err(|source| ContractRegistryError::Io {
+                path: self.artifacts_dir.clone(),
+                source,
+            })?;
+            let file_name = entry.file_name();
+            if let Some(name) = file_name
+                .to_str()
+                .and_then(|file_name| file_name.strip_prefix(&prefix))
+                .and_then(|file_name| file_name.strip_suffix(SIERRA_SUFFIX))
+            {
+                names.push(name.to_string());
+            }
+        }
+        names.sort();
+
+        Ok(names)
+    }
+
+    /// Flattened Sierra class and compiled class hash of the contract, e.g. `ExecutableAccount`
+    /// or `contracts_sample_contract_1_HelloStarknet` for contracts declared in submodules.
+    pub fn get(&self, name: &str) -> Result<(ContractClass<Felt>, Felt), ContractRegistryError> {
+        if let Some(cached) = self.cache.lock().unwrap().get(name) {
+            return Ok(cached.clone());
+        }
+
+        let sierra = self.sierra_class(name)?;
+        let casm: CompiledClass = self.read_artifact(name, "CASM", &self.casm_path(name))?;
+
+        let compiled_class_hash =
+            casm.class_hash()
+                .map_err(|source| ContractRegistryError::CompiledClassHash {
+                    name: name.to_string(),
+                    source,
+                })?;
+        let flattened_class =
+            sierra
+                .flatten()
+                .map_err(|source| ContractRegistryError::Flatten {
+                    name: name.to_string(),
+                    source,
+                })?;
+
+        let artifacts = (flattened_class, compiled_class_hash);
+        self.cache
+            .lock()
+            .unwrap()
+            .insert(name.to_string(), artifacts.clone());
+
+        Ok(artifacts)
+    }
+
+    /// Sierra class of the contract as written by the compiler, before flattening.
+    pub fn sierra_class(&self, name: &str) -> Result<SierraClass, ContractRegistryError> {
+        self.read_artifact(name, "Sierra", &self.sierra_path(name))
+    }
+
+    fn artifact_path(&self, name: &str, suffix: &str) -> PathBuf {
+        self.artifacts_dir
+            .join(format!("{}_{}{}", self.package, name, suffix))
+    }
+
+    fn read_artifact<T>(
+        &self,
+        name: &str,
+        kind: &'static str,
+        path: &Path,
+    ) -> Result<T, ContractRegistryError>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let contents = std::fs::read_to_string(path).map_err(|source| {
+            if source.kind() == std::io::ErrorKind::NotFound {
+                ContractRegistryError::MissingArtifact {
+                    name: name.to_string(),
+                    kind,
+                    path: path.to_path_buf(),
+                }
+            } else {
+                ContractRegistryError::Io {
+                    path: path.to_path_buf(),
+                    source,
+                }
+            }
+        })?;
+
+        serde_json::from_str(&contents).map_err(|source| ContractRegistryError::Json {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_artifact_paths() {
+        let registry = ContractRegistry::new("artifacts");
+
+        assert_eq!(
+            registry.sierra_path("ExecutableAccount"),
+            PathBuf::from("artifacts/contracts_ExecutableAccount.contract_class.json")
+        );
+        assert_eq!(
+            registry.package("other").casm_path("ExecutableAccount"),
+            PathBuf::from("artifacts/other_ExecutableAccount.compiled_contract_class.json")
+        );
+    }
+
+    #[test]
+    fn test_names() {
+        let dir = std::env::temp_dir().join(format!("registry-{}", rand::random::<u64>()));
+        std::fs::create_dir_all(&dir).unwrap();
+        for file_name in [
+            "contracts_TestToken.contract_class.json",
+            "contracts_TestToken.compiled_contract_class.json",
+            "contracts_ExecutableAccount.contract_class.json",
+            "other_Foo.contract_class.json",
+        ] {
+            std::fs::write(dir.join(file_name), "{}").unwrap();
+        }
+
+        let names = ContractRegistry::new(&dir).names();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(names.unwrap(), vec!["ExecutableAccount", "TestToken"]);
+    }
+
+    #[test]
+    fn test_missing_artifact() {
+        let registry = ContractRegistry::new("does/not/exist");
+
+        match registry.get("ExecutableAccount") {
+            Err(ContractRegistryError::MissingArtifact { name, kind, path }) => {
+                assert_eq!(name, "ExecutableAccount");
+                assert_eq!(kind, "Sierra");
+                assert_eq!(path, registry.sierra_path("ExecutableAccount"));
+            }
+            other => panic!("unexpected result {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,406 @@
+//! Client of the node control methods of development nodes: the `devnet_*` JSON-RPC methods of
+//! starknet-devnet and the `dev_*` methods of Katana. Anvil, the L1 node of messaging tests, only
+//! gets its JSON-RPC transport, see [EthereumClient](super::messaging::ethereum::EthereumClient).
+
+use std::fmt;
+
+use reqwest::{Client, Url};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::{json, Value};
+use starknet_types_core::felt::Felt;
+use starknet_types_rpc::v0_7_1::{BlockId, PriceUnit};
+use tracing::debug;
+
+use super::providers::jsonrpc::{JsonRpcError, JsonRpcResponse};
+
+/// Development node implementing the control methods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DevNode {
+    Devnet,
+    Katana,
+    Anvil,
+}
+
+impl fmt::Display for DevNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Devnet => write!(f, "starknet-devnet"),
+            Self::Katana => write!(f, "Katana"),
+            Self::Anvil => write!(f, "Anvil"),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum DevnetError {
+    #[error(transparent)]
+    Reqwest(#[from] reqwest::Error),
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+    #[error("JSON-RPC error {}: {}", .0.code, .0.message)]
+    JsonRpc(JsonRpcError),
+    #[error("{method} is not supported by {node}")]
+    Unsupported { method: &'static str, node: DevNode },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MintResult {
+    pub new_balance: String,
+    pub unit: PriceUnit,
+    pub tx_hash: Felt,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetTimeResult {
+    pub block_timestamp: u64,
+    pub block_hash: Option<Felt>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IncreaseTimeResult {
+    pub timestamp_increased_by: u64,
+    pub block_hash: Felt,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateBlockResult {
+    pub block_hash: Felt,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AbortBlocksResult {
+    pub aborted: Vec<Felt>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PostmanLoadResult {
+    pub messaging_contract_address: String,
+}
+
+/// Messages relayed by `devnet_postmanFlush`, as reported by Devnet.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PostmanFlushResult {
+    pub messages_to_l1: Vec<Value>,
+    pub messages_to_l2: Vec<Value>,
+    pub generated_l2_transactions: Vec<Felt>,
+    pub l1_provider: String,
+}
+
+/// Account funded at genesis. Devnet reports keys in snake case and Katana in camel case.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PredeployedAccount {
+    pub address: Felt,
+    #[serde(alias = "publicKey")]
+    pub public_key: Felt,
+    #[serde(default, alias = "privateKey")]
+    pub private_key: Option<Felt>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DevnetClient {
+    client: Client,
+    url: Url,
+    node: DevNode,
+}
+
+impl DevnetClient {
+    pub fn new(url: Url, node: DevNode) -> Self {
+        Self {
+            client: Client::new(),
+            url,
+            node,
+        }
+    }
+
+    pub fn devnet(url: Url) -> Self {
+        Self::new(url, DevNode::Devnet)
+    }
+
+    pub fn katana(url: Url) -> Self {
+        Self::new(url, DevNode::Katana)
+    }
+
+    pub fn anvil(url: Url) -> Self {
+        Self::new(url, DevNode::Anvil)
+    }
+
+    pub fn node(&self) -> DevNode {
+        self.node
+    }
+
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// Mints `amount` of the fee token of `unit` to `address`.
+    pub async fn mint(
+        &self,
+        address: Felt,
+        amount: u128,
+        unit: PriceUnit,
+    ) -> Result<MintResult, DevnetError> {
+        self.devnet_only("devnet_mint")?;
+        self.call(
+            "devnet_mint",
+            json!({ "address": address, "amount": amount, "unit": unit }),
+        )
+        .await
+    }
+
+    /// Sets the timestamp of the next block, generating a block right away if `generate_block`.
+    pub async fn set_time(&self, time: u64, generate_block: bool) -> Result<(), DevnetError> {
+        match self.node {
+            DevNode::Devnet => {
+                let _: SetTimeResult = self
+                    .call(
+                        "devnet_setTime",
+                        json!({ "time": time, "generate_block": generate_block }),
+                    )
+                    .await?;
+            }
+            DevNode::Katana => {
+                let _: Value = self
+                    .call("dev_setNextBlockTimestamp", json!([time]))
+                    .await?;
+                if generate_block {
+                    self.create_block().await?;
+                }
+            }
+            DevNode::Anvil => return Err(self.unsupported("devnet_setTime")),
+        }
+
+        Ok(())
+    }
+
+    /// Moves the time of the following blocks forward by `seconds`. Devnet also generates a block.
+    pub async fn increase_time(&self, seconds: u64) -> Result<(), DevnetError> {
+        match self.node {
+            DevNode::Devnet => {
+                let _: IncreaseTimeResult = self
+                    .call("devnet_increaseTime", json!({ "time": seconds }))
+                    .await?;
+            }
+            DevNode::Katana => {
+                let _: Value = self
+                    .call("dev_increaseNextBlockTimestamp", json!([seconds]))
+                    .await?;
+            }
+            DevNode::Anvil => return Err(self.unsupported("devnet_increaseTime")),
+        }
+
+        Ok(())
+    }
+
+    /// Closes the pending block, including its transactions in a new block.
+    pub async fn create_block(&self) -> Result<(), DevnetError> {
+        match self.node {
+            DevNode::Devnet => {
+                let _: CreateBlockResult = self.call("devnet_createBlock", json!({})).await?;
+            }
+            DevNode::Katana => {
+                let _: Value = self.call("dev_generateBlock", json!([])).await?;
+            }
+            DevNode::Anvil => return Err(self.unsupported("devnet_createBlock")),
+        }
+
+        Ok(())
+    }
+
+    /// Aborts the blocks from `starting_block_id` up to the latest one, returning their hashes.
+    pub async fn abort_blocks(
+        &self,
+        starting_block_id: BlockId<Felt>,
+    ) -> Result<Vec<Felt>, DevnetError> {
+        self.devnet_only("devnet_abortBlocks")?;
+        let result: AbortBlocksResult = self
+            .call(
+                "devnet_abortBlocks",
+                json!({ "starting_block_id": starting_block_id }),
+            )
+            .await?;
+
+        Ok(result.aborted)
+    }
+
+    /// Dumps the state of the node to `path` on the node host, or returns it when `path` is
+    /// `None`.
+    pub async fn dump(&self, path: Option<&str>) -> Result<Value, DevnetError> {
+        self.devnet_only("devnet_dump")?;
+        let params = match path {
+            Some(path) => json!({ "path": path }),
+            None => json!({}),
+        };
+
+        self.call("devnet_dump", params).await
+    }
+
+    /// Loads a state dumped to `path` on the node host.
+    pub async fn load(&self, path: &str) -> Result<(), DevnetError> {
+        self.devnet_only("devnet_load")?;
+        let _: Value = self.call("devnet_load", json!({ "path": path })).await?;
+
+        Ok(())
+    }
+
+    /// Lets transactions from `address` through without validation, on a forked Devnet.
+    pub async fn impersonate_account(&self, address: Felt) -> Result<(), DevnetError> {
+        self.devnet_only("devnet_impersonateAccount")?;
+        let _: Value = self
+            .call(
+                "devnet_impersonateAccount",
+                json!({ "account_address": address }),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn stop_impersonate_account(&self, address: Felt) -> Result<(), DevnetError> {
+        self.devnet_only("devnet_stopImpersonateAccount")?;
+        let _: Value = self
+            .call(
+                "devnet_stopImpersonateAccount",
+                json!({ "account_address": address }),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn predeployed_accounts(&self) -> Result<Vec<PredeployedAccount>, DevnetError> {
+        match self.node {
+            DevNode::Devnet => self.call("devnet_getPredeployedAccounts", json!({})).await,
+            DevNode::Katana => self.call("dev_predeployedAccounts", json!([])).await,
+            DevNode::Anvil => Err(self.unsupported("devnet_getPredeployedAccounts")),
+        }
+    }
+
+    /// Points Devnet at the L1 node, deploying a `MockStarknetMessaging` contract there unless
+    /// `messaging_contract_address` is given. Returns the messaging contract address.
+    pub async fn postman_load(
+        &self,
+        l1_url: &Url,
+        messaging_contract_address: Option<&str>,
+    ) -> Result<String, DevnetError> {
+        self.devnet_only("devnet_postmanLoad")?;
+        let mut params = json!({ "network_url": l1_url.as_str() });
+        if let Some(address) = messaging_contract_address {
+            params["messaging_contract_address"] = json!(address);
+        }
+
+        let result: PostmanLoadResult = self.call("devnet_postmanLoad", params).await?;
+
+        Ok(result.messaging_contract_address)
+    }
+
+    /// Makes Devnet execute the messages sent from L1 and send the messages to L1 since the
+    /// last flush.
+    pub async fn postman_flush(&self) -> Result<PostmanFlushResult, DevnetError> {
+        self.devnet_only("devnet_postmanFlush")?;
+        self.call("devnet_postmanFlush", json!({ "dry_run": false }))
+            .await
+    }
+
+    /// Resets the node to its genesis state.
+    pub async fn restart(&self) -> Result<(), DevnetError> {
+        self.devnet_only("devnet_restart")?;
+        let _: Value = self.call("devnet_restart", json!({})).await?;
+
+        Ok(())
+    }
+
+    fn devnet_only(&self, method: &'static str) -> Result<(), DevnetError> {
+        match self.node {
+            DevNode::Devnet => Ok(()),
+            _ => Err(self.unsupported(method)),
+        }
+    }
+
+    fn unsupported(&self, method: &'static str) -> DevnetError {
+        DevnetError::Unsupported {
+            method,
+            node: self.node,
+        }
+    }
+
+    /// Sends a JSON-RPC request to the node, also used for the `eth_*` methods of Anvil.
+    pub(crate) async fn call<R: DeserializeOwned>(
+        &self,
+        method: &'static str,
+        params: Value,
+    ) -> Result<R, DevnetError> {
+        let request_body = serde_json::to_string(&json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": method,
+            "params": params,
+        }))?;
+        debug!("Sending dev node request: {}", request_body);
+
+        let response_body = self
+            .client
+            .post(self.url.clone())
+            .body(request_body)
+            .header("Content-Type", "application/json")
+            .send()
+            .await?
+            .text()
+            .await?;
+        debug!("Dev node response: {}", response_body);
+
+        match serde_json::from_str::<JsonRpcResponse<R>>(&response_body)? {
+            JsonRpcResponse::Success { result, .. } => Ok(result),
+            JsonRpcResponse::Error { error, .. } => Err(DevnetError::JsonRpc(error)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_predeployed_account_devnet() {
+        let account: PredeployedAccount = serde_json::from_value(json!({
+            "initial_balance": "1000000000000000000000",
+            "address": "0x64b48806902a367c8598f4f95c305e8c1a1acba5f082d294a43793113115691",
+            "public_key": "0x39d9e6ce352ad4530a0ef5d5a18fd3303c3606a7fa6ac5b620020ad681cc33b",
+            "private_key": "0x71d7bb07b9a64f6f78ac4c816aff4da9"
+        }))
+        .unwrap();
+
+        assert_eq!(
+            account.private_key,
+            Some(Felt::from_hex("0x71d7bb07b9a64f6f78ac4c816aff4da9").unwrap())
+        );
+    }
+
+    #[test]
+    fn test_predeployed_account_katana() {
+        let account: PredeployedAccount = serde_json::from_value(json!({
+            "address": "0x127fd5f1fe78a71f8bcd1fec63e3fe2f0486b6ecd5c86a0466c3a21fa5cfcec",
+            "publicKey": "0x33246ce85ebdc292e6a5c5b4dd51fab2757be34b8ffda847ca6925edf31cb67",
+        }))
+        .unwrap();
+
+        assert_eq!(
+            account.public_key,
+            Felt::from_hex("0x33246ce85ebdc292e6a5c5b4dd51fab2757be34b8ffda847ca6925edf31cb67")
+                .unwrap()
+        );
+        assert_eq!(account.private_key, None);
+    }
+
+    #[test]
+    fn test_devnet_only_methods() {
+        let client = DevnetClient::katana(Url::parse("http://127.0.0.1:5050").unwrap());
+
+        assert!(matches!(
+            client.devnet_only("devnet_dump"),
+            Err(DevnetError::Unsupported {
+                method: "devnet_dump",
+                node: DevNode::Katana
+            })
+        ));
+    }
+}
@@ -226,6 +226,9 @@ pub enum RunnerError {
     #[error(transparent)]
     ComputeClassHashError(#[from] contract::ComputeClassHashError),
 
+    #[error(transparent)]
+    ContractRegistry(#[from] contract::registry::ContractRegistryError),
+
     #[error(transparent)]
     StarknetSierraCompilationError(
         #[from] cairo_lang_starknet_classes::casm_contract_class::StarknetSierraCompilationError,
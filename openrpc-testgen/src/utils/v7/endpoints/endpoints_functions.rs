@@ -36,7 +36,7 @@ use crate::utils::v7::{
         },
         single_owner::{ExecutionEncoding, SingleOwnerAccount},
     },
-    contract::factory::ContractFactory,
+    contract::{factory::ContractFactory, registry::ContractRegistry},
     endpoints::{declare_contract::extract_class_hash_from_error, errors::CallError},
     providers::{
         jsonrpc::{HttpTransport, JsonRpcClient, StarknetError},
@@ -67,17 +67,10 @@ pub async fn invoke_contract_erc20_transfer(
     amount_per_test: Option<Felt>,
 ) -> Result<Felt, OpenRpcTestGenError> {
     let (executable_account_flattened_sierra_class, executable_account_compiled_class_hash) =
-        get_compiled_contract(
-            "target/dev/contracts_MyAccount.contract_class.json",
-            "target/dev/contracts_MyAccount.compiled_contract_class.json",
-        )
-        .await?;
+        ContractRegistry::global().get("MyAccount")?;
 
-    let (erc_20_flattened_sierra_class, erc_20_compiled_class_hash) = get_compiled_contract(
-        "target/dev/contracts_TestToken.contract_class.json",
-        "target/dev/contracts_TestToken.compiled_contract_class.json",
-    )
-    .await?;
+    let (erc_20_flattened_sierra_class, erc_20_compiled_class_hash) =
+        ContractRegistry::global().get("TestToken")?;
 
     let provider = JsonRpcClient::new(HttpTransport::new(url.clone()));
 
@@ -1,15 +1,25 @@
-use starknet_types_core::felt::FromStrError;
+use starknet_types_core::felt::{Felt, FromStrError};
 use thiserror::Error;
 
 use super::declare_contract::RunnerError;
 use crate::{
     macros::macros_errors::AssertionNoPanicError,
     utils::{
+        cairo_serde::CairoSerdeError,
         conversions::errors::ConversionsError,
         v7::{
-            accounts::{account::AccountError, errors::CreationError, utils::mint::MintError},
+            accounts::{account::AccountError, errors::CreationError},
+            block_hash::BlockHashError,
+            contract::{
+                bindings::ContractBindingError, registry::ContractRegistryError,
+                ComputeClassHashError,
+            },
+            devnet::DevnetError,
+            messaging::MessagingError,
             providers::provider::ProviderError,
-            signers::local_wallet::SignError,
+            signers::{local_wallet::SignError, remote::RemoteSignerError},
+            storage_proof::StorageProofError,
+            typed_data::{SignTypedDataError, TypedDataError},
         },
     },
 };
@@ -30,7 +40,9 @@ pub enum OpenRpcTestGenError {
     #[error(transparent)]
     ContinuationTokenError(#[from] ContinuationTokenError),
     #[error(transparent)]
-    MintError(#[from] MintError),
+    ContractRegistryError(#[from] ContractRegistryError),
+    #[error(transparent)]
+    ContractBindingError(#[from] ContractBindingError),
     #[error(transparent)]
     SignError(#[from] SignError),
     #[error(transparent)]
@@ -42,12 +54,23 @@ pub enum OpenRpcTestGenError {
     #[error(transparent)]
     AccountError(#[from] AccountError<SignError>),
     #[error(transparent)]
+    RemoteAccountError(
+        #[from]
+        AccountError<crate::utils::v7::accounts::single_owner::SignError<RemoteSignerError>>,
+    ),
+    #[error(transparent)]
+    RemoteSignerError(#[from] RemoteSignerError),
+    #[error(transparent)]
     AccountFactoryError(
         #[from] crate::utils::v7::accounts::factory::AccountFactoryError<SignError>,
     ),
     #[error(transparent)]
     ProviderError(#[from] ProviderError),
     #[error(transparent)]
+    TypedDataError(#[from] TypedDataError),
+    #[error(transparent)]
+    SignTypedDataError(#[from] SignTypedDataError<SignError>),
+    #[error(transparent)]
     CallError(#[from] CallError),
     #[error(transparent)]
     NonAsciiNameError(#[from] NonAsciiNameError),
@@ -60,7 +83,23 @@ pub enum OpenRpcTestGenError {
     #[error(transparent)]
     Conversions(#[from] ConversionsError),
     #[error(transparent)]
+    CairoSerde(#[from] CairoSerdeError),
+    #[error(transparent)]
     JoinError(#[from] tokio::task::JoinError),
+    #[error(transparent)]
+    SerdeJsonError(#[from] serde_json::Error),
+    #[error(transparent)]
+    IoError(#[from] std::io::Error),
+    #[error(transparent)]
+    ComputeClassHash(#[from] ComputeClassHashError),
+    #[error(transparent)]
+    Messaging(#[from] MessagingError),
+    #[error(transparent)]
+    Devnet(#[from] DevnetError),
+    #[error(transparent)]
+    BlockHash(#[from] BlockHashError),
+    #[error(transparent)]
+    StorageProof(#[from] StorageProofError),
     #[error("Unexpected block type {0}")]
     UnexpectedBlockResponseType(String),
     #[error("Unexpected txn type {0}")]
@@ -81,6 +120,14 @@ pub enum OpenRpcTestGenError {
     TransactionNotFound(String),
     #[error("Transaction index overflowed when converting to u64")]
     TransactionIndexOverflow,
+    #[error(
+        "Contract at {contract_address:#x} has class hash {actual:#x}, expected {expected:#x}"
+    )]
+    ClassHashMismatch {
+        contract_address: Felt,
+        expected: Felt,
+        actual: Felt,
+    },
     #[error("Unexpected error occured: {0}")]
     Other(String),
     #[error("One or more tests failed: {failed_tests:?}")]
@@ -115,6 +162,9 @@ pub enum CallError {
     #[error("Unexpected receipt response type")]
     UnexpectedReceiptType,
 
+    #[error("No {0} event in the receipt")]
+    EventNotFound(String),
+
     #[error("Unexpected execution result")]
     UnexpectedExecutionResult,
 }
@@ -12,7 +12,6 @@ use crate::utils::v7::{
     contract::{CompiledClass, HashAndFlatten, SierraClass},
     endpoints::errors::OpenRpcTestGenError,
 };
-use reqwest::Client;
 use starknet_types_core::felt::Felt;
 use starknet_types_core::hash::{Pedersen, StarkHash};
 use starknet_types_rpc::v0_7_1::{ContractClass, TxnHash};
@@ -21,14 +20,20 @@ use starknet_types_rpc::{
 };
 use tokio::io::AsyncReadExt;
 
-use tracing::{error, info, warn};
-use url::Url;
+use tracing::{info, warn};
 
 use super::{declare_contract::RunnerError, errors::NonAsciiNameError};
 
 const DEFAULT_ENTRY_POINT_NAME: &str = "__default__";
 const DEFAULT_L1_ENTRY_POINT_NAME: &str = "__l1_default__";
 
+/// ETH fee token, paying the fees of V1 transactions.
+pub const ETH_ADDRESS: Felt =
+    Felt::from_hex_unchecked("0x049d36570d4e46f48e99674bd3fcc84644ddd6b96f7c741b1562b82f9e004dc7");
+/// STRK fee token, paying the fees of V3 transactions.
+pub const STRK_ADDRESS: Felt =
+    Felt::from_hex_unchecked("0x04718f5a0fc34cc1af16a1cdee98ffb20c31f5cd61d6ab07201858f4287c938d");
+
 pub async fn get_compiled_contract(
     sierra_path: &str,
     casm_path: &str,
@@ -72,21 +77,6 @@ pub async fn get_compiled_contract(
     Ok((flattened_class, casm_class_hash))
 }
 
-pub async fn restart_devnet(url: Url) -> Result<(), OpenRpcTestGenError> {
-    let client = Client::new();
-    let url = url.join("/restart")?;
-    let response = client.post(url).send().await?;
-    if response.status().is_success() {
-        info!("Devnet restarted successfully.");
-        Ok(())
-    } else {
-        error!("Failed to restart Devnet. Status: {}", response.status());
-        Err(OpenRpcTestGenError::RequestError(
-            response.error_for_status().unwrap_err(),
-        ))
-    }
-}
-
 pub fn get_selector_from_name(func_name: &str) -> Result<Felt, NonAsciiNameError> {
     if func_name == DEFAULT_ENTRY_POINT_NAME || func_name == DEFAULT_L1_ENTRY_POINT_NAME {
         Ok(Felt::ZERO)
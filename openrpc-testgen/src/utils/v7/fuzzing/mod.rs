@@ -0,0 +1,298 @@
+//! Property-based fuzzing of the `starknet_add*Transaction` endpoints.
+//!
+//! A fuzz case starts from a valid, signed broadcasted transaction (the seed) serialized to JSON,
+//! applies a random set of [Mutation]s to it and submits the result as a raw JSON-RPC request.
+//! Working on the JSON representation allows producing payloads that cannot be expressed with the
+//! typed [BroadcastedTxn](starknet_types_rpc::v0_7_1::BroadcastedTxn) structs, such as unknown
+//! versions, overflowing resource bounds or unknown data availability modes.
+//!
+//! Every response is classified into an [Outcome]. Responses carrying an error code that is not
+//! documented for the given method, malformed responses and unreachable nodes are reported as a
+//! [Finding], after the mutation set has been shrunk to a minimal reproducer.
+
+pub mod mutation;
+pub mod outcome;
+pub mod shrink;
+
+use core::fmt;
+use std::future::Future;
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use serde_json::{json, Value};
+
+pub use mutation::Mutation;
+pub use outcome::Outcome;
+
+use super::providers::jsonrpc::{HttpTransport, JsonRpcMethod, JsonRpcTransport};
+
+/// Kind of broadcasted transaction, which determines the endpoint a fuzz case is submitted to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TxnKind {
+    Invoke,
+    Declare,
+    DeployAccount,
+}
+
+impl TxnKind {
+    pub fn method(&self) -> JsonRpcMethod {
+        match self {
+            TxnKind::Invoke => JsonRpcMethod::AddInvokeTransaction,
+            TxnKind::Declare => JsonRpcMethod::AddDeclareTransaction,
+            TxnKind::DeployAccount => JsonRpcMethod::AddDeployAccountTransaction,
+        }
+    }
+
+    /// Wraps the transaction into the named params object expected by [TxnKind::method].
+    pub fn params(&self, txn: Value) -> Value {
+        match self {
+            TxnKind::Invoke => json!({ "invoke_transaction": txn }),
+            TxnKind::Declare => json!({ "declare_transaction": txn }),
+            TxnKind::DeployAccount => json!({ "deploy_account_transaction": txn }),
+        }
+    }
+
+    /// Name of the field holding the calldata, if the transaction has one.
+    pub fn calldata_field(&self) -> Option<&'static str> {
+        match self {
+            TxnKind::Invoke => Some("calldata"),
+            TxnKind::Declare => None,
+            TxnKind::DeployAccount => Some("constructor_calldata"),
+        }
+    }
+}
+
+/// A mutated transaction the node handled in a way the specification does not allow.
+#[derive(Debug, Clone)]
+pub struct Finding {
+    pub kind: TxnKind,
+    /// Minimal set of mutations, applied to the seed, that still reproduces the [Outcome].
+    pub mutations: Vec<Mutation>,
+    pub outcome: Outcome,
+    /// Full JSON-RPC request body of the minimal reproducer.
+    pub reproducer: Value,
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{:?} transaction: {}", self.kind, self.outcome)?;
+        writeln!(f, "  mutations: {:?}", self.mutations)?;
+        write!(f, "  reproducer: {}", self.reproducer)
+    }
+}
+
+/// Applies `mutations` in order to a copy of `seed`.
+pub fn apply_mutations(kind: TxnKind, seed: &Value, mutations: &[Mutation]) -> Value {
+    let mut txn = seed.clone();
+    for mutation in mutations {
+        mutation.apply(kind, &mut txn);
+    }
+    txn
+}
+
+#[derive(Debug, Clone)]
+pub struct TxnFuzzer {
+    transport: HttpTransport,
+    rng: StdRng,
+    max_mutations: usize,
+    max_shrink_steps: usize,
+}
+
+impl TxnFuzzer {
+    /// Creates a fuzzer submitting to `transport`. The same `seed` always produces the same
+    /// sequence of mutations, so failing campaigns can be replayed.
+    pub fn new(transport: HttpTransport, seed: u64) -> Self {
+        Self {
+            transport,
+            rng: StdRng::seed_from_u64(seed),
+            max_mutations: 4,
+            max_shrink_steps: 64,
+        }
+    }
+
+    /// Upper bound on the number of mutations applied to a single fuzz case.
+    pub fn max_mutations(self, max_mutations: usize) -> Self {
+        Self {
+            max_mutations: max_mutations.max(1),
+            ..self
+        }
+    }
+
+    /// Upper bound on the number of requests sent while shrinking a single finding.
+    pub fn max_shrink_steps(self, max_shrink_steps: usize) -> Self {
+        Self {
+            max_shrink_steps,
+            ..self
+        }
+    }
+
+    /// Submits `txn` as-is and classifies the response.
+    pub async fn submit(&self, kind: TxnKind, txn: &Value) -> Outcome {
+        let response = self
+            .transport
+            .send_request::<Value, Value>(kind.method(), kind.params(txn.clone()))
+            .await;
+
+        Outcome::classify(kind, response)
+    }
+
+    /// Runs a single fuzz case against `seed`, returning a shrunk [Finding] if the node misbehaved.
+    pub async fn fuzz_case(&mut self, kind: TxnKind, seed: &Value) -> Option<Finding> {
+        let mutation_count = self.rng.gen_range(1..=self.max_mutations);
+        let mutations: Vec<Mutation> = (0..mutation_count)
+            .map(|_| Mutation::arbitrary(&mut self.rng, kind))
+            .collect();
+
+        let outcome = self
+            .submit(kind, &apply_mutations(kind, seed, &mutations))
+            .await;
+        if !outcome.is_finding() {
+            return None;
+        }
+
+        let fuzzer = &*self;
+        let (mutations, outcome) = fuzzer
+            .shrink(mutations, outcome, |candidate| {
+                let txn = apply_mutations(kind, seed, candidate);
+                async move { fuzzer.submit(kind, &txn).await }
+            })
+            .await;
+        let reproducer = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": kind.method(),
+            "params": kind.params(apply_mutations(kind, seed, &mutations)),
+        });
+
+        Some(Finding {
+            kind,
+            mutations,
+            outcome,
+            reproducer,
+        })
+    }
+
+    /// Greedily replaces the mutation set with smaller candidates as long as `replay` still
+    /// reproduces the same failure with them. A node that became unreachable cannot be replayed
+    /// against, so such findings are returned unshrunk.
+    async fn shrink<F, Fut>(
+        &self,
+        mut mutations: Vec<Mutation>,
+        mut outcome: Outcome,
+        mut replay: F,
+    ) -> (Vec<Mutation>, Outcome)
+    where
+        F: FnMut(&[Mutation]) -> Fut,
+        Fut: Future<Output = Outcome>,
+    {
+        if !outcome.is_replayable() {
+            return (mutations, outcome);
+        }
+
+        let mut steps = 0;
+        'shrinking: loop {
+            for candidate in shrink::shrink_candidates(&mutations) {
+                if steps >= self.max_shrink_steps {
+                    break 'shrinking;
+                }
+                steps += 1;
+
+                let candidate_outcome = replay(&candidate).await;
+                if candidate_outcome.same_failure(&outcome) {
+                    mutations = candidate;
+                    outcome = candidate_outcome;
+                    continue 'shrinking;
+                }
+            }
+            break;
+        }
+
+        (mutations, outcome)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::cell::Cell;
+
+    use super::*;
+
+    fn fuzzer() -> TxnFuzzer {
+        TxnFuzzer::new(
+            HttpTransport::new(url::Url::parse("http://localhost:5050").unwrap()),
+            0,
+        )
+    }
+
+    fn undocumented_error() -> Outcome {
+        Outcome::UndocumentedError {
+            code: -1,
+            message: "Internal error".to_string(),
+            data: None,
+        }
+    }
+
+    /// Stub node failing whenever the signature has more than 3 elements, regardless of the other
+    /// mutations, and accepting everything else.
+    fn replay(mutations: &[Mutation]) -> Outcome {
+        if mutations
+            .iter()
+            .any(|m| matches!(m, Mutation::SignatureLength(length) if *length > 3))
+        {
+            undocumented_error()
+        } else {
+            Outcome::Accepted
+        }
+    }
+
+    fn mutations() -> Vec<Mutation> {
+        vec![
+            Mutation::PaymasterDataLength(16),
+            Mutation::SignatureLength(1024),
+            Mutation::Version("0x100000000000000000000000000000003".to_string()),
+        ]
+    }
+
+    #[tokio::test]
+    async fn test_shrink_reaches_minimal_reproducer() {
+        let (mutations, outcome) = fuzzer()
+            .shrink(mutations(), undocumented_error(), |candidate| {
+                let outcome = replay(candidate);
+                async move { outcome }
+            })
+            .await;
+
+        assert_eq!(mutations, vec![Mutation::SignatureLength(4)]);
+        assert_eq!(outcome, undocumented_error());
+    }
+
+    #[tokio::test]
+    async fn test_shrink_stops_after_max_steps() {
+        let steps = Cell::new(0);
+        let (mutations, _) = fuzzer()
+            .max_shrink_steps(3)
+            .shrink(mutations(), undocumented_error(), |candidate| {
+                steps.set(steps.get() + 1);
+                let outcome = replay(candidate);
+                async move { outcome }
+            })
+            .await;
+
+        // Dropping the paymaster data, then the version, takes all three replays: the signature
+        // is never simplified.
+        assert_eq!(steps.get(), 3);
+        assert_eq!(mutations, vec![Mutation::SignatureLength(1024)]);
+    }
+
+    #[tokio::test]
+    async fn test_shrink_keeps_unreachable_finding() {
+        let unreachable = Outcome::NodeUnreachable("connection refused".to_string());
+        let (mutations, outcome) = fuzzer()
+            .shrink(mutations(), unreachable.clone(), |_| async {
+                panic!("an unreachable node must not be replayed")
+            })
+            .await;
+
+        assert_eq!(mutations, self::mutations());
+        assert_eq!(outcome, unreachable);
+    }
+}
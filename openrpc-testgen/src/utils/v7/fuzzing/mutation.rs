@@ -0,0 +1,333 @@
+use rand::{seq::SliceRandom, Rng};
+use serde_json::Value;
+use starknet_types_core::felt::Felt;
+
+use super::TxnKind;
+
+/// Array lengths around common boundaries, used for signatures, calldata and data arrays.
+const LENGTHS: &[usize] = &[0, 1, 2, 3, 4, 16, 255, 256, 1024, 4096];
+
+/// Raw values for resource bounds: `u64`/`u128` limits and their overflows, the field prime and
+/// a few strings that are not valid hex numbers at all.
+const BOUND_VALUES: &[&str] = &[
+    "0x0",
+    "0xffffffffffffffff",
+    "0x10000000000000000",
+    "0xffffffffffffffffffffffffffffffff",
+    "0x100000000000000000000000000000000",
+    "0x800000000000011000000000000000000000000000000000000000000000001",
+    "0x",
+    "-0x1",
+    "1",
+];
+
+const DA_MODES: &[&str] = &["L1", "L2", "L3", "", "0x0", "0x1"];
+
+/// Plain versions, query versions (`2^128 + version`) and an empty hex string.
+const VERSIONS: &[&str] = &[
+    "0x0",
+    "0x1",
+    "0x2",
+    "0x3",
+    "0x4",
+    "0x100000000000000000000000000000000",
+    "0x100000000000000000000000000000001",
+    "0x100000000000000000000000000000002",
+    "0x100000000000000000000000000000003",
+    "0x100000000000000000000000000000004",
+    "0x",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Resource {
+    L1Gas,
+    L2Gas,
+}
+
+impl Resource {
+    fn key(&self) -> &'static str {
+        match self {
+            Resource::L1Gas => "l1_gas",
+            Resource::L2Gas => "l2_gas",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoundField {
+    MaxAmount,
+    MaxPricePerUnit,
+}
+
+impl BoundField {
+    fn key(&self) -> &'static str {
+        match self {
+            BoundField::MaxAmount => "max_amount",
+            BoundField::MaxPricePerUnit => "max_price_per_unit",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DaModeField {
+    Nonce,
+    Fee,
+}
+
+impl DaModeField {
+    fn key(&self) -> &'static str {
+        match self {
+            DaModeField::Nonce => "nonce_data_availability_mode",
+            DaModeField::Fee => "fee_data_availability_mode",
+        }
+    }
+}
+
+/// A single change applied to the JSON representation of a broadcasted transaction.
+///
+/// Mutations are fully determined by their parameters, so a list of mutations together with the
+/// seed transaction is a complete reproducer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Mutation {
+    /// Truncates or pads `signature` to the given number of elements.
+    SignatureLength(usize),
+    /// Truncates or pads the calldata to the given number of elements.
+    CalldataLength(usize),
+    /// Overwrites the first calldata element, the number of calls for `__execute__`.
+    CallCount(Felt),
+    /// Sets a resource bound to a raw, possibly invalid, value.
+    ResourceBound {
+        resource: Resource,
+        field: BoundField,
+        value: String,
+    },
+    /// Sets a data availability mode to a raw, possibly invalid, value.
+    DaMode { field: DaModeField, value: String },
+    /// Truncates or pads `paymaster_data` to the given number of elements.
+    PaymasterDataLength(usize),
+    /// Truncates or pads `account_deployment_data` to the given number of elements.
+    AccountDeploymentDataLength(usize),
+    /// Sets `version` to a raw value, including query versions.
+    Version(String),
+}
+
+impl Mutation {
+    /// Picks a random mutation applicable to a transaction of the given kind.
+    pub fn arbitrary<R: Rng>(rng: &mut R, kind: TxnKind) -> Self {
+        let length = *LENGTHS.choose(rng).unwrap_or(&0);
+        let variants: &[u8] = match kind {
+            TxnKind::Invoke => &[0, 1, 2, 3, 4, 5, 6, 7],
+            TxnKind::Declare => &[0, 3, 4, 5, 6, 7],
+            TxnKind::DeployAccount => &[0, 1, 3, 4, 5, 7],
+        };
+
+        match variants.choose(rng).unwrap_or(&0) {
+            0 => Mutation::SignatureLength(length),
+            1 => Mutation::CalldataLength(length),
+            2 => {
+                let call_counts = [Felt::ZERO, Felt::TWO, Felt::from(u64::MAX), Felt::MAX];
+                Mutation::CallCount(*call_counts.choose(rng).unwrap_or(&Felt::ZERO))
+            }
+            3 => Mutation::ResourceBound {
+                resource: *[Resource::L1Gas, Resource::L2Gas]
+                    .choose(rng)
+                    .unwrap_or(&Resource::L1Gas),
+                field: *[BoundField::MaxAmount, BoundField::MaxPricePerUnit]
+                    .choose(rng)
+                    .unwrap_or(&BoundField::MaxAmount),
+                value: BOUND_VALUES.choose(rng).unwrap_or(&"0x0").to_string(),
+            },
+            4 => Mutation::DaMode {
+                field: *[DaModeField::Nonce, DaModeField::Fee]
+                    .choose(rng)
+                    .unwrap_or(&DaModeField::Nonce),
+                value: DA_MODES.choose(rng).unwrap_or(&"L1").to_string(),
+            },
+            5 => Mutation::PaymasterDataLength(length),
+            6 => Mutation::AccountDeploymentDataLength(length),
+            _ => Mutation::Version(VERSIONS.choose(rng).unwrap_or(&"0x3").to_string()),
+        }
+    }
+
+    /// Applies the mutation to `txn`, creating missing fields when needed.
+    pub fn apply(&self, kind: TxnKind, txn: &mut Value) {
+        let Some(object) = txn.as_object_mut() else {
+            return;
+        };
+
+        match self {
+            Mutation::SignatureLength(length) => {
+                resize_felt_array(object.entry("signature").or_insert(Value::Null), *length)
+            }
+            Mutation::CalldataLength(length) => {
+                if let Some(field) = kind.calldata_field() {
+                    resize_felt_array(object.entry(field).or_insert(Value::Null), *length)
+                }
+            }
+            Mutation::CallCount(count) => {
+                if let Some(field) = kind.calldata_field() {
+                    let calldata = object.entry(field).or_insert(Value::Null);
+                    if calldata.as_array().map(Vec::len).unwrap_or(0) == 0 {
+                        resize_felt_array(calldata, 1);
+                    }
+                    if let Some(first) = calldata.as_array_mut().and_then(|c| c.first_mut()) {
+                        *first = Value::String(count.to_hex_string());
+                    }
+                }
+            }
+            Mutation::ResourceBound {
+                resource,
+                field,
+                value,
+            } => {
+                let bounds = object.entry("resource_bounds").or_insert(Value::Null);
+                ensure_object(bounds);
+                let resource_bounds = bounds
+                    .as_object_mut()
+                    .map(|bounds| bounds.entry(resource.key()).or_insert(Value::Null));
+                if let Some(resource_bounds) = resource_bounds {
+                    ensure_object(resource_bounds);
+                    if let Some(resource_bounds) = resource_bounds.as_object_mut() {
+                        resource_bounds
+                            .insert(field.key().to_string(), Value::String(value.clone()));
+                    }
+                }
+            }
+            Mutation::DaMode { field, value } => {
+                object.insert(field.key().to_string(), Value::String(value.clone()));
+            }
+            Mutation::PaymasterDataLength(length) => resize_felt_array(
+                object.entry("paymaster_data").or_insert(Value::Null),
+                *length,
+            ),
+            Mutation::AccountDeploymentDataLength(length) => resize_felt_array(
+                object
+                    .entry("account_deployment_data")
+                    .or_insert(Value::Null),
+                *length,
+            ),
+            Mutation::Version(version) => {
+                object.insert("version".to_string(), Value::String(version.clone()));
+            }
+        }
+    }
+
+    /// Simpler variants of this mutation, tried while shrinking a finding.
+    pub fn simplify(&self) -> Vec<Mutation> {
+        let shorter = |length: usize| -> Vec<usize> {
+            let mut lengths = vec![0, length / 2, length.saturating_sub(1)];
+            lengths.retain(|candidate| *candidate < length);
+            lengths.dedup();
+            lengths
+        };
+
+        match self {
+            Mutation::SignatureLength(length) => shorter(*length)
+                .into_iter()
+                .map(Mutation::SignatureLength)
+                .collect(),
+            Mutation::CalldataLength(length) => shorter(*length)
+                .into_iter()
+                .map(Mutation::CalldataLength)
+                .collect(),
+            Mutation::PaymasterDataLength(length) => shorter(*length)
+                .into_iter()
+                .map(Mutation::PaymasterDataLength)
+                .collect(),
+            Mutation::AccountDeploymentDataLength(length) => shorter(*length)
+                .into_iter()
+                .map(Mutation::AccountDeploymentDataLength)
+                .collect(),
+            Mutation::CallCount(_)
+            | Mutation::ResourceBound { .. }
+            | Mutation::DaMode { .. }
+            | Mutation::Version(_) => vec![],
+        }
+    }
+}
+
+fn ensure_object(value: &mut Value) {
+    if !value.is_object() {
+        *value = Value::Object(Default::default());
+    }
+}
+
+/// Truncates `value` to `length` elements, or pads it with small distinct felts. Non-array values
+/// are replaced with an array.
+fn resize_felt_array(value: &mut Value, length: usize) {
+    if !value.is_array() {
+        *value = Value::Array(vec![]);
+    }
+    if let Some(array) = value.as_array_mut() {
+        let current = array.len();
+        if current > length {
+            array.truncate(length);
+        } else {
+            array.extend(
+                (current..length).map(|i| Value::String(Felt::from(i as u64 + 1).to_hex_string())),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_resize_signature() {
+        let mut txn = json!({ "signature": ["0xa", "0xb"] });
+
+        Mutation::SignatureLength(1).apply(TxnKind::Invoke, &mut txn);
+        assert_eq!(txn, json!({ "signature": ["0xa"] }));
+
+        Mutation::SignatureLength(3).apply(TxnKind::Invoke, &mut txn);
+        assert_eq!(txn, json!({ "signature": ["0xa", "0x2", "0x3"] }));
+    }
+
+    #[test]
+    fn test_calldata_field_depends_on_kind() {
+        let mut txn = json!({});
+
+        Mutation::CalldataLength(1).apply(TxnKind::DeployAccount, &mut txn);
+        assert_eq!(txn, json!({ "constructor_calldata": ["0x1"] }));
+
+        Mutation::CalldataLength(1).apply(TxnKind::Declare, &mut txn);
+        assert_eq!(txn, json!({ "constructor_calldata": ["0x1"] }));
+    }
+
+    #[test]
+    fn test_resource_bound_creates_missing_fields() {
+        let mut txn = json!({ "resource_bounds": { "l1_gas": { "max_amount": "0x1" } } });
+
+        Mutation::ResourceBound {
+            resource: Resource::L1Gas,
+            field: BoundField::MaxPricePerUnit,
+            value: "0x10000000000000000".to_string(),
+        }
+        .apply(TxnKind::Invoke, &mut txn);
+
+        assert_eq!(
+            txn,
+            json!({ "resource_bounds": { "l1_gas": {
+                "max_amount": "0x1",
+                "max_price_per_unit": "0x10000000000000000"
+            } } })
+        );
+    }
+
+    #[test]
+    fn test_simplify_lengths() {
+        assert_eq!(
+            Mutation::SignatureLength(4).simplify(),
+            vec![
+                Mutation::SignatureLength(0),
+                Mutation::SignatureLength(2),
+                Mutation::SignatureLength(3)
+            ]
+        );
+        assert!(Mutation::SignatureLength(0).simplify().is_empty());
+        assert!(Mutation::Version("0x3".to_string()).simplify().is_empty());
+    }
+}
@@ -0,0 +1,153 @@
+use core::fmt;
+
+use serde_json::Value;
+
+use super::TxnKind;
+use crate::utils::v7::providers::jsonrpc::{
+    transports::http::HttpTransportError, JsonRpcError, JsonRpcResponse, StarknetError,
+};
+
+/// `Invalid params` from the JSON-RPC 2.0 specification, expected for payloads violating the
+/// transaction schema.
+const INVALID_PARAMS: i64 = -32602;
+
+/// Error codes listed for `starknet_addInvokeTransaction`.
+const INVOKE_ERRORS: &[i64] = &[52, 53, 54, 55, 58, 59, 61, 63];
+
+/// Error codes listed for `starknet_addDeclareTransaction`.
+const DECLARE_ERRORS: &[i64] = &[51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63];
+
+/// Error codes listed for `starknet_addDeployAccountTransaction`.
+const DEPLOY_ACCOUNT_ERRORS: &[i64] = &[28, 52, 53, 54, 55, 58, 59, 61, 63];
+
+/// How a node handled a submitted transaction.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outcome {
+    /// The transaction was accepted into the mempool.
+    Accepted,
+    /// The node returned an error documented for the method.
+    DocumentedError { code: i64, message: String },
+    /// The node returned an error code the specification does not list for the method.
+    UndocumentedError {
+        code: i64,
+        message: String,
+        data: Option<Value>,
+    },
+    /// The error code is documented, but its `data` field does not have the documented shape.
+    MalformedErrorData {
+        code: i64,
+        message: String,
+        data: Option<Value>,
+    },
+    /// The response body is not a valid JSON-RPC response.
+    MalformedResponse(String),
+    /// The request could not be delivered or the connection dropped, e.g. the node crashed.
+    NodeUnreachable(String),
+}
+
+impl Outcome {
+    pub fn classify(
+        kind: TxnKind,
+        response: Result<JsonRpcResponse<Value>, HttpTransportError>,
+    ) -> Self {
+        match response {
+            Ok(JsonRpcResponse::Success { .. }) => Outcome::Accepted,
+            Ok(JsonRpcResponse::Error { error, .. }) => Self::classify_error(kind, error),
+            Err(HttpTransportError::Json(error)) => Outcome::MalformedResponse(error.to_string()),
+            Err(HttpTransportError::Reqwest(error)) => Outcome::NodeUnreachable(error.to_string()),
+        }
+    }
+
+    fn classify_error(kind: TxnKind, error: JsonRpcError) -> Self {
+        if error.code == INVALID_PARAMS {
+            return Outcome::DocumentedError {
+                code: error.code,
+                message: error.message,
+            };
+        }
+
+        if !documented_errors(kind).contains(&error.code) {
+            return Outcome::UndocumentedError {
+                code: error.code,
+                message: error.message,
+                data: error.data,
+            };
+        }
+
+        match StarknetError::try_from(&error) {
+            Ok(_) => Outcome::DocumentedError {
+                code: error.code,
+                message: error.message,
+            },
+            Err(_) => Outcome::MalformedErrorData {
+                code: error.code,
+                message: error.message,
+                data: error.data,
+            },
+        }
+    }
+
+    /// Whether the outcome violates the specification and should be reported.
+    pub fn is_finding(&self) -> bool {
+        !matches!(self, Outcome::Accepted | Outcome::DocumentedError { .. })
+    }
+
+    /// Whether the request can be sent again to check if a smaller payload reproduces it.
+    pub fn is_replayable(&self) -> bool {
+        !matches!(self, Outcome::NodeUnreachable(_))
+    }
+
+    /// Whether `other` is the same kind of failure, ignoring messages that usually embed
+    /// payload-specific details.
+    pub fn same_failure(&self, other: &Outcome) -> bool {
+        match (self, other) {
+            (
+                Outcome::UndocumentedError { code: a, .. },
+                Outcome::UndocumentedError { code: b, .. },
+            ) => a == b,
+            (
+                Outcome::MalformedErrorData { code: a, .. },
+                Outcome::MalformedErrorData { code: b, .. },
+            ) => a == b,
+            (Outcome::MalformedResponse(_), Outcome::MalformedResponse(_)) => true,
+            (Outcome::NodeUnreachable(_), Outcome::NodeUnreachable(_)) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Error codes the specification lists for the endpoint `kind` is submitted to.
+pub fn documented_errors(kind: TxnKind) -> &'static [i64] {
+    match kind {
+        TxnKind::Invoke => INVOKE_ERRORS,
+        TxnKind::Declare => DECLARE_ERRORS,
+        TxnKind::DeployAccount => DEPLOY_ACCOUNT_ERRORS,
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Accepted => write!(f, "accepted"),
+            Outcome::DocumentedError { code, message } => {
+                write!(f, "documented error {}: {}", code, message)
+            }
+            Outcome::UndocumentedError {
+                code,
+                message,
+                data,
+            } => write!(f, "undocumented error {}: {} ({:?})", code, message, data),
+            Outcome::MalformedErrorData {
+                code,
+                message,
+                data,
+            } => write!(
+                f,
+                "malformed data for error {}: {} ({:?})",
+                code, message, data
+            ),
+            Outcome::MalformedResponse(error) => write!(f, "malformed response: {}", error),
+            Outcome::NodeUnreachable(error) => write!(f, "node unreachable: {}", error),
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use super::Mutation;
+
+/// Candidate mutation lists smaller than `mutations`, from the most to the least aggressive
+/// reduction: first every list with a single mutation dropped, then every list with a single
+/// mutation replaced by one of its [simplifications](Mutation::simplify).
+pub fn shrink_candidates(mutations: &[Mutation]) -> Vec<Vec<Mutation>> {
+    let mut candidates = Vec::new();
+
+    for index in 0..mutations.len() {
+        let mut candidate = mutations.to_vec();
+        candidate.remove(index);
+        candidates.push(candidate);
+    }
+
+    for (index, mutation) in mutations.iter().enumerate() {
+        for simplified in mutation.simplify() {
+            let mut candidate = mutations.to_vec();
+            candidate[index] = simplified;
+            candidates.push(candidate);
+        }
+    }
+
+    candidates
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_shrink_candidates() {
+        let mutations = vec![
+            Mutation::Version("0x1".to_string()),
+            Mutation::SignatureLength(2),
+        ];
+
+        assert_eq!(
+            shrink_candidates(&mutations),
+            vec![
+                vec![Mutation::SignatureLength(2)],
+                vec![Mutation::Version("0x1".to_string())],
+                vec![
+                    Mutation::Version("0x1".to_string()),
+                    Mutation::SignatureLength(0)
+                ],
+                vec![
+                    Mutation::Version("0x1".to_string()),
+                    Mutation::SignatureLength(1)
+                ],
+            ]
+        );
+    }
+}
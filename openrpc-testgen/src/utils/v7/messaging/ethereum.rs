@@ -0,0 +1,282 @@
+//! Minimal Ethereum JSON-RPC client sending messages to L2 from a dev node such as Anvil, whose
+//! default accounts are unlocked and can send transactions without local signing.
+
+use std::time::Duration;
+
+use reqwest::Url;
+use serde::Deserialize;
+use serde_json::json;
+use starknet_types_core::felt::Felt;
+
+use super::{MessagingError, MsgToL2};
+use crate::utils::v7::devnet::DevnetClient;
+
+/// Default account 0 of Anvil and Hardhat, derived from the `test test ... junk` mnemonic.
+pub const DEFAULT_ACCOUNT: &str = "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266";
+
+/// First 4 bytes of `keccak256("sendMessageToL2(uint256,uint256,uint256[])")`.
+pub const SEND_MESSAGE_TO_L2_SELECTOR: [u8; 4] = [0x3e, 0x3a, 0xa6, 0xc5];
+
+/// `keccak256("LogMessageToL2(address,uint256,uint256,uint256[],uint256,uint256)")`.
+pub const LOG_MESSAGE_TO_L2_TOPIC: &str =
+    "0xdb80dd488acf86d17c747445b0eabb5d57c541d3bd7b6b87af987858e5066b2b";
+
+const RECEIPT_TIMEOUT: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, Deserialize)]
+struct Log {
+    address: String,
+    topics: Vec<String>,
+    data: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct Receipt {
+    status: String,
+    logs: Vec<Log>,
+}
+
+/// Message sent to L2 along with the hash of the L1 transaction that sent it.
+#[derive(Debug, Clone)]
+pub struct L1MessageSent {
+    pub transaction_hash: String,
+    pub message: MsgToL2,
+}
+
+#[derive(Debug, Clone)]
+pub struct EthereumClient {
+    client: DevnetClient,
+    from: String,
+}
+
+impl EthereumClient {
+    pub fn new(url: Url) -> Self {
+        Self {
+            client: DevnetClient::anvil(url),
+            from: DEFAULT_ACCOUNT.to_string(),
+        }
+    }
+
+    /// Overrides the unlocked account sending the transactions.
+    pub fn from_account(self, from: &str) -> Self {
+        Self {
+            from: from.to_string(),
+            ..self
+        }
+    }
+
+    pub fn account(&self) -> &str {
+        &self.from
+    }
+
+    /// Calls `sendMessageToL2` of the messaging contract, paying `fee` wei for the L1 handler,
+    /// and waits for the transaction to be mined.
+    pub async fn send_message_to_l2(
+        &self,
+        messaging_contract_address: &str,
+        to_address: Felt,
+        selector: Felt,
+        payload: &[Felt],
+        fee: u128,
+    ) -> Result<L1MessageSent, MessagingError> {
+        let transaction_hash: String = self
+            .client
+            .call(
+                "eth_sendTransaction",
+                json!([{
+                    "from": self.from,
+                    "to": messaging_contract_address,
+                    "data": encode_send_message_to_l2(to_address, selector, payload),
+                    "value": format!("{:#x}", fee),
+                }]),
+            )
+            .await?;
+
+        let receipt = self.wait_for_receipt(&transaction_hash).await?;
+        if receipt.status != "0x1" {
+            return Err(MessagingError::Reverted(transaction_hash));
+        }
+
+        let log = receipt
+            .logs
+            .iter()
+            .find(|log| {
+                log.address.eq_ignore_ascii_case(messaging_contract_address)
+                    && log
+                        .topics
+                        .first()
+                        .is_some_and(|topic| topic.eq_ignore_ascii_case(LOG_MESSAGE_TO_L2_TOPIC))
+            })
+            .ok_or_else(|| {
+                MessagingError::InvalidLog(format!(
+                    "no LogMessageToL2 in transaction {}",
+                    transaction_hash
+                ))
+            })?;
+
+        Ok(L1MessageSent {
+            message: decode_log_message_to_l2(&log.topics, &log.data)?,
+            transaction_hash,
+        })
+    }
+
+    async fn wait_for_receipt(&self, transaction_hash: &str) -> Result<Receipt, MessagingError> {
+        let start = std::time::Instant::now();
+
+        loop {
+            let receipt: Option<Receipt> = self
+                .client
+                .call("eth_getTransactionReceipt", json!([transaction_hash]))
+                .await?;
+
+            if let Some(receipt) = receipt {
+                return Ok(receipt);
+            }
+            if start.elapsed() > RECEIPT_TIMEOUT {
+                return Err(MessagingError::Timeout(transaction_hash.to_string()));
+            }
+            tokio::time::sleep(Duration::from_millis(500)).await;
+        }
+    }
+}
+
+/// ABI encoded call of `sendMessageToL2(uint256 toAddress, uint256 selector, uint256[] payload)`.
+pub fn encode_send_message_to_l2(to_address: Felt, selector: Felt, payload: &[Felt]) -> String {
+    let mut words = vec![
+        to_address,
+        selector,
+        // Offset of the dynamic `payload` argument, after the three head words.
+        Felt::from(0x60_u8),
+        Felt::from(payload.len()),
+    ];
+    words.extend(payload);
+
+    let mut data = String::with_capacity(2 + 8 + words.len() * 64);
+    data.push_str("0x");
+    for byte in SEND_MESSAGE_TO_L2_SELECTOR {
+        data.push_str(&format!("{:02x}", byte));
+    }
+    for word in words {
+        data.push_str(&format!("{:064x}", word));
+    }
+
+    data
+}
+
+/// Decodes `LogMessageToL2(address indexed fromAddress, uint256 indexed toAddress,
+/// uint256 indexed selector, uint256[] payload, uint256 nonce, uint256 fee)`.
+pub fn decode_log_message_to_l2(topics: &[String], data: &str) -> Result<MsgToL2, MessagingError> {
+    let [_, from_address, to_address, selector] = topics else {
+        return Err(MessagingError::InvalidLog(format!(
+            "expected 4 topics, got {}",
+            topics.len()
+        )));
+    };
+
+    let data = data.trim_start_matches("0x");
+    if data.len() % 64 != 0 {
+        return Err(MessagingError::InvalidLog(format!(
+            "data is not made of 32 byte words: {}",
+            data
+        )));
+    }
+    let words: Vec<&str> = data
+        .as_bytes()
+        .chunks(64)
+        .map(|word| std::str::from_utf8(word).unwrap_or_default())
+        .collect();
+
+    // Head: payload offset, nonce, fee. Tail: payload length and items.
+    let (Some(nonce), Some(payload_length)) = (words.get(1), words.get(3)) else {
+        return Err(MessagingError::InvalidLog(format!(
+            "expected at least 4 data words, got {}",
+            words.len()
+        )));
+    };
+    let payload_length = usize::from_str_radix(payload_length, 16)
+        .map_err(|e| MessagingError::InvalidLog(format!("invalid payload length: {}", e)))?;
+    let payload = words
+        .get(4..4 + payload_length)
+        .ok_or_else(|| {
+            MessagingError::InvalidLog(format!("payload of {} items truncated", payload_length))
+        })?
+        .iter()
+        .map(|word| parse_word(word))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(MsgToL2 {
+        from_address: parse_word(from_address)?,
+        to_address: parse_word(to_address)?,
+        selector: parse_word(selector)?,
+        payload,
+        nonce: parse_word(nonce)?,
+    })
+}
+
+fn parse_word(word: &str) -> Result<Felt, MessagingError> {
+    let word = word.trim_start_matches("0x");
+    Felt::from_hex(&format!("0x{}", word))
+        .map_err(|e| MessagingError::InvalidLog(format!("invalid word {}: {}", word, e)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn word(value: u64) -> String {
+        format!("{:064x}", value)
+    }
+
+    #[test]
+    fn test_encode_send_message_to_l2() {
+        let data = encode_send_message_to_l2(
+            Felt::from(0x1234_u64),
+            Felt::from(0x5678_u64),
+            &[Felt::from(7_u8), Felt::from(8_u8)],
+        );
+
+        let expected = ["0x3e3aa6c5".to_string(), word(0x1234), word(0x5678)]
+            .into_iter()
+            .chain([word(0x60), word(2), word(7), word(8)])
+            .collect::<String>();
+        assert_eq!(data, expected);
+    }
+
+    #[test]
+    fn test_decode_log_message_to_l2() {
+        let topics = vec![
+            LOG_MESSAGE_TO_L2_TOPIC.to_string(),
+            format!("0x{}", word(0xf39f)),
+            format!("0x{}", word(0x1234)),
+            format!("0x{}", word(0x5678)),
+        ];
+        let data = format!(
+            "0x{}",
+            [word(0x60), word(3), word(1), word(2), word(7), word(8)].concat()
+        );
+
+        let message = decode_log_message_to_l2(&topics, &data).unwrap();
+
+        assert_eq!(
+            message,
+            MsgToL2 {
+                from_address: Felt::from(0xf39f_u64),
+                to_address: Felt::from(0x1234_u64),
+                selector: Felt::from(0x5678_u64),
+                payload: vec![Felt::from(7_u8), Felt::from(8_u8)],
+                nonce: Felt::from(3_u8),
+            }
+        );
+    }
+
+    #[test]
+    fn test_decode_log_message_to_l2_truncated() {
+        let topics = vec![LOG_MESSAGE_TO_L2_TOPIC.to_string(); 4];
+        let data = format!("0x{}", [word(0x60), word(3), word(1), word(2)].concat());
+
+        assert!(matches!(
+            decode_log_message_to_l2(&topics, &data),
+            Err(MessagingError::InvalidLog(_))
+        ));
+    }
+}
@@ -0,0 +1,145 @@
+//! L1 <-> L2 messaging.
+//!
+//! Messages from L1 are sent through the `sendMessageToL2` entrypoint of the Starknet messaging
+//! contract, a `MockStarknetMessaging` instance on a local Anvil node in tests, and executed on L2
+//! as `L1_HANDLER` transactions. Messages to L1 are sent by contracts with the
+//! `send_message_to_l1` syscall and show up in transaction receipts.
+//!
+//! Devnet relays messages between the two networks on `devnet_postmanFlush`, once
+//! `devnet_postmanLoad` pointed it at the L1 node, see [DevnetClient](super::devnet::DevnetClient).
+
+pub mod ethereum;
+
+use sha3::{Digest, Keccak256};
+use starknet_types_core::{
+    felt::Felt,
+    hash::{Pedersen, StarkHash},
+};
+
+use super::devnet::DevnetError;
+
+/// Prefix of `L1_HANDLER` transaction hashes, `l1_handler` as a short string.
+const PREFIX_L1_HANDLER: Felt = Felt::from_raw([
+    157895833347907735,
+    18446744073709551615,
+    18446744073708665300,
+    1365666230910873368,
+]);
+
+#[derive(Debug, thiserror::Error)]
+pub enum MessagingError {
+    #[error(transparent)]
+    Devnet(#[from] DevnetError),
+    #[error("L1 transaction {0} reverted")]
+    Reverted(String),
+    #[error("L1 transaction {0} not mined in time")]
+    Timeout(String),
+    #[error("Invalid LogMessageToL2 log: {0}")]
+    InvalidLog(String),
+}
+
+/// Message sent from L1 with `sendMessageToL2`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MsgToL2 {
+    /// Address of the L1 sender.
+    pub from_address: Felt,
+    pub to_address: Felt,
+    pub selector: Felt,
+    pub payload: Vec<Felt>,
+    pub nonce: Felt,
+}
+
+impl MsgToL2 {
+    /// Hash of the message as computed by the Starknet core contract, reported as
+    /// `message_hash` in the receipt of the `L1_HANDLER` transaction.
+    pub fn hash(&self) -> [u8; 32] {
+        let mut hasher = Keccak256::new();
+        hasher.update(self.from_address.to_bytes_be());
+        hasher.update(self.to_address.to_bytes_be());
+        hasher.update(self.nonce.to_bytes_be());
+        hasher.update(self.selector.to_bytes_be());
+        hasher.update(Felt::from(self.payload.len()).to_bytes_be());
+        for item in &self.payload {
+            hasher.update(item.to_bytes_be());
+        }
+
+        hasher.finalize().into()
+    }
+
+    /// Calldata of the `L1_HANDLER` transaction, the sender followed by the payload.
+    pub fn calldata(&self) -> Vec<Felt> {
+        let mut calldata = Vec::with_capacity(self.payload.len() + 1);
+        calldata.push(self.from_address);
+        calldata.extend(&self.payload);
+        calldata
+    }
+
+    /// Hash of the `L1_HANDLER` transaction executing the message on L2.
+    pub fn transaction_hash(&self, chain_id: Felt) -> Felt {
+        Pedersen::hash_array(&[
+            PREFIX_L1_HANDLER,
+            Felt::ZERO,
+            self.to_address,
+            self.selector,
+            Pedersen::hash_array(&self.calldata()),
+            Felt::ZERO,
+            chain_id,
+            self.nonce,
+        ])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Goerli transaction 0x374286ae28f201e61ffbc5b022cc9701208640b405ea34ea9799f97d5d2d23c
+    fn goerli_message() -> MsgToL2 {
+        MsgToL2 {
+            from_address: Felt::from_hex("0xc3511006c04ef1d78af4c8e0e74ec18a6e64ff9e").unwrap(),
+            to_address: Felt::from_hex(
+                "0x73314940630fd6dcda0d772d4c972c4e0a9946bef9dabf4ef84eda8ef542b82",
+            )
+            .unwrap(),
+            selector: Felt::from_hex(
+                "0x2d757788a8d8d6f21d1cd40bce38a8222d70654214e96ff95d8086e684fbee5",
+            )
+            .unwrap(),
+            payload: vec![
+                Felt::from_hex("0x689ead7d814e51ed93644bc145f0754839b8dcb340027ce0c30953f38f55d7")
+                    .unwrap(),
+                Felt::from_hex("0x2c68af0bb140000").unwrap(),
+                Felt::ZERO,
+            ],
+            nonce: Felt::from(775628_u64),
+        }
+    }
+
+    #[test]
+    fn test_prefix_l1_handler() {
+        assert_eq!(PREFIX_L1_HANDLER, Felt::from_bytes_be_slice(b"l1_handler"));
+    }
+
+    #[test]
+    fn test_message_hash() {
+        let hash: String = goerli_message()
+            .hash()
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect();
+
+        assert_eq!(
+            hash,
+            "c51a543ef9563ad2545342b390b67edfcddf9886aa36846cf70382362fc5fab3"
+        );
+    }
+
+    #[test]
+    fn test_l1_handler_transaction_hash() {
+        assert_eq!(
+            goerli_message().transaction_hash(Felt::from_bytes_be_slice(b"SN_GOERLI")),
+            Felt::from_hex("0x374286ae28f201e61ffbc5b022cc9701208640b405ea34ea9799f97d5d2d23c")
+                .unwrap()
+        );
+    }
+}
@@ -1,5 +1,11 @@
 pub mod accounts;
+pub mod block_hash;
 pub mod contract;
+pub mod devnet;
 pub mod endpoints;
+pub mod fuzzing;
+pub mod messaging;
 pub mod providers;
 pub mod signers;
+pub mod storage_proof;
+pub mod typed_data;
@@ -4,21 +4,24 @@ use starknet_types_rpc::v0_7_1::{
     AddDeclareTransactionParams, AddDeployAccountTransactionParams, AddInvokeTransactionParams,
     AddInvokeTransactionResult, BlockHashAndNumber, BlockHashAndNumberParams, BlockId,
     BlockNumberParams, BroadcastedTxn, CallParams, ChainIdParams, ClassAndTxnHash,
-    ContractAndTxnHash, ContractClass, EstimateFeeParams, EstimateMessageFeeParams,
-    EventFilterWithPageRequest, EventsChunk, FeeEstimate, FunctionCall,
-    GetBlockTransactionCountParams, GetBlockWithTxHashesParams, GetBlockWithTxsParams,
-    GetClassAtParams, GetClassHashAtParams, GetClassParams, GetEventsParams, GetNonceParams,
-    GetStateUpdateParams, GetStorageAtParams, GetTransactionByBlockIdAndIndexParams,
-    GetTransactionByHashParams, GetTransactionReceiptParams, GetTransactionStatusParams,
-    MaybePendingBlockWithTxHashes, MaybePendingBlockWithTxs, MaybePendingStateUpdate, MsgFromL1,
-    SimulateTransactionsParams, SimulateTransactionsResult, SimulationFlag, SpecVersionParams,
-    SyncingParams, SyncingStatus, TraceBlockTransactionsParams, TraceBlockTransactionsResult,
-    TraceTransactionParams, TransactionTrace, Txn, TxnFinalityAndExecutionStatus, TxnHash,
-    TxnReceipt,
+    ContractAndTxnHash, ContractClass, DeprecatedContractClass, EstimateFeeParams,
+    EstimateMessageFeeParams, EventFilterWithPageRequest, EventsChunk, FeeEstimate, FunctionCall,
+    GetBlockTransactionCountParams, GetBlockWithReceiptsParams, GetBlockWithTxHashesParams,
+    GetBlockWithTxsParams, GetClassAtParams, GetClassHashAtParams, GetClassParams, GetEventsParams,
+    GetNonceParams, GetStateUpdateParams, GetStorageAtParams,
+    GetTransactionByBlockIdAndIndexParams, GetTransactionByHashParams, GetTransactionReceiptParams,
+    GetTransactionStatusParams, MaybePendingBlockWithReceipts, MaybePendingBlockWithTxHashes,
+    MaybePendingBlockWithTxs, MaybePendingStateUpdate, MsgFromL1, SimulateTransactionsParams,
+    SimulateTransactionsResult, SimulationFlag, SpecVersionParams, SyncingParams, SyncingStatus,
+    TraceBlockTransactionsParams, TraceBlockTransactionsResult, TraceTransactionParams,
+    TransactionTrace, Txn, TxnFinalityAndExecutionStatus, TxnHash, TxnReceipt,
 };
 use std::{any::Any, error::Error, fmt::Display};
 
-use super::provider::{Provider, ProviderError, ProviderImplError};
+use super::provider::{
+    ContractStorageKeys, MessageStatus, PathfinderProof, Provider, ProviderError,
+    ProviderImplError, StorageProof, TxnReceiptWithBlockInfo,
+};
 use starknet_types_core::felt::Felt as FeltPrimitive;
 pub use transports::{HttpTransport, JsonRpcTransport};
 
@@ -35,6 +38,8 @@ pub enum JsonRpcMethod {
     GetBlockWithTxHashes,
     #[serde(rename = "starknet_getBlockWithTxs")]
     GetBlockWithTxs,
+    #[serde(rename = "starknet_getBlockWithReceipts")]
+    GetBlockWithReceipts,
     #[serde(rename = "starknet_getStateUpdate")]
     GetStateUpdate,
     #[serde(rename = "starknet_getStorageAt")]
@@ -85,6 +90,12 @@ pub enum JsonRpcMethod {
     SimulateTransactions,
     #[serde(rename = "starknet_traceBlockTransactions")]
     TraceBlockTransactions,
+    #[serde(rename = "starknet_getMessagesStatus")]
+    GetMessagesStatus,
+    #[serde(rename = "starknet_getStorageProof")]
+    GetStorageProof,
+    #[serde(rename = "pathfinder_getProof")]
+    PathfinderGetProof,
 }
 
 #[derive(Debug, Clone)]
@@ -100,6 +111,7 @@ pub enum JsonRpcRequestData {
     SpecVersion(SpecVersionParams),
     GetBlockWithTxHashes(GetBlockWithTxHashesParams<FeltPrimitive>),
     GetBlockWithTxs(GetBlockWithTxsParams<FeltPrimitive>),
+    GetBlockWithReceipts(GetBlockWithReceiptsParams<FeltPrimitive>),
     GetStateUpdate(GetStateUpdateParams<FeltPrimitive>),
     GetStorageAt(GetStorageAtParams<FeltPrimitive>),
     GetTransactionStatus(GetTransactionStatusParams<FeltPrimitive>),
@@ -125,6 +137,36 @@ pub enum JsonRpcRequestData {
     TraceTransaction(TraceTransactionParams<FeltPrimitive>),
     SimulateTransactions(SimulateTransactionsParams<FeltPrimitive>),
     TraceBlockTransactions(TraceBlockTransactionsParams<FeltPrimitive>),
+    GetMessagesStatus(GetMessagesStatusParams),
+    GetStorageProof(GetStorageProofParams),
+    PathfinderGetProof(PathfinderGetProofParams),
+}
+
+/// Parameters of `starknet_getMessagesStatus`, not part of the v0.7 specification types.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetMessagesStatusParams {
+    /// Hash of the L1 transaction that sent the messages.
+    pub transaction_hash: String,
+}
+
+/// Parameters of `starknet_getStorageProof`, not part of the v0.7 specification types.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetStorageProofParams {
+    pub block_id: BlockId<FeltPrimitive>,
+    #[serde(default)]
+    pub class_hashes: Vec<FeltPrimitive>,
+    #[serde(default)]
+    pub contract_addresses: Vec<FeltPrimitive>,
+    #[serde(default)]
+    pub contracts_storage_keys: Vec<ContractStorageKeys>,
+}
+
+/// Parameters of the pathfinder `pathfinder_getProof` extension.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PathfinderGetProofParams {
+    pub block_id: BlockId<FeltPrimitive>,
+    pub contract_address: FeltPrimitive,
+    pub keys: Vec<FeltPrimitive>,
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -236,6 +278,18 @@ where
         .await
     }
 
+    /// Get block information with full transactions and receipts given the block id
+    async fn get_block_with_receipts(
+        &self,
+        block_id: BlockId<FeltPrimitive>,
+    ) -> Result<MaybePendingBlockWithReceipts<FeltPrimitive>, ProviderError> {
+        self.send_request(
+            JsonRpcMethod::GetBlockWithReceipts,
+            GetBlockWithReceiptsParams { block_id },
+        )
+        .await
+    }
+
     /// Get the information about the result of executing the requested block
     async fn get_state_update(
         &self,
@@ -317,6 +371,18 @@ where
         .await
     }
 
+    /// Same as [get_transaction_receipt], but keeping the block the transaction was included in
+    async fn get_transaction_receipt_with_block_info(
+        &self,
+        transaction_hash: TxnHash<FeltPrimitive>,
+    ) -> Result<TxnReceiptWithBlockInfo, ProviderError> {
+        self.send_request(
+            JsonRpcMethod::GetTransactionReceipt,
+            GetTransactionReceiptParams { transaction_hash },
+        )
+        .await
+    }
+
     /// Get the contract class definition in the given block associated with the given hash
     async fn get_class(
         &self,
@@ -333,6 +399,22 @@ where
         .await
     }
 
+    /// Get the Cairo 0 contract class definition in the given block associated with the given hash
+    async fn get_legacy_class(
+        &self,
+        block_id: BlockId<FeltPrimitive>,
+        class_hash: FeltPrimitive,
+    ) -> Result<DeprecatedContractClass<FeltPrimitive>, ProviderError> {
+        self.send_request(
+            JsonRpcMethod::GetClass,
+            GetClassParams {
+                block_id,
+                class_hash,
+            },
+        )
+        .await
+    }
+
     /// Get the contract class hash in the given block for the contract deployed at the given address
     async fn get_class_hash_at(
         &self,
@@ -367,6 +449,22 @@ where
         .await
     }
 
+    /// Get the Cairo 0 contract class definition in the given block at the given address
+    async fn get_legacy_class_at(
+        &self,
+        block_id: BlockId<FeltPrimitive>,
+        contract_address: FeltPrimitive,
+    ) -> Result<DeprecatedContractClass<FeltPrimitive>, ProviderError> {
+        self.send_request(
+            JsonRpcMethod::GetClassAt,
+            GetClassAtParams {
+                block_id,
+                contract_address,
+            },
+        )
+        .await
+    }
+
     /// Get the number of transactions in a block given a block id
     async fn get_block_transaction_count(
         &self,
@@ -587,6 +685,58 @@ where
         }
     }
 
+    /// Given an L1 transaction hash, returns the statuses of the L1_HANDLER transactions
+    /// triggered by the messages it sent
+    async fn get_messages_status(
+        &self,
+        transaction_hash: String,
+    ) -> Result<Vec<MessageStatus>, ProviderError> {
+        self.send_request(
+            JsonRpcMethod::GetMessagesStatus,
+            GetMessagesStatusParams { transaction_hash },
+        )
+        .await
+    }
+
+    /// Get merkle paths in the classes trie, the contracts trie and the storage tries of contracts,
+    /// along with the roots they hash up to
+    async fn get_storage_proof(
+        &self,
+        block_id: BlockId<FeltPrimitive>,
+        class_hashes: Vec<FeltPrimitive>,
+        contract_addresses: Vec<FeltPrimitive>,
+        contracts_storage_keys: Vec<ContractStorageKeys>,
+    ) -> Result<StorageProof, ProviderError> {
+        self.send_request(
+            JsonRpcMethod::GetStorageProof,
+            GetStorageProofParams {
+                block_id,
+                class_hashes,
+                contract_addresses,
+                contracts_storage_keys,
+            },
+        )
+        .await
+    }
+
+    /// Get the proofs of a contract and of the given keys of its storage, a pathfinder extension
+    async fn pathfinder_get_proof(
+        &self,
+        block_id: BlockId<FeltPrimitive>,
+        contract_address: FeltPrimitive,
+        keys: Vec<FeltPrimitive>,
+    ) -> Result<PathfinderProof, ProviderError> {
+        self.send_request(
+            JsonRpcMethod::PathfinderGetProof,
+            PathfinderGetProofParams {
+                block_id,
+                contract_address,
+                keys,
+            },
+        )
+        .await
+    }
+
     #[doc = " Same as [simulate_transactions], but only with one simulation."]
     async fn simulate_transaction(
         &self,
@@ -636,6 +786,12 @@ impl<'de> Deserialize<'de> for JsonRpcRequest {
                 serde_json::from_value::<GetBlockWithTxsParams<FeltPrimitive>>(raw_request.params)
                     .map_err(error_mapper)?,
             ),
+            JsonRpcMethod::GetBlockWithReceipts => JsonRpcRequestData::GetBlockWithReceipts(
+                serde_json::from_value::<GetBlockWithReceiptsParams<FeltPrimitive>>(
+                    raw_request.params,
+                )
+                .map_err(error_mapper)?,
+            ),
             JsonRpcMethod::GetStateUpdate => JsonRpcRequestData::GetStateUpdate(
                 serde_json::from_value::<GetStateUpdateParams<FeltPrimitive>>(raw_request.params)
                     .map_err(error_mapper)?,
@@ -764,6 +920,18 @@ impl<'de> Deserialize<'de> for JsonRpcRequest {
                 )
                 .map_err(error_mapper)?,
             ),
+            JsonRpcMethod::GetMessagesStatus => JsonRpcRequestData::GetMessagesStatus(
+                serde_json::from_value::<GetMessagesStatusParams>(raw_request.params)
+                    .map_err(error_mapper)?,
+            ),
+            JsonRpcMethod::GetStorageProof => JsonRpcRequestData::GetStorageProof(
+                serde_json::from_value::<GetStorageProofParams>(raw_request.params)
+                    .map_err(error_mapper)?,
+            ),
+            JsonRpcMethod::PathfinderGetProof => JsonRpcRequestData::PathfinderGetProof(
+                serde_json::from_value::<PathfinderGetProofParams>(raw_request.params)
+                    .map_err(error_mapper)?,
+            ),
         };
 
         Ok(Self {
@@ -812,6 +980,7 @@ impl TryFrom<&JsonRpcError> for StarknetError {
             32 => Ok(StarknetError::NoBlocks),
             33 => Ok(StarknetError::InvalidContinuationToken),
             34 => Ok(StarknetError::TooManyKeysInFilter),
+            42 => Ok(StarknetError::StorageProofNotSupported),
             40 => {
                 let data = ContractErrorData::deserialize(
                     value
@@ -929,6 +1098,8 @@ pub enum StarknetError {
     ContractError(ContractErrorData),
     /// Transaction execution error
     TransactionExecutionError(TransactionExecutionErrorData),
+    /// The node doesn't support storage proofs for blocks that are too far in the past
+    StorageProofNotSupported,
     /// Class already declared
     ClassAlreadyDeclared,
     /// Invalid transaction nonce
@@ -974,6 +1145,7 @@ impl core::fmt::Display for StarknetError {
             Self::TooManyKeysInFilter => write!(f, "TooManyKeysInFilter"),
             Self::ContractError(_) => write!(f, "ContractError"),
             Self::TransactionExecutionError(_) => write!(f, "TransactionExecutionError"),
+            Self::StorageProofNotSupported => write!(f, "StorageProofNotSupported"),
             Self::ClassAlreadyDeclared => write!(f, "ClassAlreadyDeclared"),
             Self::InvalidTransactionNonce => write!(f, "InvalidTransactionNonce"),
             Self::InsufficientMaxFee => write!(f, "InsufficientMaxFee"),
@@ -1007,6 +1179,7 @@ impl StarknetError {
             Self::TooManyKeysInFilter => "Too many keys provided in a filter",
             Self::ContractError(_) => "Contract error",
             Self::TransactionExecutionError(_) => "Transaction execution error",
+            Self::StorageProofNotSupported => "the node doesn't support storage proofs for blocks that are too far in the past",
             Self::ClassAlreadyDeclared => "Class already declared",
             Self::InvalidTransactionNonce => "Invalid transaction nonce",
             Self::InsufficientMaxFee => "Max fee is smaller than the minimal transaction cost (validation plus fee transfer)",
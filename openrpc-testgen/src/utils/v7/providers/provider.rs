@@ -3,10 +3,12 @@ use serde::{Deserialize, Serialize};
 use starknet_types_core::felt::Felt;
 use starknet_types_rpc::v0_7_1::{
     AddInvokeTransactionResult, BlockHashAndNumber, BlockId, BroadcastedTxn, ClassAndTxnHash,
-    ContractAndTxnHash, ContractClass, EventFilterWithPageRequest, EventsChunk, FeeEstimate,
-    FunctionCall, MaybePendingBlockWithTxHashes, MaybePendingBlockWithTxs, MaybePendingStateUpdate,
-    MsgFromL1, SimulateTransactionsResult, SimulationFlag, SyncingStatus,
-    TraceBlockTransactionsResult, TransactionTrace, Txn, TxnFinalityAndExecutionStatus, TxnReceipt,
+    ContractAndTxnHash, ContractClass, DeprecatedContractClass, EventFilterWithPageRequest,
+    EventsChunk, FeeEstimate, FunctionCall, MaybePendingBlockWithReceipts,
+    MaybePendingBlockWithTxHashes, MaybePendingBlockWithTxs, MaybePendingStateUpdate, MsgFromL1,
+    SimulateTransactionsResult, SimulationFlag, SyncingStatus, TraceBlockTransactionsResult,
+    TransactionTrace, Txn, TxnExecutionStatus, TxnFinalityAndExecutionStatus, TxnReceipt,
+    TxnStatus,
 };
 
 use std::{any::Any, error::Error, fmt::Debug};
@@ -19,6 +21,132 @@ pub enum SimulationFlagForEstimateFee {
     SkipValidate,
 }
 
+/// Status of an L1_HANDLER transaction triggered by a message sent from L1.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MessageStatus {
+    pub transaction_hash: Felt,
+    pub finality_status: TxnStatus,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub execution_status: Option<TxnExecutionStatus>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub failure_reason: Option<String>,
+}
+
+/// Receipt of `starknet_getTransactionReceipt` with the block fields that [TxnReceipt] leaves
+/// out. Receipts of pending transactions have neither of them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TxnReceiptWithBlockInfo {
+    #[serde(flatten)]
+    pub receipt: TxnReceipt<Felt>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_hash: Option<Felt>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_number: Option<u64>,
+}
+
+/// Storage keys of a contract whose proofs are requested from `starknet_getStorageProof`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContractStorageKeys {
+    pub contract_address: Felt,
+    pub storage_keys: Vec<Felt>,
+}
+
+/// Node of a proof served by `starknet_getStorageProof`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum MerkleNode {
+    Binary {
+        left: Felt,
+        right: Felt,
+    },
+    Edge {
+        path: Felt,
+        length: usize,
+        child: Felt,
+    },
+}
+
+/// Proof node along with the hash the node reports for it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NodeHashToNode {
+    pub node_hash: Felt,
+    pub node: MerkleNode,
+}
+
+/// Leaf of a contract in the contracts trie.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContractLeafData {
+    pub nonce: Felt,
+    pub class_hash: Felt,
+    pub storage_root: Felt,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContractsProof {
+    pub nodes: Vec<NodeHashToNode>,
+    /// Leaves of the requested contracts, in the order of the request.
+    pub contract_leaves_data: Vec<ContractLeafData>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GlobalRoots {
+    pub contracts_tree_root: Felt,
+    pub classes_tree_root: Felt,
+    /// Block the proofs were generated at.
+    pub block_hash: Felt,
+}
+
+/// Result of `starknet_getStorageProof`, not part of the v0.7 specification types.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StorageProof {
+    pub classes_proof: Vec<NodeHashToNode>,
+    pub contracts_proof: ContractsProof,
+    /// Proofs of the storage keys of each requested contract, in the order of the request.
+    pub contracts_storage_proofs: Vec<Vec<NodeHashToNode>>,
+    pub global_roots: GlobalRoots,
+}
+
+/// Node of a proof served by `pathfinder_getProof`, ordered from the root.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProofNode {
+    Binary { left: Felt, right: Felt },
+    Edge { child: Felt, path: EdgePath },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EdgePath {
+    pub value: Felt,
+    pub len: usize,
+}
+
+/// Contract state served by `pathfinder_getProof`, with the proofs of the requested keys in its
+/// storage trie.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PathfinderContractData {
+    pub class_hash: Felt,
+    pub nonce: Felt,
+    /// Root of the storage trie.
+    pub root: Felt,
+    pub contract_state_hash_version: Felt,
+    pub storage_proofs: Vec<Vec<ProofNode>>,
+}
+
+/// Result of the pathfinder `pathfinder_getProof` extension.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PathfinderProof {
+    /// Global state root, `None` for blocks preceding state commitments.
+    #[serde(default)]
+    pub state_commitment: Option<Felt>,
+    /// Root of the classes trie, `None` for blocks preceding state commitments.
+    #[serde(default)]
+    pub class_commitment: Option<Felt>,
+    pub contract_proof: Vec<ProofNode>,
+    /// `None` if no contract is deployed at the address.
+    #[serde(default)]
+    pub contract_data: Option<PathfinderContractData>,
+}
+
 #[auto_impl(&, Box, Arc)]
 pub trait Provider {
     /// Returns the version of the Starknet JSON-RPC specification being used
@@ -36,6 +164,12 @@ pub trait Provider {
         block_id: BlockId<Felt>,
     ) -> impl std::future::Future<Output = Result<MaybePendingBlockWithTxs<Felt>, ProviderError>>;
 
+    /// Get block information with full transactions and receipts given the block id
+    fn get_block_with_receipts(
+        &self,
+        block_id: BlockId<Felt>,
+    ) -> impl std::future::Future<Output = Result<MaybePendingBlockWithReceipts<Felt>, ProviderError>>;
+
     /// Get the information about the result of executing the requested block
     fn get_state_update(
         &self,
@@ -76,6 +210,12 @@ pub trait Provider {
         transaction_hash: Felt,
     ) -> impl std::future::Future<Output = Result<TxnReceipt<Felt>, ProviderError>>;
 
+    /// Same as [get_transaction_receipt], but keeping the block the transaction was included in
+    fn get_transaction_receipt_with_block_info(
+        &self,
+        transaction_hash: Felt,
+    ) -> impl std::future::Future<Output = Result<TxnReceiptWithBlockInfo, ProviderError>>;
+
     /// Get the contract class definition in the given block associated with the given hash
     fn get_class(
         &self,
@@ -83,6 +223,13 @@ pub trait Provider {
         class_hash: Felt,
     ) -> impl std::future::Future<Output = Result<ContractClass<Felt>, ProviderError>>;
 
+    /// Get the Cairo 0 contract class definition in the given block associated with the given hash
+    fn get_legacy_class(
+        &self,
+        block_id: BlockId<Felt>,
+        class_hash: Felt,
+    ) -> impl std::future::Future<Output = Result<DeprecatedContractClass<Felt>, ProviderError>>;
+
     /// Get the contract class hash in the given block for the contract deployed at the given address
     fn get_class_hash_at(
         &self,
@@ -97,6 +244,13 @@ pub trait Provider {
         contract_address: Felt,
     ) -> impl std::future::Future<Output = Result<ContractClass<Felt>, ProviderError>>;
 
+    /// Get the Cairo 0 contract class definition in the given block at the given address
+    fn get_legacy_class_at(
+        &self,
+        block_id: BlockId<Felt>,
+        contract_address: Felt,
+    ) -> impl std::future::Future<Output = Result<DeprecatedContractClass<Felt>, ProviderError>>;
+
     /// Get the number of transactions in a block given a block id
     fn get_block_transaction_count(
         &self,
@@ -218,6 +372,31 @@ pub trait Provider {
         block_id: BlockId<Felt>,
     ) -> impl std::future::Future<Output = Result<Vec<TraceBlockTransactionsResult<Felt>>, ProviderError>>;
 
+    /// Given an L1 transaction hash, returns the statuses of the L1_HANDLER transactions
+    /// triggered by the messages it sent
+    fn get_messages_status(
+        &self,
+        transaction_hash: String,
+    ) -> impl std::future::Future<Output = Result<Vec<MessageStatus>, ProviderError>>;
+
+    /// Get merkle paths in the classes trie, the contracts trie and the storage tries of contracts,
+    /// along with the roots they hash up to
+    fn get_storage_proof(
+        &self,
+        block_id: BlockId<Felt>,
+        class_hashes: Vec<Felt>,
+        contract_addresses: Vec<Felt>,
+        contracts_storage_keys: Vec<ContractStorageKeys>,
+    ) -> impl std::future::Future<Output = Result<StorageProof, ProviderError>>;
+
+    /// Get the proofs of a contract and of the given keys of its storage, a pathfinder extension
+    fn pathfinder_get_proof(
+        &self,
+        block_id: BlockId<Felt>,
+        contract_address: Felt,
+        keys: Vec<Felt>,
+    ) -> impl std::future::Future<Output = Result<PathfinderProof, ProviderError>>;
+
     /// Same as [simulate_transactions], but only with one simulation.
     fn simulate_transaction(
         &self,
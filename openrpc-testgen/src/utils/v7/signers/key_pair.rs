@@ -15,13 +15,16 @@ pub struct VerifyingKey {
 }
 
 #[cfg(not(target_arch = "wasm32"))]
-#[allow(dead_code)]
 #[derive(Debug, thiserror::Error)]
 pub enum KeystoreError {
     #[error("invalid path")]
     InvalidPath,
     #[error("invalid decrypted secret scalar")]
     InvalidScalar,
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Keystore(#[from] eth_keystore::KeystoreError),
 }
 #[allow(dead_code)]
 impl SigningKey {
@@ -47,6 +50,61 @@ impl SigningKey {
     pub fn from_secret_scalar(secret_scalar: Felt) -> Self {
         Self { secret_scalar }
     }
+
+    /// Loads the key from an encrypted keystore JSON file, in the Web3 Secret Storage format
+    /// also used by `starkli`: scrypt or PBKDF2 key derivation and AES-128-CTR encryption.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_keystore<P>(path: P, password: &str) -> Result<Self, KeystoreError>
+    where
+        P: AsRef<std::path::Path>,
+    {
+        let key = eth_keystore::decrypt_key(path, password)?;
+        let secret_scalar =
+            Felt::from_bytes_be(&key.try_into().map_err(|_| KeystoreError::InvalidScalar)?);
+
+        Ok(Self::from_secret_scalar(secret_scalar))
+    }
+
+    /// Encrypts the key into a keystore JSON file at `path`, readable with
+    /// [from_keystore](SigningKey::from_keystore).
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn save_as_keystore<P>(&self, path: P, password: &str) -> Result<(), KeystoreError>
+    where
+        P: AsRef<std::path::Path>,
+    {
+        // `eth-keystore` takes the directory and the file name separately
+        let mut path = path.as_ref().to_path_buf();
+        let file_name = path
+            .file_name()
+            .and_then(|file_name| file_name.to_str())
+            .ok_or(KeystoreError::InvalidPath)?
+            .to_owned();
+        path.pop();
+
+        let mut rng = StdRng::from_entropy();
+        eth_keystore::encrypt_key(
+            path,
+            &mut rng,
+            self.secret_scalar.to_bytes_be(),
+            password,
+            Some(&file_name),
+        )?;
+
+        Ok(())
+    }
+
+    /// Loads the key from a plain text file holding the secret scalar as a hex string.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_key_file<P>(path: P) -> Result<Self, KeystoreError>
+    where
+        P: AsRef<std::path::Path>,
+    {
+        let content = std::fs::read_to_string(path)?;
+        let secret_scalar =
+            Felt::from_hex(content.trim()).map_err(|_| KeystoreError::InvalidScalar)?;
+
+        Ok(Self::from_secret_scalar(secret_scalar))
+    }
     pub fn secret_scalar(&self) -> Felt {
         self.secret_scalar
     }
@@ -73,3 +131,40 @@ impl VerifyingKey {
     //     ecdsa_verify(&self.scalar, hash, signature)
     // }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_keystore_roundtrip() {
+        let path = std::env::temp_dir().join(format!("keystore-{}.json", rand::random::<u64>()));
+        let key = SigningKey::from_random();
+
+        key.save_as_keystore(&path, "password").unwrap();
+        let decrypted = SigningKey::from_keystore(&path, "password");
+        let wrong_password = SigningKey::from_keystore(&path, "wrong");
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(decrypted.unwrap().secret_scalar(), key.secret_scalar());
+        assert!(matches!(wrong_password, Err(KeystoreError::Keystore(_))));
+    }
+
+    #[test]
+    fn test_key_file() {
+        let path = std::env::temp_dir().join(format!("key-{}.txt", rand::random::<u64>()));
+
+        std::fs::write(&path, "0x1234\n").unwrap();
+        let key = SigningKey::from_key_file(&path);
+        std::fs::write(&path, "not a key").unwrap();
+        let invalid = SigningKey::from_key_file(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(key.unwrap().secret_scalar(), Felt::from(0x1234));
+        assert!(matches!(invalid, Err(KeystoreError::InvalidScalar)));
+        assert!(matches!(
+            SigningKey::from_key_file(std::env::temp_dir().join("missing-key-file")),
+            Err(KeystoreError::Io(_))
+        ));
+    }
+}
@@ -1,3 +1,5 @@
+#[cfg(not(target_arch = "wasm32"))]
+use super::key_pair::KeystoreError;
 use super::{
     key_pair::{SigningKey, VerifyingKey},
     signer::Signer,
@@ -30,6 +32,24 @@ impl LocalWallet {
     pub fn from_signing_key(key: SigningKey) -> Self {
         key.into()
     }
+
+    /// Wallet backed by an encrypted keystore file, see [SigningKey::from_keystore].
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_keystore<P>(path: P, password: &str) -> Result<Self, KeystoreError>
+    where
+        P: AsRef<std::path::Path>,
+    {
+        Ok(SigningKey::from_keystore(path, password)?.into())
+    }
+
+    /// Wallet backed by a plain text key file, see [SigningKey::from_key_file].
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_key_file<P>(path: P) -> Result<Self, KeystoreError>
+    where
+        P: AsRef<std::path::Path>,
+    {
+        Ok(SigningKey::from_key_file(path)?.into())
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
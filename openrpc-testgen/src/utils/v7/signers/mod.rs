@@ -1,3 +1,4 @@
 pub mod key_pair;
 pub mod local_wallet;
+pub mod remote;
 pub mod signer;
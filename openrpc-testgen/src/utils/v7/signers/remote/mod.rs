@@ -0,0 +1,143 @@
+//! Signer living in a separate process, reached over JSON-RPC 2.0 on HTTP.
+//!
+//! The protocol has two methods:
+//! - `signer_getPublicKey`, without params, returning the public key as a hex felt;
+//! - `signer_signHash`, with `{"hash": "0x..."}` params, returning `{"r": "0x...", "s": "0x..."}`.
+//!
+//! A reference server is available in [server].
+
+pub mod server;
+
+use crypto_utils::curve::signer::Signature;
+use reqwest::{Client, Url};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::json;
+use starknet_types_core::felt::Felt;
+use tracing::debug;
+
+use super::{key_pair::VerifyingKey, signer::Signer};
+use crate::utils::v7::providers::jsonrpc::{JsonRpcError, JsonRpcResponse};
+
+pub const GET_PUBLIC_KEY_METHOD: &str = "signer_getPublicKey";
+pub const SIGN_HASH_METHOD: &str = "signer_signHash";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignHashParams {
+    pub hash: Felt,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoteSignature {
+    pub r: Felt,
+    pub s: Felt,
+}
+
+/// [Signer] forwarding every request to a remote signing server. Treated as interactive by
+/// default, as each signature costs a round trip.
+#[derive(Debug, Clone)]
+pub struct RemoteSigner {
+    client: Client,
+    url: Url,
+    interactive: bool,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RemoteSignerError {
+    #[error(transparent)]
+    Reqwest(#[from] reqwest::Error),
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+    #[error("Remote signer error {}: {}", .0.code, .0.message)]
+    JsonRpc(JsonRpcError),
+}
+
+#[derive(Debug, Serialize)]
+struct JsonRpcRequest<T> {
+    id: u64,
+    jsonrpc: &'static str,
+    method: &'static str,
+    params: T,
+}
+
+impl RemoteSigner {
+    pub fn new(url: Url) -> Self {
+        Self::new_with_client(url, Client::new())
+    }
+
+    pub fn new_with_client(url: Url, client: Client) -> Self {
+        Self {
+            client,
+            url,
+            interactive: true,
+        }
+    }
+
+    /// Overrides whether the signer reports itself as interactive, e.g. to compare account
+    /// builder behaviour with and without it against the same server.
+    pub fn interactive(self, interactive: bool) -> Self {
+        Self {
+            interactive,
+            ..self
+        }
+    }
+
+    async fn send_request<P, R>(
+        &self,
+        method: &'static str,
+        params: P,
+    ) -> Result<R, RemoteSignerError>
+    where
+        P: Serialize,
+        R: DeserializeOwned,
+    {
+        let request_body = serde_json::to_string(&JsonRpcRequest {
+            id: 1,
+            jsonrpc: "2.0",
+            method,
+            params,
+        })?;
+        debug!("Sending request to remote signer: {}", request_body);
+
+        let response_body = self
+            .client
+            .post(self.url.clone())
+            .body(request_body)
+            .header("Content-Type", "application/json")
+            .send()
+            .await?
+            .text()
+            .await?;
+        debug!("Response from remote signer: {}", response_body);
+
+        match serde_json::from_str::<JsonRpcResponse<R>>(&response_body)? {
+            JsonRpcResponse::Success { result, .. } => Ok(result),
+            JsonRpcResponse::Error { error, .. } => Err(RemoteSignerError::JsonRpc(error)),
+        }
+    }
+}
+
+impl Signer for RemoteSigner {
+    type GetPublicKeyError = RemoteSignerError;
+    type SignError = RemoteSignerError;
+
+    async fn get_public_key(&self) -> Result<VerifyingKey, Self::GetPublicKeyError> {
+        let public_key: Felt = self.send_request(GET_PUBLIC_KEY_METHOD, json!([])).await?;
+
+        Ok(VerifyingKey::from_scalar(public_key))
+    }
+
+    async fn sign_hash(&self, hash: &Felt) -> Result<Signature, Self::SignError> {
+        let signature: RemoteSignature = self
+            .send_request(SIGN_HASH_METHOD, SignHashParams { hash: *hash })
+            .await?;
+
+        Ok(Signature {
+            r: signature.r,
+            s: signature.s,
+        })
+    }
+
+    fn is_interactive(&self) -> bool {
+        self.interactive
+    }
+}
@@ -0,0 +1,178 @@
+//! Minimal reference server for [RemoteSigner](super::RemoteSigner), signing with a single
+//! [SigningKey]. Each connection carries one request and is closed after the response.
+
+use serde::Deserialize;
+use serde_json::{json, Value};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tracing::{info, warn};
+
+use super::{RemoteSignature, SignHashParams, GET_PUBLIC_KEY_METHOD, SIGN_HASH_METHOD};
+use crate::utils::v7::signers::key_pair::SigningKey;
+
+const PARSE_ERROR: i64 = -32700;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+const INTERNAL_ERROR: i64 = -32603;
+
+#[derive(Debug, Deserialize)]
+struct RemoteSignerRequest {
+    #[serde(default)]
+    id: Value,
+    method: String,
+    #[serde(default)]
+    params: Value,
+}
+
+/// Accepts connections on `listener` until it fails, answering signing requests with
+/// `signing_key`.
+pub async fn serve(listener: TcpListener, signing_key: SigningKey) -> std::io::Result<()> {
+    info!("Remote signer is running on {}", listener.local_addr()?);
+
+    loop {
+        let (stream, _) = listener.accept().await?;
+
+        tokio::spawn(async move {
+            if let Err(e) = handle_connection(stream, &signing_key).await {
+                warn!("Error handling remote signer connection: {:?}", e);
+            }
+        });
+    }
+}
+
+async fn handle_connection(mut stream: TcpStream, signing_key: &SigningKey) -> std::io::Result<()> {
+    let mut buf_reader = BufReader::new(&mut stream);
+
+    let mut request_header = Vec::new();
+    loop {
+        let mut line = String::new();
+        buf_reader.read_line(&mut line).await?;
+        if line == "\r\n" || line.is_empty() {
+            break;
+        }
+        request_header.push(line);
+    }
+
+    let content_length = request_header
+        .iter()
+        .find(|line| line.to_lowercase().starts_with("content-length:"))
+        .and_then(|line| line.split_whitespace().nth(1))
+        .and_then(|length| length.parse::<u64>().ok())
+        .unwrap_or_default();
+
+    let mut request_body = String::new();
+    buf_reader
+        .take(content_length)
+        .read_to_string(&mut request_body)
+        .await?;
+
+    let response_body = handle_request(signing_key, &request_body).to_string();
+    let response = format!(
+        "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response_body.len(),
+        response_body
+    );
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await
+}
+
+/// JSON-RPC response to a single request body.
+pub fn handle_request(signing_key: &SigningKey, request_body: &str) -> Value {
+    let request: RemoteSignerRequest = match serde_json::from_str(request_body) {
+        Ok(request) => request,
+        Err(e) => return error_response(Value::Null, PARSE_ERROR, &e.to_string()),
+    };
+
+    match request.method.as_str() {
+        GET_PUBLIC_KEY_METHOD => {
+            success_response(request.id, json!(signing_key.verifying_key().scalar()))
+        }
+        SIGN_HASH_METHOD => {
+            let params: SignHashParams = match serde_json::from_value(request.params) {
+                Ok(params) => params,
+                Err(e) => return error_response(request.id, INVALID_PARAMS, &e.to_string()),
+            };
+
+            match signing_key.sign(&params.hash) {
+                Ok(signature) => success_response(
+                    request.id,
+                    json!(RemoteSignature {
+                        r: signature.r,
+                        s: signature.s,
+                    }),
+                ),
+                Err(e) => error_response(request.id, INTERNAL_ERROR, &e.to_string()),
+            }
+        }
+        method => error_response(
+            request.id,
+            METHOD_NOT_FOUND,
+            &format!("Method {method} not found"),
+        ),
+    }
+}
+
+fn success_response(id: Value, result: Value) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "result": result })
+}
+
+fn error_response(id: Value, code: i64, message: &str) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "error": { "code": code, "message": message } })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use starknet_types_core::felt::Felt;
+
+    #[test]
+    fn test_get_public_key() {
+        let signing_key = SigningKey::from_secret_scalar(Felt::from(1234));
+
+        let response = handle_request(
+            &signing_key,
+            r#"{"jsonrpc":"2.0","id":1,"method":"signer_getPublicKey","params":[]}"#,
+        );
+
+        assert_eq!(
+            serde_json::from_value::<Felt>(response["result"].clone()).unwrap(),
+            signing_key.verifying_key().scalar()
+        );
+    }
+
+    #[test]
+    fn test_sign_hash() {
+        let signing_key = SigningKey::from_secret_scalar(Felt::from(1234));
+        let hash = Felt::from(5678);
+
+        let response = handle_request(
+            &signing_key,
+            &json!({
+                "jsonrpc": "2.0",
+                "id": 7,
+                "method": "signer_signHash",
+                "params": { "hash": hash }
+            })
+            .to_string(),
+        );
+        let signature: RemoteSignature =
+            serde_json::from_value(response["result"].clone()).unwrap();
+        let expected = signing_key.sign(&hash).unwrap();
+
+        assert_eq!(response["id"], json!(7));
+        assert_eq!(signature.r, expected.r);
+        assert_eq!(signature.s, expected.s);
+    }
+
+    #[test]
+    fn test_unknown_method() {
+        let signing_key = SigningKey::from_secret_scalar(Felt::from(1234));
+
+        let response = handle_request(
+            &signing_key,
+            r#"{"jsonrpc":"2.0","id":1,"method":"signer_signTransaction"}"#,
+        );
+
+        assert_eq!(response["error"]["code"], json!(METHOD_NOT_FOUND));
+    }
+}
@@ -0,0 +1,299 @@
+//! Verification of the proofs served by `starknet_getStorageProof` and `pathfinder_getProof`,
+//! using the standalone Merkle-Patricia proof verifier of `production-nodes-types`.
+//!
+//! Proofs are checked against the roots they are served with, which in turn hash up to the
+//! state commitment returned to the caller, to be compared with the `new_root` of the block.
+
+use production_nodes_types::pathfinder_types::types::{
+    hash::{FeltHash, PedersenHash, PoseidonHash},
+    proof::{contract_state_hash, edge_node, key_path, proven_value, state_commitment},
+    trie_node::TrieNode,
+};
+use starknet_types_core::felt::Felt;
+
+use super::providers::provider::{
+    ContractStorageKeys, MerkleNode, NodeHashToNode, PathfinderProof, ProofNode, StorageProof,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum StorageProofError {
+    #[error("Proof node reported with hash {reported:#x} hashes to {computed:#x}")]
+    NodeHash { reported: Felt, computed: Felt },
+    #[error("Edge node has an invalid path {path:#x} of length {length}")]
+    InvalidEdge { path: Felt, length: usize },
+    #[error("Proof of key {key:#x} in the {trie} trie doesn't hash up to root {root:#x}")]
+    InvalidProof {
+        trie: &'static str,
+        key: Felt,
+        root: Felt,
+    },
+    #[error("{field} reported by the node is {reported:#x}, proven {proven:#x}")]
+    Mismatch {
+        field: String,
+        reported: Felt,
+        proven: Felt,
+    },
+    #[error("Expected {expected} {items}, got {actual}")]
+    Length {
+        items: &'static str,
+        expected: usize,
+        actual: usize,
+    },
+    #[error("Storage proofs of contract {0:#x} requested without the contract")]
+    MissingContract(Felt),
+    #[error("Unsupported contract state hash version {0:#x}")]
+    UnsupportedVersion(Felt),
+}
+
+/// State proven by a verified proof.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProvenState {
+    /// Global state root the proofs hash up to.
+    pub state_commitment: Felt,
+    /// Leaf of each requested class in the classes trie, zero for undeclared classes.
+    pub class_leaves: Vec<Felt>,
+    /// Values of the requested storage keys of each contract, zero for unset keys.
+    pub storage: Vec<Vec<Felt>>,
+}
+
+/// Verifies a `starknet_getStorageProof` result against the request it answers.
+///
+/// The leaves of the requested contracts must be served, so the contracts of
+/// `contracts_storage_keys` must also be in `contract_addresses` for their storage roots to be
+/// known.
+pub fn verify_storage_proof(
+    proof: &StorageProof,
+    class_hashes: &[Felt],
+    contract_addresses: &[Felt],
+    contracts_storage_keys: &[ContractStorageKeys],
+) -> Result<ProvenState, StorageProofError> {
+    let roots = &proof.global_roots;
+
+    let classes_proof = served_trie_nodes::<PoseidonHash>(&proof.classes_proof)?;
+    let class_leaves = class_hashes
+        .iter()
+        .map(|&class_hash| {
+            proven::<PoseidonHash>(
+                "classes",
+                roots.classes_tree_root,
+                class_hash,
+                &classes_proof,
+            )
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let leaves = &proof.contracts_proof.contract_leaves_data;
+    check_length("contract leaves", contract_addresses.len(), leaves.len())?;
+    let contracts_proof = served_trie_nodes::<PedersenHash>(&proof.contracts_proof.nodes)?;
+    for (&contract_address, leaf) in contract_addresses.iter().zip(leaves) {
+        let proven = proven::<PedersenHash>(
+            "contracts",
+            roots.contracts_tree_root,
+            contract_address,
+            &contracts_proof,
+        )?;
+        check_proven(
+            format!("Leaf of contract {:#x}", contract_address),
+            contract_state_hash(leaf.class_hash, leaf.storage_root, leaf.nonce),
+            proven,
+        )?;
+    }
+
+    check_length(
+        "contract storage proofs",
+        contracts_storage_keys.len(),
+        proof.contracts_storage_proofs.len(),
+    )?;
+    let storage = contracts_storage_keys
+        .iter()
+        .zip(&proof.contracts_storage_proofs)
+        .map(|(keys, storage_proof)| {
+            let leaf = contract_addresses
+                .iter()
+                .position(|&address| address == keys.contract_address)
+                .map(|index| &leaves[index])
+                .ok_or(StorageProofError::MissingContract(keys.contract_address))?;
+            let storage_proof = served_trie_nodes::<PedersenHash>(storage_proof)?;
+
+            keys.storage_keys
+                .iter()
+                .map(|&key| {
+                    proven::<PedersenHash>("storage", leaf.storage_root, key, &storage_proof)
+                })
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(ProvenState {
+        state_commitment: state_commitment(roots.contracts_tree_root, roots.classes_tree_root),
+        class_leaves,
+        storage,
+    })
+}
+
+/// Verifies a `pathfinder_getProof` result for the contract and the storage `keys` it was
+/// requested for. The proven storage holds the values of the keys, empty if no contract is
+/// deployed at the address.
+pub fn verify_pathfinder_proof(
+    proof: &PathfinderProof,
+    contract_address: Felt,
+    keys: &[Felt],
+) -> Result<ProvenState, StorageProofError> {
+    let contract_proof = pathfinder_trie_nodes(&proof.contract_proof)?;
+    // Nodes are ordered from the root, an empty proof standing for an empty contracts trie.
+    let contracts_root = contract_proof
+        .first()
+        .map(TrieNode::hash::<PedersenHash>)
+        .unwrap_or_default();
+
+    let computed_commitment =
+        state_commitment(contracts_root, proof.class_commitment.unwrap_or_default());
+    if let Some(reported) = proof.state_commitment {
+        check_proven(
+            "State commitment".to_string(),
+            reported,
+            computed_commitment,
+        )?;
+    }
+
+    let proven_leaf = proven::<PedersenHash>(
+        "contracts",
+        contracts_root,
+        contract_address,
+        &contract_proof,
+    )?;
+
+    let Some(contract_data) = &proof.contract_data else {
+        check_proven(
+            format!("Leaf of undeployed contract {:#x}", contract_address),
+            Felt::ZERO,
+            proven_leaf,
+        )?;
+        return Ok(ProvenState {
+            state_commitment: computed_commitment,
+            class_leaves: vec![],
+            storage: vec![],
+        });
+    };
+
+    if contract_data.contract_state_hash_version != Felt::ZERO {
+        return Err(StorageProofError::UnsupportedVersion(
+            contract_data.contract_state_hash_version,
+        ));
+    }
+    check_proven(
+        format!("Leaf of contract {:#x}", contract_address),
+        contract_state_hash(
+            contract_data.class_hash,
+            contract_data.root,
+            contract_data.nonce,
+        ),
+        proven_leaf,
+    )?;
+
+    check_length(
+        "storage proofs",
+        keys.len(),
+        contract_data.storage_proofs.len(),
+    )?;
+    let storage = keys
+        .iter()
+        .zip(&contract_data.storage_proofs)
+        .map(|(&key, storage_proof)| {
+            let storage_proof = pathfinder_trie_nodes(storage_proof)?;
+            proven::<PedersenHash>("storage", contract_data.root, key, &storage_proof)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(ProvenState {
+        state_commitment: computed_commitment,
+        class_leaves: vec![],
+        storage: vec![storage],
+    })
+}
+
+/// Converts the served nodes, checking the hash reported for each of them.
+fn served_trie_nodes<H: FeltHash>(
+    nodes: &[NodeHashToNode],
+) -> Result<Vec<TrieNode>, StorageProofError> {
+    nodes
+        .iter()
+        .map(|served| {
+            let node = match served.node {
+                MerkleNode::Binary { left, right } => TrieNode::Binary { left, right },
+                MerkleNode::Edge {
+                    path,
+                    length,
+                    child,
+                } => edge_node(child, path, length)
+                    .ok_or(StorageProofError::InvalidEdge { path, length })?,
+            };
+
+            let computed = node.hash::<H>();
+            if computed != served.node_hash {
+                return Err(StorageProofError::NodeHash {
+                    reported: served.node_hash,
+                    computed,
+                });
+            }
+
+            Ok(node)
+        })
+        .collect()
+}
+
+fn pathfinder_trie_nodes(nodes: &[ProofNode]) -> Result<Vec<TrieNode>, StorageProofError> {
+    nodes
+        .iter()
+        .map(|node| match *node {
+            ProofNode::Binary { left, right } => Ok(TrieNode::Binary { left, right }),
+            ProofNode::Edge { child, path } => {
+                edge_node(child, path.value, path.len).ok_or(StorageProofError::InvalidEdge {
+                    path: path.value,
+                    length: path.len,
+                })
+            }
+        })
+        .collect()
+}
+
+fn proven<H: FeltHash>(
+    trie: &'static str,
+    root: Felt,
+    key: Felt,
+    proof: &[TrieNode],
+) -> Result<Felt, StorageProofError> {
+    proven_value::<H>(root, &key_path(key), proof).ok_or(StorageProofError::InvalidProof {
+        trie,
+        key,
+        root,
+    })
+}
+
+fn check_proven(field: String, reported: Felt, proven: Felt) -> Result<(), StorageProofError> {
+    if reported != proven {
+        return Err(StorageProofError::Mismatch {
+            field,
+            reported,
+            proven,
+        });
+    }
+
+    Ok(())
+}
+
+fn check_length(
+    items: &'static str,
+    expected: usize,
+    actual: usize,
+) -> Result<(), StorageProofError> {
+    if expected != actual {
+        return Err(StorageProofError::Length {
+            items,
+            expected,
+            actual,
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,401 @@
+use std::collections::HashMap;
+
+use serde_json::Value;
+use starknet_types_core::felt::Felt;
+
+use super::{Revision, TypeMember, TypedData, TypedDataError};
+use crate::utils::v7::accounts::account::{cairo_short_string_to_felt, starknet_keccak};
+
+/// Encodes types and values of a single [TypedData], with the preset types of its revision
+/// merged into the user defined ones.
+pub(crate) struct Encoder {
+    types: HashMap<String, Vec<TypeMember>>,
+    revision: Revision,
+}
+
+impl Encoder {
+    pub(crate) fn new(typed_data: &TypedData) -> Result<Self, TypedDataError> {
+        let revision = typed_data.revision()?;
+        let mut types = typed_data.types.clone();
+
+        if revision == Revision::V1 {
+            for (name, members) in preset_types() {
+                types.entry(name.to_string()).or_insert(members);
+            }
+        }
+
+        Ok(Self { types, revision })
+    }
+
+    pub(crate) fn encode_type(&self, type_name: &str) -> Result<String, TypedDataError> {
+        if !self.types.contains_key(type_name) {
+            return Err(TypedDataError::UnknownType(type_name.to_string()));
+        }
+
+        let mut dependencies = vec![];
+        self.collect_dependencies(type_name, None, &mut dependencies);
+        dependencies[1..].sort();
+
+        let escape = |name: &str| match self.revision {
+            Revision::V0 => name.to_string(),
+            Revision::V1 => format!("\"{}\"", name),
+        };
+
+        let mut encoded = String::new();
+        for dependency in dependencies {
+            let members = self.types[&dependency]
+                .iter()
+                .map(|member| {
+                    let target = match (self.revision, member.type_name.as_str()) {
+                        (Revision::V1, "enum") => member.contains.clone().unwrap_or_default(),
+                        _ => member.type_name.clone(),
+                    };
+                    let target = match tuple_elements(&target) {
+                        Some(elements) => format!(
+                            "({})",
+                            elements
+                                .iter()
+                                .map(|element| if element.is_empty() {
+                                    String::new()
+                                } else {
+                                    escape(element)
+                                })
+                                .collect::<Vec<_>>()
+                                .join(",")
+                        ),
+                        None => escape(&target),
+                    };
+                    format!("{}:{}", escape(&member.name), target)
+                })
+                .collect::<Vec<_>>()
+                .join(",");
+
+            encoded.push_str(&format!("{}({})", escape(&dependency), members));
+        }
+
+        Ok(encoded)
+    }
+
+    pub(crate) fn type_hash(&self, type_name: &str) -> Result<Felt, TypedDataError> {
+        Ok(starknet_keccak(self.encode_type(type_name)?.as_bytes()))
+    }
+
+    pub(crate) fn struct_hash(
+        &self,
+        type_name: &str,
+        data: &Value,
+    ) -> Result<Felt, TypedDataError> {
+        let members = self
+            .types
+            .get(type_name)
+            .ok_or_else(|| TypedDataError::UnknownType(type_name.to_string()))?;
+        let object = data
+            .as_object()
+            .ok_or_else(|| invalid_value(type_name, data))?;
+
+        let mut elements = vec![self.type_hash(type_name)?];
+        for member in members {
+            let value = object
+                .get(&member.name)
+                .filter(|value| !value.is_null() || member.type_name == "enum")
+                .ok_or_else(|| {
+                    TypedDataError::MissingValue(format!("{}.{}", type_name, member.name))
+                })?;
+
+            elements.push(self.encode_value(
+                &member.type_name,
+                member.contains.as_deref(),
+                value,
+            )?);
+        }
+
+        Ok(self.revision.hash_array(&elements))
+    }
+
+    fn encode_value(
+        &self,
+        type_name: &str,
+        contains: Option<&str>,
+        value: &Value,
+    ) -> Result<Felt, TypedDataError> {
+        if self.revision == Revision::V1 && type_name == "enum" {
+            return self.encode_enum(contains.unwrap_or_default(), value);
+        }
+
+        if type_name == "merkletree" {
+            return self.encode_merkle_tree(contains.unwrap_or_default(), value);
+        }
+
+        if self.types.contains_key(type_name) {
+            return self.struct_hash(type_name, value);
+        }
+
+        if let Some(element_type) = type_name.strip_suffix('*') {
+            let elements = value
+                .as_array()
+                .ok_or_else(|| invalid_value(type_name, value))?
+                .iter()
+                .map(|element| self.encode_value(element_type, None, element))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            return Ok(self.revision.hash_array(&elements));
+        }
+
+        match (self.revision, type_name) {
+            (_, "selector") => selector_value(value),
+            (Revision::V1, "string") => byte_array_hash(value),
+            (Revision::V1, "bool") => match value {
+                Value::Bool(value) => Ok(Felt::from(*value)),
+                _ => Err(invalid_value(type_name, value)),
+            },
+            (Revision::V1, "u128" | "timestamp") => {
+                let felt = felt_value(type_name, value)?;
+                if felt.bits() <= 128 {
+                    Ok(felt)
+                } else {
+                    Err(invalid_value(type_name, value))
+                }
+            }
+            (Revision::V1, "i128") => {
+                let felt = felt_value(type_name, value)?;
+                let fits = felt.bits() <= 127
+                    || (-felt).bits() <= 127
+                    || -felt == Felt::from(i128::MIN.unsigned_abs());
+                if fits {
+                    Ok(felt)
+                } else {
+                    Err(invalid_value(type_name, value))
+                }
+            }
+            (Revision::V1, "felt" | "shortstring" | "ContractAddress" | "ClassHash")
+            | (Revision::V0, _) => felt_value(type_name, value),
+            (Revision::V1, _) => Err(TypedDataError::UnknownType(type_name.to_string())),
+        }
+    }
+
+    /// Hashes the index of the selected variant together with its encoded parameters. Unit
+    /// variants, declared as `()`, contribute a single zero parameter, as in `starknet.js`.
+    fn encode_enum(&self, enum_name: &str, value: &Value) -> Result<Felt, TypedDataError> {
+        let variants = self
+            .types
+            .get(enum_name)
+            .ok_or_else(|| TypedDataError::UnknownType(enum_name.to_string()))?;
+        let (variant, data) = value
+            .as_object()
+            .and_then(|object| object.iter().next())
+            .ok_or_else(|| invalid_value(enum_name, value))?;
+
+        let (index, variant_type) = variants
+            .iter()
+            .enumerate()
+            .find(|(_, member)| &member.name == variant)
+            .ok_or_else(|| TypedDataError::UnknownVariant {
+                enum_name: enum_name.to_string(),
+                variant: variant.clone(),
+            })?;
+        let parameters = tuple_elements(&variant_type.type_name)
+            .ok_or_else(|| TypedDataError::UnknownType(variant_type.type_name.clone()))?;
+
+        let mut elements = vec![Felt::from(index)];
+        for (position, parameter) in parameters.iter().enumerate() {
+            if parameter.is_empty() {
+                elements.push(Felt::ZERO);
+                continue;
+            }
+            let parameter_value = data.get(position).ok_or_else(|| {
+                TypedDataError::MissingValue(format!("{}.{}", enum_name, variant))
+            })?;
+            elements.push(self.encode_value(parameter, None, parameter_value)?);
+        }
+
+        Ok(self.revision.hash_array(&elements))
+    }
+
+    /// Root of a Merkle tree built from the encoded leaves, hashing sorted pairs and pairing an
+    /// odd leaf out with zero.
+    fn encode_merkle_tree(&self, leaf_type: &str, value: &Value) -> Result<Felt, TypedDataError> {
+        let mut level = value
+            .as_array()
+            .filter(|leaves| !leaves.is_empty())
+            .ok_or_else(|| invalid_value("merkletree", value))?
+            .iter()
+            .map(|leaf| self.encode_value(leaf_type, None, leaf))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        while level.len() > 1 {
+            level = level
+                .chunks(2)
+                .map(|pair| {
+                    let (a, b) = (pair[0], pair.get(1).copied().unwrap_or(Felt::ZERO));
+                    self.revision.hash(&a.min(b), &a.max(b))
+                })
+                .collect();
+        }
+
+        Ok(level[0])
+    }
+
+    fn collect_dependencies(
+        &self,
+        type_name: &str,
+        contains: Option<&str>,
+        found: &mut Vec<String>,
+    ) {
+        let candidates: Vec<String> = if let Some(element_type) = type_name.strip_suffix('*') {
+            vec![element_type.to_string()]
+        } else if self.revision == Revision::V1 && type_name == "enum" {
+            contains.map(str::to_string).into_iter().collect()
+        } else if let (Revision::V1, Some(elements)) = (self.revision, tuple_elements(type_name)) {
+            elements
+                .iter()
+                .map(|element| element.trim_end_matches('*').to_string())
+                .collect()
+        } else {
+            vec![type_name.to_string()]
+        };
+
+        for candidate in candidates {
+            if found.contains(&candidate) {
+                continue;
+            }
+            let Some(members) = self.types.get(&candidate) else {
+                continue;
+            };
+
+            found.push(candidate);
+            for member in members {
+                self.collect_dependencies(&member.type_name, member.contains.as_deref(), found);
+            }
+        }
+    }
+}
+
+fn preset_types() -> Vec<(&'static str, Vec<TypeMember>)> {
+    vec![
+        (
+            "u256",
+            vec![
+                TypeMember::new("low", "u128"),
+                TypeMember::new("high", "u128"),
+            ],
+        ),
+        (
+            "TokenAmount",
+            vec![
+                TypeMember::new("token_address", "ContractAddress"),
+                TypeMember::new("amount", "u256"),
+            ],
+        ),
+        (
+            "NftId",
+            vec![
+                TypeMember::new("collection_address", "ContractAddress"),
+                TypeMember::new("token_id", "u256"),
+            ],
+        ),
+    ]
+}
+
+/// Elements of a tuple type like `(felt,u128*)`, or `None` if the type is not a tuple.
+fn tuple_elements(type_name: &str) -> Option<Vec<String>> {
+    type_name
+        .strip_prefix('(')
+        .and_then(|inner| inner.strip_suffix(')'))
+        .map(|inner| inner.split(',').map(str::to_string).collect())
+}
+
+fn invalid_value(type_name: &str, value: &Value) -> TypedDataError {
+    TypedDataError::InvalidValue {
+        type_name: type_name.to_string(),
+        value: value.to_string(),
+    }
+}
+
+/// Numbers, hex and decimal strings are taken as is, negative decimals as their field element
+/// (e.g. for `i128`), any other string is encoded as a Cairo short string.
+fn felt_value(type_name: &str, value: &Value) -> Result<Felt, TypedDataError> {
+    let felt = match value {
+        Value::Bool(value) => Some(Felt::from(*value)),
+        Value::Number(number) => number
+            .as_u64()
+            .map(Felt::from)
+            .or_else(|| number.as_i64().map(Felt::from)),
+        Value::String(string) if string.starts_with("0x") || string.starts_with("0X") => {
+            Felt::from_hex(string).ok()
+        }
+        Value::String(string) if is_decimal(string) => Felt::from_dec_str(string).ok(),
+        Value::String(string) if string.strip_prefix('-').is_some_and(is_decimal) => {
+            Felt::from_dec_str(&string[1..]).ok().map(|felt| -felt)
+        }
+        Value::String(string) => cairo_short_string_to_felt(string).ok(),
+        _ => None,
+    };
+
+    felt.ok_or_else(|| invalid_value(type_name, value))
+}
+
+fn is_decimal(string: &str) -> bool {
+    !string.is_empty() && string.chars().all(|c| c.is_ascii_digit())
+}
+
+fn selector_value(value: &Value) -> Result<Felt, TypedDataError> {
+    match value {
+        Value::String(name) if !name.starts_with("0x") => Ok(starknet_keccak(name.as_bytes())),
+        _ => felt_value("selector", value),
+    }
+}
+
+/// Poseidon hash of the string serialized as a Cairo `ByteArray`.
+fn byte_array_hash(value: &Value) -> Result<Felt, TypedDataError> {
+    let string = value
+        .as_str()
+        .ok_or_else(|| invalid_value("string", value))?;
+    let bytes = string.as_bytes();
+
+    let full_words = bytes.chunks_exact(31);
+    let pending_word = full_words.remainder();
+
+    let mut elements = vec![Felt::from(full_words.len())];
+    elements.extend(full_words.map(Felt::from_bytes_be_slice));
+    elements.push(Felt::from_bytes_be_slice(pending_word));
+    elements.push(Felt::from(pending_word.len()));
+
+    Ok(Revision::V1.hash_array(&elements))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_felt_value() {
+        assert_eq!(felt_value("felt", &json!(10)).unwrap(), Felt::from(10));
+        assert_eq!(felt_value("felt", &json!("10")).unwrap(), Felt::from(10));
+        assert_eq!(felt_value("felt", &json!("0xa")).unwrap(), Felt::from(10));
+        assert_eq!(felt_value("i128", &json!("-10")).unwrap(), -Felt::from(10));
+        assert_eq!(
+            felt_value("felt", &json!("Cow")).unwrap(),
+            Felt::from_hex_unchecked("0x436f77")
+        );
+        assert!(felt_value("felt", &json!({})).is_err());
+    }
+
+    #[test]
+    fn test_tuple_elements() {
+        assert_eq!(tuple_elements("()"), Some(vec![String::new()]));
+        assert_eq!(
+            tuple_elements("(felt,u128*)"),
+            Some(vec!["felt".to_string(), "u128*".to_string()])
+        );
+        assert_eq!(tuple_elements("felt"), None);
+    }
+
+    #[test]
+    fn test_byte_array_hash_of_empty_string() {
+        assert_eq!(
+            byte_array_hash(&json!("")).unwrap(),
+            Revision::V1.hash_array(&[Felt::ZERO, Felt::ZERO, Felt::ZERO])
+        );
+    }
+}
@@ -8,6 +8,7 @@ pub mod gateway_state_update;
 pub mod hash;
 pub mod header;
 pub mod merkle_node;
+pub mod proof;
 pub mod receipt;
 pub mod reply;
 pub mod serde_utils;
@@ -0,0 +1,439 @@
+//! Standalone verification of Merkle-Patricia proofs, such as the ones served by
+//! `starknet_getStorageProof` and `pathfinder_getProof`.
+//!
+//! The contracts trie and the storage tries of contracts hash their nodes with
+//! [Pedersen](PedersenHash), the classes trie with [Poseidon](PoseidonHash). A proof holds the
+//! nodes on the path from the root towards a key, so verifying it only requires a trusted root.
+
+use std::collections::HashMap;
+
+use bitvec::prelude::{BitSlice, BitVec, BitView, Msb0};
+use starknet_types_core::felt::Felt;
+use starknet_types_core::hash::{Pedersen, Poseidon, StarkHash};
+
+use super::hash::{FeltHash, PedersenHash, PoseidonHash};
+use super::trie_node::TrieNode;
+
+/// Height of the Starknet tries, i.e. the number of bits of their keys.
+pub const TRIE_HEIGHT: usize = 251;
+
+/// What a valid proof shows about its key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Membership {
+    /// The key is in the trie, with the expected value.
+    Member,
+    /// The key is not in the trie.
+    NonMember,
+}
+
+/// Path of `key` in a trie, i.e. its lowest [TRIE_HEIGHT] bits.
+pub fn key_path(key: Felt) -> BitVec<u8, Msb0> {
+    key.to_bytes_be().view_bits::<Msb0>()[256 - TRIE_HEIGHT..].to_bitvec()
+}
+
+/// Edge node whose path is the lowest `length` bits of `path`, the way nodes serve edges.
+/// Returns `None` if the length is out of the trie height or `path` has higher bits set.
+pub fn edge_node(child: Felt, path: Felt, length: usize) -> Option<TrieNode> {
+    if length == 0 || length > TRIE_HEIGHT {
+        return None;
+    }
+
+    let bytes = path.to_bytes_be();
+    let bits = bytes.view_bits::<Msb0>();
+    if bits[..256 - length].any() {
+        return None;
+    }
+
+    Some(TrieNode::Edge {
+        child,
+        path: bits[256 - length..].to_bitvec(),
+    })
+}
+
+/// Walks the proof from `root` along `key` and returns the value of the leaf, or zero if the
+/// proof shows that the key is not in the trie.
+///
+/// Nodes are looked up by their hash computed with `H`, so the order of the proof doesn't
+/// matter. Returns `None` if a node on the path is missing from the proof, i.e. the proof does
+/// not hash up to `root`.
+pub fn proven_value<H: FeltHash>(
+    root: Felt,
+    key: &BitSlice<u8, Msb0>,
+    proof: &[TrieNode],
+) -> Option<Felt> {
+    let nodes: HashMap<Felt, &TrieNode> =
+        proof.iter().map(|node| (node.hash::<H>(), node)).collect();
+
+    // An empty trie has a root of zero.
+    if root == Felt::ZERO {
+        return Some(Felt::ZERO);
+    }
+
+    let mut next = root;
+    let mut height = 0;
+    while height < key.len() {
+        match nodes.get(&next)? {
+            TrieNode::Binary { left, right } => {
+                next = if key[height] { *right } else { *left };
+                height += 1;
+            }
+            TrieNode::Edge { child, path } => {
+                let end = height + path.len();
+                if path.is_empty() || end > key.len() {
+                    return None;
+                }
+                // The edge leads elsewhere, so the key is not in the trie.
+                if key[height..end] != path[..] {
+                    return Some(Felt::ZERO);
+                }
+                next = *child;
+                height = end;
+            }
+        }
+    }
+
+    Some(next)
+}
+
+/// Verifies that the proof shows `key` mapping to `value` in the trie with the given root, a
+/// `value` of zero standing for the key not being in the trie.
+///
+/// Returns `None` if the proof is invalid or shows another value.
+pub fn verify_proof<H: FeltHash>(
+    root: Felt,
+    key: &BitSlice<u8, Msb0>,
+    value: Felt,
+    proof: &[TrieNode],
+) -> Option<Membership> {
+    let proven = proven_value::<H>(root, key, proof)?;
+
+    match (proven == value, value == Felt::ZERO) {
+        (true, false) => Some(Membership::Member),
+        (true, true) => Some(Membership::NonMember),
+        (false, _) => None,
+    }
+}
+
+/// [verify_proof] for the contracts trie and the storage tries of contracts.
+pub fn verify_pedersen_proof(
+    root: Felt,
+    key: Felt,
+    value: Felt,
+    proof: &[TrieNode],
+) -> Option<Membership> {
+    verify_proof::<PedersenHash>(root, &key_path(key), value, proof)
+}
+
+/// [verify_proof] for the classes trie.
+pub fn verify_poseidon_proof(
+    root: Felt,
+    key: Felt,
+    value: Felt,
+    proof: &[TrieNode],
+) -> Option<Membership> {
+    verify_proof::<PoseidonHash>(root, &key_path(key), value, proof)
+}
+
+/// Leaf of a contract in the contracts trie.
+pub fn contract_state_hash(class_hash: Felt, storage_root: Felt, nonce: Felt) -> Felt {
+    const CONTRACT_STATE_HASH_VERSION: Felt = Felt::ZERO;
+
+    let hash = Pedersen::hash(&class_hash, &storage_root);
+    let hash = Pedersen::hash(&hash, &nonce);
+    Pedersen::hash(&hash, &CONTRACT_STATE_HASH_VERSION)
+}
+
+/// Leaf of a Sierra class in the classes trie.
+pub fn class_leaf_hash(compiled_class_hash: Felt) -> Felt {
+    let contract_class_leaf_v0 = Felt::from_bytes_be_slice(b"CONTRACT_CLASS_LEAF_V0");
+    Poseidon::hash(&contract_class_leaf_v0, &compiled_class_hash)
+}
+
+/// Global state root, committing to the contracts and classes tries. It is the root of the
+/// contracts trie alone while the classes trie is empty.
+pub fn state_commitment(contracts_root: Felt, classes_root: Felt) -> Felt {
+    if classes_root == Felt::ZERO {
+        return contracts_root;
+    }
+
+    let starknet_state_v0 = Felt::from_bytes_be_slice(b"STARKNET_STATE_V0");
+    Poseidon::hash_array(&[starknet_state_v0, contracts_root, classes_root])
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use bitvec::prelude::*;
+    use starknet_types_core::felt::Felt;
+
+    use crate::pathfinder_types::types::hash::{FeltHash, PedersenHash, PoseidonHash};
+    use crate::pathfinder_types::types::proof::{
+        class_leaf_hash, edge_node, key_path, verify_pedersen_proof, verify_poseidon_proof,
+        Membership, TRIE_HEIGHT,
+    };
+    use crate::pathfinder_types::types::storage::Storage;
+    use crate::pathfinder_types::types::tree::MerkleTree;
+    use crate::pathfinder_types::types::trie::{Node, NodeRef, StoredNode};
+    use crate::pathfinder_types::types::trie_node::TrieNode;
+
+    /// Nodes of a committed tree, indexed in the order the commit added them, and its leaves by
+    /// path.
+    #[derive(Default)]
+    struct MemoryStorage {
+        nodes: Vec<(Felt, StoredNode)>,
+        leaves: HashMap<BitVec<u8, Msb0>, Felt>,
+    }
+
+    impl Storage for MemoryStorage {
+        fn get(&self, index: u64) -> anyhow::Result<Option<StoredNode>> {
+            Ok(self.nodes.get(index as usize).map(|(_, node)| node.clone()))
+        }
+
+        fn hash(&self, index: u64) -> anyhow::Result<Option<Felt>> {
+            Ok(self.nodes.get(index as usize).map(|(hash, _)| *hash))
+        }
+
+        fn leaf(&self, path: &BitSlice<u8, Msb0>) -> anyhow::Result<Option<Felt>> {
+            Ok(self.leaves.get(&path.to_bitvec()).copied())
+        }
+    }
+
+    /// Builds and commits a tree holding `leaves`, returning its root and the storage the root
+    /// is the last node of.
+    fn commit_tree<H: FeltHash>(leaves: &[(Felt, Felt)]) -> (Felt, MemoryStorage) {
+        let mut storage = MemoryStorage::default();
+        let mut tree = MerkleTree::<H, TRIE_HEIGHT>::empty();
+        for (key, value) in leaves {
+            tree.set(&storage, key_path(*key), *value).unwrap();
+            storage.leaves.insert(key_path(*key), *value);
+        }
+        let update = tree.commit(&storage).unwrap();
+
+        let index = |node: NodeRef| match node {
+            NodeRef::StorageIndex(index) => index,
+            NodeRef::Index(index) => index as u64,
+        };
+        for (hash, node) in update.nodes_added {
+            let node = match node {
+                Node::Binary { left, right } => StoredNode::Binary {
+                    left: index(left),
+                    right: index(right),
+                },
+                Node::Edge { child, path } => StoredNode::Edge {
+                    child: index(child),
+                    path,
+                },
+                Node::LeafBinary => StoredNode::LeafBinary,
+                Node::LeafEdge { path } => StoredNode::LeafEdge { path },
+            };
+            storage.nodes.push((hash, node));
+        }
+
+        (update.root_commitment, storage)
+    }
+
+    /// Proof of `key` served by [MerkleTree::get_proof] from a tree committed by [commit_tree].
+    fn tree_proof<H: FeltHash>(storage: &MemoryStorage, key: Felt) -> Vec<TrieNode> {
+        let root_index = storage.nodes.len() as u64 - 1;
+
+        MerkleTree::<H, TRIE_HEIGHT>::get_proof(root_index, storage, &key_path(key))
+            .unwrap()
+            .unwrap()
+    }
+
+    /// Trie holding a single leaf, its root being an edge to the leaf.
+    fn single_leaf_proof(key: Felt, value: Felt) -> (Felt, Vec<TrieNode>) {
+        let root = TrieNode::Edge {
+            child: value,
+            path: key_path(key),
+        };
+        (root.hash::<PedersenHash>(), vec![root])
+    }
+
+    #[test]
+    fn key_path_length() {
+        let path = key_path(Felt::from(5u8));
+
+        assert_eq!(path.len(), TRIE_HEIGHT);
+        assert_eq!(&path[TRIE_HEIGHT - 3..], bits![u8, Msb0; 1, 0, 1]);
+        assert!(path[..TRIE_HEIGHT - 3].not_any());
+    }
+
+    #[test]
+    fn served_edge() {
+        let child = Felt::from(7u8);
+
+        assert_eq!(
+            edge_node(child, Felt::from(42u8), 6),
+            Some(TrieNode::Edge {
+                child,
+                path: bitvec![u8, Msb0; 1, 0, 1, 0, 1, 0],
+            })
+        );
+        assert_eq!(
+            edge_node(child, Felt::from(42u8), 8),
+            Some(TrieNode::Edge {
+                child,
+                path: bitvec![u8, Msb0; 0, 0, 1, 0, 1, 0, 1, 0],
+            })
+        );
+        assert_eq!(edge_node(child, Felt::from(42u8), 5), None);
+        assert_eq!(edge_node(child, Felt::ZERO, 0), None);
+        assert_eq!(edge_node(child, Felt::ZERO, TRIE_HEIGHT + 1), None);
+    }
+
+    #[test]
+    fn single_leaf() {
+        let key = Felt::from(0x1234u32);
+        let value = Felt::from(42u8);
+        let (root, proof) = single_leaf_proof(key, value);
+
+        assert_eq!(
+            verify_pedersen_proof(root, key, value, &proof),
+            Some(Membership::Member)
+        );
+        assert_eq!(verify_pedersen_proof(root, key, Felt::ONE, &proof), None);
+        assert_eq!(verify_pedersen_proof(root, key, Felt::ZERO, &proof), None);
+    }
+
+    #[test]
+    fn non_membership() {
+        let (root, proof) = single_leaf_proof(Felt::from(0x1234u32), Felt::from(42u8));
+        let other_key = Felt::from(0x1235u32);
+
+        assert_eq!(
+            verify_pedersen_proof(root, other_key, Felt::ZERO, &proof),
+            Some(Membership::NonMember)
+        );
+        assert_eq!(
+            verify_pedersen_proof(root, other_key, Felt::from(42u8), &proof),
+            None
+        );
+    }
+
+    #[test]
+    fn empty_trie() {
+        assert_eq!(
+            verify_pedersen_proof(Felt::ZERO, Felt::ONE, Felt::ZERO, &[]),
+            Some(Membership::NonMember)
+        );
+        assert_eq!(
+            verify_pedersen_proof(Felt::ZERO, Felt::ONE, Felt::ONE, &[]),
+            None
+        );
+    }
+
+    #[test]
+    fn binary_branch() {
+        // Keys 2 and 3 share all bits but the last one.
+        let (left, right) = (Felt::from(20u8), Felt::from(30u8));
+        let binary = TrieNode::Binary { left, right };
+        let edge = TrieNode::Edge {
+            child: binary.hash::<PoseidonHash>(),
+            path: key_path(Felt::TWO)[..TRIE_HEIGHT - 1].to_bitvec(),
+        };
+        let root = edge.hash::<PoseidonHash>();
+        // Order of the nodes doesn't matter.
+        let proof = vec![binary, edge];
+
+        assert_eq!(
+            verify_poseidon_proof(root, Felt::TWO, left, &proof),
+            Some(Membership::Member)
+        );
+        assert_eq!(
+            verify_poseidon_proof(root, Felt::THREE, right, &proof),
+            Some(Membership::Member)
+        );
+        assert_eq!(
+            verify_poseidon_proof(root, Felt::from(4u8), Felt::ZERO, &proof),
+            Some(Membership::NonMember)
+        );
+        // Nodes hashed with Poseidon don't hash up to the root with Pedersen.
+        assert_eq!(verify_pedersen_proof(root, Felt::TWO, left, &proof), None);
+    }
+
+    #[test]
+    fn missing_node() {
+        let binary = TrieNode::Binary {
+            left: Felt::from(20u8),
+            right: Felt::from(30u8),
+        };
+        let edge = TrieNode::Edge {
+            child: binary.hash::<PedersenHash>(),
+            path: key_path(Felt::TWO)[..TRIE_HEIGHT - 1].to_bitvec(),
+        };
+        let root = edge.hash::<PedersenHash>();
+
+        assert_eq!(
+            verify_pedersen_proof(root, Felt::TWO, Felt::from(20u8), &[edge]),
+            None
+        );
+    }
+
+    #[test]
+    fn merkle_tree_storage_proofs() {
+        // 0x1234 and 0x1235 only differ in their last bit, 0x5678 branches off higher up.
+        let leaves = [
+            (Felt::from(0x1234u32), Felt::from(42u8)),
+            (Felt::from(0x1235u32), Felt::from(7u8)),
+            (Felt::from(0x5678u32), Felt::from(99u8)),
+        ];
+        let (root, storage) = commit_tree::<PedersenHash>(&leaves);
+
+        for (key, value) in leaves {
+            let proof = tree_proof::<PedersenHash>(&storage, key);
+
+            assert_eq!(
+                verify_pedersen_proof(root, key, value, &proof),
+                Some(Membership::Member)
+            );
+            assert_eq!(
+                verify_pedersen_proof(root, key, value + Felt::ONE, &proof),
+                None
+            );
+        }
+
+        // Absent keys diverging from the root edge, the edge to the leaves of 0x1234 and 0x1235,
+        // and the edge to the leaf of 0x5678.
+        for key in [0x9999u32, 0x1236, 0x5679].map(Felt::from) {
+            let proof = tree_proof::<PedersenHash>(&storage, key);
+
+            assert_eq!(
+                verify_pedersen_proof(root, key, Felt::ZERO, &proof),
+                Some(Membership::NonMember)
+            );
+        }
+    }
+
+    #[test]
+    fn merkle_tree_class_proofs() {
+        let class_hash = Felt::from(0x5eedu32);
+        let leaves = [
+            (class_hash, class_leaf_hash(Felt::from(0xca5eu32))),
+            (
+                Felt::from(0xc1a55u32),
+                class_leaf_hash(Felt::from(0xc0deu32)),
+            ),
+        ];
+        let (root, storage) = commit_tree::<PoseidonHash>(&leaves);
+
+        let proof = tree_proof::<PoseidonHash>(&storage, class_hash);
+        assert_eq!(
+            verify_poseidon_proof(root, class_hash, leaves[0].1, &proof),
+            Some(Membership::Member)
+        );
+        // The classes trie is hashed with Poseidon.
+        assert_eq!(
+            verify_pedersen_proof(root, class_hash, leaves[0].1, &proof),
+            None
+        );
+
+        let undeclared = Felt::from(0x5eefu32);
+        let proof = tree_proof::<PoseidonHash>(&storage, undeclared);
+        assert_eq!(
+            verify_poseidon_proof(root, undeclared, Felt::ZERO, &proof),
+            Some(Membership::NonMember)
+        );
+    }
+}
@@ -3,6 +3,7 @@ use bitvec::vec::BitVec;
 use starknet_types_core::felt::Felt;
 
 use super::hash::FeltHash;
+use super::merkle_node::{BinaryNode, EdgeNode};
 
 /// A node in a Starknet patricia-merkle trie.
 ///
@@ -16,24 +17,31 @@ pub enum TrieNode {
 impl TrieNode {
     pub fn hash<H: FeltHash>(&self) -> Felt {
         match self {
-            TrieNode::Binary { left, right } => H::hash(*left, *right),
-            TrieNode::Edge { child, path } => {
-                let mut length = [0; 32];
-                length[31] = path.len() as u8;
+            TrieNode::Binary { left, right } => BinaryNode::calculate_hash::<H>(*left, *right),
+            TrieNode::Edge { child, path } => EdgeNode::calculate_hash::<H>(*child, path),
+        }
+    }
+}
 
-                // Convert the `BitSlice<u8, Msb0>` to a `Vec<u8>`
-                let mut path_bytes = vec![0u8; (path.len() + 7) / 8];
-                path.as_bitslice().iter().enumerate().for_each(|(i, bit)| {
-                    if *bit {
-                        path_bytes[i / 8] |= 1 << (7 - (i % 8));
-                    }
-                });
+#[cfg(test)]
+mod tests {
+    use bitvec::prelude::*;
+    use starknet_types_core::felt::Felt;
 
-                let path_felt = Felt::from_bytes_be_slice(&path_bytes);
+    use crate::pathfinder_types::types::hash::PedersenHash;
+    use crate::pathfinder_types::types::trie_node::TrieNode;
 
-                let length_felt = Felt::from_bytes_be(&length);
-                H::hash(*child, path_felt) + length_felt
-            }
-        }
+    #[test]
+    fn edge_hash() {
+        // Same test data as the `EdgeNode` hash, a path shorter than a byte must be right-aligned.
+        let expected = Felt::from_hex_unchecked(
+            "0x1d937094c09b5f8e26a662d21911871e3cbc6858d55cc49af9848ea6fed4e9",
+        );
+        let node = TrieNode::Edge {
+            child: Felt::from_hex_unchecked("0x1234ABCD"),
+            path: bitvec![u8, Msb0; 1, 0, 1, 0, 1, 0],
+        };
+
+        assert_eq!(node.hash::<PedersenHash>(), expected);
     }
 }
@@ -0,0 +1,92 @@
+use std::{
+    net::{IpAddr, Ipv4Addr, SocketAddr},
+    path::PathBuf,
+};
+
+use clap::{ArgGroup, Parser};
+use openrpc_testgen::utils::v7::signers::{
+    key_pair::{KeystoreError, SigningKey},
+    remote::server::serve,
+};
+use starknet_types_core::felt::Felt;
+use tokio::net::TcpListener;
+use tracing::error;
+
+#[derive(Parser, Debug)]
+#[command(version, about = "Reference signing server for the remote signer", long_about = None)]
+#[command(group(
+    ArgGroup::new("key")
+        .required(true)
+        .args(["private_key", "keystore", "key_file"])
+))]
+struct Cli {
+    #[arg(long, env = "REMOTE_SIGNER_HOST", default_value_t = IpAddr::V4(Ipv4Addr::LOCALHOST))]
+    host: IpAddr,
+
+    #[arg(short, long, env = "REMOTE_SIGNER_PORT", default_value_t = 3001)]
+    port: u16,
+
+    #[arg(
+        long,
+        env = "REMOTE_SIGNER_PRIVATE_KEY",
+        hide_env_values = true,
+        help = "Private key to sign with"
+    )]
+    private_key: Option<Felt>,
+
+    #[arg(
+        long,
+        env = "REMOTE_SIGNER_KEYSTORE",
+        requires = "keystore_password",
+        help = "Path to an encrypted keystore JSON file holding the private key"
+    )]
+    keystore: Option<PathBuf>,
+
+    #[arg(
+        long,
+        env = "REMOTE_SIGNER_KEYSTORE_PASSWORD",
+        hide_env_values = true,
+        help = "Password of the keystore, preferably passed through the environment"
+    )]
+    keystore_password: Option<String>,
+
+    #[arg(
+        long,
+        env = "REMOTE_SIGNER_KEY_FILE",
+        help = "Path to a plain text file holding the private key as a hex string"
+    )]
+    key_file: Option<PathBuf>,
+}
+
+impl Cli {
+    fn signing_key(&self) -> Result<SigningKey, KeystoreError> {
+        match (self.private_key, &self.keystore, &self.key_file) {
+            (Some(private_key), _, _) => Ok(SigningKey::from_secret_scalar(private_key)),
+            (None, Some(keystore), _) => SigningKey::from_keystore(
+                keystore,
+                self.keystore_password.as_deref().unwrap_or_default(),
+            ),
+            (None, None, Some(key_file)) => SigningKey::from_key_file(key_file),
+            (None, None, None) => Err(KeystoreError::InvalidPath),
+        }
+    }
+}
+
+#[tokio::main]
+async fn main() -> std::io::Result<()> {
+    tracing_subscriber::fmt()
+        .with_max_level(tracing::Level::INFO)
+        .init();
+
+    let cli = Cli::parse();
+    let signing_key = match cli.signing_key() {
+        Ok(signing_key) => signing_key,
+        Err(e) => {
+            error!("Failed to load the private key: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    let listener = TcpListener::bind(SocketAddr::new(cli.host, cli.port)).await?;
+    serve(listener, signing_key).await
+}
@@ -15,6 +15,32 @@ const ADDR_BOUND: NonZeroFelt = NonZeroFelt::from_raw([
     18446743986131443745,
 ]);
 
+pub fn calculate_declare_v0_hash(txn: &DeclareTxnV0<Felt>, chain_id: &Felt) -> Result<Felt, Error> {
+    Ok(compute_hash_on_elements(&[
+        PREFIX_DECLARE,
+        Felt::ZERO, // version
+        txn.sender_address,
+        Felt::ZERO, // entry_point_selector
+        compute_hash_on_elements(&[]),
+        txn.max_fee,
+        *chain_id,
+        txn.class_hash,
+    ]))
+}
+
+pub fn calculate_declare_v1_hash(txn: &DeclareTxnV1<Felt>, chain_id: &Felt) -> Result<Felt, Error> {
+    Ok(compute_hash_on_elements(&[
+        PREFIX_DECLARE,
+        Felt::ONE, // version
+        txn.sender_address,
+        Felt::ZERO, // entry_point_selector
+        compute_hash_on_elements(&[txn.class_hash]),
+        txn.max_fee,
+        *chain_id,
+        txn.nonce,
+    ]))
+}
+
 pub fn calculate_declare_v2_hash(
     txn: &BroadcastedDeclareTxnV2<Felt>,
     chain_id: &Felt,
@@ -0,0 +1,31 @@
+use crate::txn_validation::errors::Error;
+use base64::decode;
+use crypto_utils::legacy_class_hash::{compute_legacy_class_hash, LegacyEntryPoints};
+use flate2::read::GzDecoder;
+use serde::Deserialize;
+use serde_json::value::RawValue;
+use starknet_types_core::felt::Felt;
+use std::io::Read;
+
+/// Cairo 0 class as sent in `DECLARE` v1 transactions, with a gzipped, base64 encoded program.
+#[derive(Deserialize)]
+struct CompressedLegacyClass {
+    program: String,
+    entry_points_by_type: LegacyEntryPoints,
+    #[serde(default)]
+    abi: Option<Box<RawValue>>,
+}
+
+/// Computes the class hash of a compressed Cairo 0 class, the `contract_class` of a `DECLARE` v1
+/// transaction.
+pub fn calculate_legacy_class_hash(contract_class: &serde_json::Value) -> Result<Felt, Error> {
+    let contract_class: CompressedLegacyClass = serde_json::from_value(contract_class.clone())?;
+
+    let mut program = String::new();
+    GzDecoder::new(decode(&contract_class.program)?.as_slice()).read_to_string(&mut program)?;
+
+    let abi = contract_class.abi.as_ref().map_or("null", |abi| abi.get());
+
+    compute_legacy_class_hash(&contract_class.entry_points_by_type, abi, &program)
+        .map_err(|e| Error::InvalidLegacyClass(e.to_string()))
+}
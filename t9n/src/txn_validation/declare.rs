@@ -1,9 +1,58 @@
 use super::errors::Error;
-use crate::txn_hashes::declare_hash::{calculate_declare_v2_hash, calculate_declare_v3_hash};
+use crate::txn_hashes::declare_hash::{
+    calculate_declare_v0_hash, calculate_declare_v1_hash, calculate_declare_v2_hash,
+    calculate_declare_v3_hash,
+};
 use crypto_utils::curve::signer::{recover, verify};
 use starknet_types_core::felt::Felt;
 use starknet_types_rpc::v0_7_1::starknet_api_openrpc::*;
 
+pub fn verify_declare_v0_signature(
+    txn: &DeclareTxnV0<Felt>,
+    public_key: Option<&str>,
+    chain_id_input: &str,
+) -> Result<(bool, Felt), Error> {
+    let chain_id = Felt::from_hex_unchecked(chain_id_input);
+
+    let msg_hash = calculate_declare_v0_hash(txn, &chain_id)?;
+
+    let r_bytes = txn.signature[0];
+    let s_bytes = txn.signature[1];
+
+    let stark_key = match public_key {
+        Some(public_key) => Felt::from_hex_unchecked(public_key),
+        None => recover(&msg_hash, &r_bytes, &s_bytes, &Felt::ONE)?,
+    };
+
+    match verify(&stark_key, &msg_hash, &r_bytes, &s_bytes) {
+        Ok(is_valid) => Ok((is_valid, msg_hash)),
+        Err(e) => Err(Error::VerifyError(e)),
+    }
+}
+
+pub fn verify_declare_v1_signature(
+    txn: &DeclareTxnV1<Felt>,
+    public_key: Option<&str>,
+    chain_id_input: &str,
+) -> Result<(bool, Felt), Error> {
+    let chain_id = Felt::from_hex_unchecked(chain_id_input);
+
+    let msg_hash = calculate_declare_v1_hash(txn, &chain_id)?;
+
+    let r_bytes = txn.signature[0];
+    let s_bytes = txn.signature[1];
+
+    let stark_key = match public_key {
+        Some(public_key) => Felt::from_hex_unchecked(public_key),
+        None => recover(&msg_hash, &r_bytes, &s_bytes, &Felt::ONE)?,
+    };
+
+    match verify(&stark_key, &msg_hash, &r_bytes, &s_bytes) {
+        Ok(is_valid) => Ok((is_valid, msg_hash)),
+        Err(e) => Err(Error::VerifyError(e)),
+    }
+}
+
 pub fn verify_declare_v2_signature(
     txn: &BroadcastedDeclareTxnV2<Felt>,
     public_key: Option<&str>,
@@ -17,4 +17,8 @@ pub enum Error {
     VerifyError(#[from] VerifyError),
     #[error(transparent)]
     RecoverError(#[from] RecoverError),
+    #[error(transparent)]
+    Base64Error(#[from] base64::DecodeError),
+    #[error("Invalid legacy contract class: {0}")]
+    InvalidLegacyClass(String),
 }
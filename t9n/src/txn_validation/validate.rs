@@ -1,3 +1,4 @@
+use crate::txn_hashes::legacy_class_hash::calculate_legacy_class_hash;
 use crate::txn_validation::declare::*;
 use crate::txn_validation::deploy_account::*;
 use crate::txn_validation::invoke::*;
@@ -60,7 +61,12 @@ pub fn validate_txn_json(
 
     let trimmed_version = txn_version.trim_start_matches("0x").trim_start_matches("0");
 
-    let formatted_version = format!("0x{}", trimmed_version);
+    // Version 0 is trimmed down to nothing
+    let formatted_version = if trimmed_version.is_empty() {
+        "0x0".to_string()
+    } else {
+        format!("0x{}", trimmed_version)
+    };
 
     let version = formatted_version.as_str();
 
@@ -117,6 +123,54 @@ pub fn validate_txn_json(
             _ => Err(serde_json::Error::custom("Unsupported version")),
         },
         "DECLARE" => match version {
+            "0x0" => {
+                let txn: DeclareTxnV0<Felt> = from_value(with_legacy_class_hash(value)?)?;
+                match verify_declare_v0_signature(&txn, public_key, chain_id) {
+                    Ok((is_valid, hash)) => {
+                        if is_valid {
+                            Ok(json!({ "hash": hash }))
+                        } else {
+                            Err(serde_json::Error::custom(
+                                json!({
+                                    "error": "Signature is invalid",
+                                    "hash": hash,
+                                })
+                                .to_string(),
+                            ))
+                        }
+                    }
+                    Err(e) => Err(serde_json::Error::custom(
+                        json!({
+                            "error": format!("Signature verification failed: {:?}", e),
+                        })
+                        .to_string(),
+                    )),
+                }
+            }
+            "0x1" => {
+                let txn: DeclareTxnV1<Felt> = from_value(with_legacy_class_hash(value)?)?;
+                match verify_declare_v1_signature(&txn, public_key, chain_id) {
+                    Ok((is_valid, hash)) => {
+                        if is_valid {
+                            Ok(json!({ "hash": hash }))
+                        } else {
+                            Err(serde_json::Error::custom(
+                                json!({
+                                    "error": "Signature is invalid",
+                                    "hash": hash,
+                                })
+                                .to_string(),
+                            ))
+                        }
+                    }
+                    Err(e) => Err(serde_json::Error::custom(
+                        json!({
+                            "error": format!("Signature verification failed: {:?}", e),
+                        })
+                        .to_string(),
+                    )),
+                }
+            }
             "0x2" => {
                 let txn: BroadcastedDeclareTxnV2<Felt> = from_value(value)?;
                 match verify_declare_v2_signature(&txn, public_key, chain_id) {
@@ -229,3 +283,20 @@ pub fn validate_txn_json(
         _ => Err(Error::custom("Invalid or missing transaction type")),
     }
 }
+
+/// `DECLARE` v0 and v1 transactions are hashed with the class hash, which is computed from the
+/// Cairo 0 class when the transaction carries it instead of the hash.
+fn with_legacy_class_hash(mut value: Value) -> SerdeResult<Value> {
+    if let Some(contract_class) = value.get("contract_class") {
+        let class_hash = calculate_legacy_class_hash(contract_class).map_err(|e| {
+            serde_json::Error::custom(
+                json!({
+                    "error": format!("Class hash calculation failed: {:?}", e),
+                })
+                .to_string(),
+            )
+        })?;
+        value["class_hash"] = json!(class_hash);
+    }
+    Ok(value)
+}